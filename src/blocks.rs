@@ -0,0 +1,161 @@
+//! How blocks and chunks are shown: the header fields of a block with the list of its chunks,
+//! and the transactions and receipts of a chunk.
+
+/// The UTC date and time of a block timestamp in nanoseconds, e.g. `2021-06-01 13:01:01 UTC`
+pub fn format_timestamp(timestamp_nanosec: u64) -> String {
+    let seconds = timestamp_nanosec / 1_000_000_000;
+    let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+    // The civil date of the days since 1970-01-01, the years start in March so that
+    // February 29 is the last day of a year
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    )
+}
+
+pub fn print_block(block: &near_primitives::views::BlockView) {
+    println!("\nBlock #{} ({})", block.header.height, block.header.hash);
+    println!("  Previous block: {}", block.header.prev_hash);
+    println!("  Author: {}", block.author);
+    println!(
+        "  Timestamp: {} ({})",
+        format_timestamp(block.header.timestamp),
+        block.header.timestamp
+    );
+    println!("  Epoch ID: {}", block.header.epoch_id);
+    println!("  Gas price: {} yoctoNEAR", block.header.gas_price);
+    println!(
+        "  Total supply: {}",
+        crate::common::NearBalance::from_yoctonear(block.header.total_supply)
+    );
+    println!("  Chunks ({}):", block.chunks.len());
+    for chunk in &block.chunks {
+        println!(
+            "    Shard {}: {}, gas used {} of {}{}",
+            chunk.shard_id,
+            chunk.chunk_hash,
+            chunk.gas_used,
+            chunk.gas_limit,
+            // A shard without a new chunk repeats its last chunk
+            if chunk.height_included == block.header.height {
+                "".to_owned()
+            } else {
+                format!(
+                    " (missing, the last chunk is from block #{})",
+                    chunk.height_included
+                )
+            }
+        );
+    }
+}
+
+pub fn print_chunk(chunk: &near_primitives::views::ChunkView) {
+    println!(
+        "\nChunk {} of shard {} in block #{}",
+        chunk.header.chunk_hash, chunk.header.shard_id, chunk.header.height_included
+    );
+    println!("  Author: {}", chunk.author);
+    println!(
+        "  Gas used: {} of {}",
+        chunk.header.gas_used, chunk.header.gas_limit
+    );
+    println!("  Transactions ({}):", chunk.transactions.len());
+    for transaction in &chunk.transactions {
+        println!(
+            "    {}: <{}> -> <{}>, {} action(s)",
+            transaction.hash,
+            transaction.signer_id,
+            transaction.receiver_id,
+            transaction.actions.len()
+        );
+    }
+    println!("  Receipts ({}):", chunk.receipts.len());
+    for receipt in &chunk.receipts {
+        println!(
+            "    {}: <{}> -> <{}>",
+            receipt.receipt_id, receipt.predecessor_id, receipt.receiver_id
+        );
+    }
+}
+
+pub async fn block(
+    rpc_url: &url::Url,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<near_primitives::views::BlockView> {
+    let block = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .block(block_reference)
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!("Failed to fetch the block: {:?}", err))
+        })?;
+    Ok(block)
+}
+
+/// The chunk of the shard in the block, a shard without a new chunk in the block gives its
+/// last chunk
+pub async fn chunk(
+    rpc_url: &url::Url,
+    block: &near_primitives::views::BlockView,
+    shard_id: near_primitives::types::ShardId,
+) -> color_eyre::eyre::Result<near_primitives::views::ChunkView> {
+    let chunk_header = block
+        .chunks
+        .iter()
+        .find(|chunk_header| chunk_header.shard_id == shard_id)
+        .ok_or_else(|| {
+            crate::common::CliError::UserInput(format!(
+                "The block #{} has {} shard(s), there is no shard {}",
+                block.header.height,
+                block.chunks.len(),
+                shard_id
+            ))
+        })?;
+    let chunk = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .chunk(near_jsonrpc_client::ChunkId::Hash(chunk_header.chunk_hash))
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!("Failed to fetch the chunk: {:?}", err))
+        })?;
+    Ok(chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_are_shown_as_utc_dates() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(
+            format_timestamp(1_600_000_000_000_000_000),
+            "2020-09-13 12:26:40 UTC"
+        );
+        assert_eq!(
+            format_timestamp(951_782_400_000_000_000),
+            "2000-02-29 00:00:00 UTC"
+        );
+        assert_eq!(
+            format_timestamp(1_622_552_461_123_456_789),
+            "2021-06-01 13:01:01 UTC"
+        );
+    }
+}
@@ -33,6 +33,10 @@ impl FullAccessType {
 }
 
 impl FullAccessType {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.sign_option.to_cli_args()
+    }
+
     pub async fn process(
         self,
         nonce: near_primitives::types::Nonce,
@@ -56,10 +60,10 @@ impl FullAccessType {
             actions,
             ..prepopulated_unsigned_transaction
         };
-        println!(
+        crate::common::print_message(&format!(
             "\nAdding full access key = {:?} to {:?}.",
             public_key, unsigned_transaction.signer_id
-        );
+        ));
         match self
             .sign_option
             .process(
@@ -73,7 +77,7 @@ impl FullAccessType {
                     transaction_info,
                     network_connection_config,
                 )
-                .await;
+                .await?;
             }
             None => {}
         };
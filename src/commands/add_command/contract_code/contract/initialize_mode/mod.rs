@@ -52,6 +52,7 @@ impl NextAction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("next-action subcommand");
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Do you want to choose next action")
             .items(&actions)
@@ -71,6 +72,21 @@ impl NextAction {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Initialize(initialize) => {
+                let mut args = initialize.to_cli_args();
+                args.push_front("initialize".to_owned());
+                args
+            }
+            Self::NoInitialize(no_initialize) => {
+                let mut args = no_initialize.to_cli_args();
+                args.push_front("no-initialize".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -126,6 +142,10 @@ impl NoInitialize {
 }
 
 impl NoInitialize {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.sign_option.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -144,7 +164,7 @@ impl NoInitialize {
                     transaction_info,
                     network_connection_config,
                 )
-                .await;
+                .await?;
             }
             None => {}
         };
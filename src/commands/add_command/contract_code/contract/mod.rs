@@ -42,6 +42,7 @@ impl Contract {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("contract subcommand");
         let selected_contract = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("To deploy contract code you will need to choose next action")
             .items(&contracts)
@@ -58,6 +59,16 @@ impl Contract {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::ContractFile(contract_file) => {
+                let mut args = contract_file.to_cli_args();
+                args.push_front("contract-file".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -123,16 +134,88 @@ impl ContractFile {
 impl ContractFile {
     fn input_file_path() -> std::path::PathBuf {
         println!();
+        crate::common::ensure_interactive("<file-path>");
         let input_file_path: String = Input::new()
             .with_prompt("What is a file location of the contract?")
             .interact_text()
             .unwrap();
         let mut path = std::path::PathBuf::new();
         path.push(input_file_path);
-        println!("path: {:?}", &path);
+        crate::common::print_message(&format!("path: {:?}", &path));
         path
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(self.file_path.display().to_string());
+        args
+    }
+
+    /// The pre-deploy check: the code must be a WebAssembly module within the contract size limit
+    /// of the network, or of the default runtime config offline
+    async fn check_code(
+        &self,
+        code: &[u8],
+        receiver_id: &str,
+        network_connection_config: &Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let wasm_info = crate::wasm::WasmInfo::new(code).map_err(|err| {
+            crate::common::CliError::UserInput(format!(
+                "{} is not a valid WebAssembly module: {}",
+                self.file_path.display(),
+                err
+            ))
+        })?;
+        let (deployed_code_hash, max_contract_size) = match network_connection_config {
+            Some(network_connection_config) => {
+                let rpc_url = network_connection_config.rpc_url();
+                let deployed_code_hash = crate::wasm::account_code_hash(
+                    &rpc_url,
+                    receiver_id.to_owned(),
+                    near_primitives::types::Finality::Final.into(),
+                )
+                .await?;
+                (
+                    deployed_code_hash,
+                    Some(crate::wasm::max_contract_size(&rpc_url).await?),
+                )
+            }
+            None => (None, None),
+        };
+        if let crate::common::OutputFormat::Plaintext = crate::common::output_format() {
+            println!("\nThe contract code {}:", self.file_path.display());
+        }
+        if wasm_info.print(
+            deployed_code_hash.as_ref(),
+            max_contract_size.unwrap_or(crate::wasm::MAX_CONTRACT_SIZE),
+        ) && deployed_code_hash.is_some()
+        {
+            crate::common::print_message(&format!(
+                "The account <{}> already has this contract code deployed",
+                receiver_id
+            ));
+        }
+        match max_contract_size {
+            Some(max_contract_size) if wasm_info.exceeds_size_limit(max_contract_size) => {
+                return Err(crate::common::CliError::UserInput(format!(
+                    "The contract code is {} bytes, more than the limit of {} bytes of the network",
+                    wasm_info.size, max_contract_size
+                ))
+                .into());
+            }
+            None if wasm_info.exceeds_size_limit(crate::wasm::MAX_CONTRACT_SIZE) => {
+                return Err(crate::common::CliError::UserInput(format!(
+                    "The contract code is {} bytes, more than the limit of {} bytes of the default runtime config (the limit of the network is not known offline)",
+                    wasm_info.size,
+                    crate::wasm::MAX_CONTRACT_SIZE
+                ))
+                .into());
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -143,6 +226,12 @@ impl ContractFile {
             .map_err(|err| color_eyre::Report::msg(format!("Failed to open file: {:?}", err)))?
             .read_to_end(&mut code)
             .map_err(|err| color_eyre::Report::msg(format!("Failed to read file: {:?}", err)))?;
+        self.check_code(
+            &code,
+            &prepopulated_unsigned_transaction.receiver_id,
+            &network_connection_config,
+        )
+        .await?;
         let action = near_primitives::transaction::Action::DeployContract(
             near_primitives::transaction::DeployContractAction { code },
         );
@@ -28,6 +28,10 @@ impl OfflineArgs {
 }
 
 impl OfflineArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.send_from.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
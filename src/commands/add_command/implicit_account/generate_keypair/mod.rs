@@ -1,4 +1,3 @@
-use std::io::Write;
 use std::str::FromStr;
 
 fn bip32path_to_string(bip32path: &slip10::BIP32Path) -> String {
@@ -26,6 +25,10 @@ fn bip32path_to_string(bip32path: &slip10::BIP32Path) -> String {
 pub struct CliGenerateKeypair {}
 
 impl CliGenerateKeypair {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::new()
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let new_master_seed_phrase_words_count: usize = 12;
         let seed_phrase_hd_path = slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
@@ -90,16 +93,14 @@ impl CliGenerateKeypair {
                 &path.display()
             )));
         };
-        std::fs::File::create(&path)
-            .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-            .write(buf.as_bytes())
-            .map_err(|err| {
-                color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
-            })?;
-        println!(
+        crate::keychain::write_key_file(&path, &buf, None)?;
+        crate::common::print_message(&format!(
             "The data for the access key is saved in a file {}",
             &path.display()
-        );
+        ));
+        crate::common::set_json_output("account_id", implicit_account_id);
+        crate::common::set_json_output("public_key", public_key_str);
+        crate::common::set_json_output("file_path", path.display().to_string());
         Ok(())
     }
 }
@@ -31,6 +31,10 @@ impl From<CliImplicitAccount> for ImplicitAccount {
 }
 
 impl ImplicitAccount {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.public_key_mode.to_cli_args()
+    }
+
     pub async fn process(self) -> crate::CliResult {
         self.public_key_mode.process().await
     }
@@ -66,6 +70,7 @@ impl PublicKeyMode {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("public-key-mode subcommand");
         let select_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
@@ -79,6 +84,16 @@ impl PublicKeyMode {
         }
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::GenerateKeypair(generate_keypair) => {
+                let mut args = generate_keypair.to_cli_args();
+                args.push_front("generate-keypair".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(self) -> crate::CliResult {
         match self {
             PublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
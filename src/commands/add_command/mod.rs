@@ -35,6 +35,10 @@ impl AddAction {
 }
 
 impl AddAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -79,18 +83,16 @@ impl Action {
                 self::access_key::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::ContractCode(cli_operation_mode) => Ok(Action::ContractCode(
-                self::contract_code::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
+                self::contract_code::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::ImplicitAccount(cli_generate_keypair) => {
                 Ok(Action::ImplicitAccount(cli_generate_keypair.into()))
             }
             CliAction::StakeProposal(cli_operation_mode) => Ok(Action::StakeProposal(
-                self::stake_proposal::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
+                self::stake_proposal::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::SubAccount(cli_operation_mode) => Ok(Action::SubAccount(
-                self::sub_account::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::sub_account::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
         }
     }
@@ -104,6 +106,7 @@ impl Action {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action subcommand");
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to add")
             .items(&actions)
@@ -120,6 +123,36 @@ impl Action {
         Ok(Self::from(cli_action)?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AccessKey(access_key) => {
+                let mut args = access_key.to_cli_args();
+                args.push_front("access-key".to_owned());
+                args
+            }
+            Self::ContractCode(contract_code) => {
+                let mut args = contract_code.to_cli_args();
+                args.push_front("contract-code".to_owned());
+                args
+            }
+            Self::ImplicitAccount(implicit_account) => {
+                let mut args = implicit_account.to_cli_args();
+                args.push_front("implicit-account".to_owned());
+                args
+            }
+            Self::StakeProposal(stake_proposal) => {
+                let mut args = stake_proposal.to_cli_args();
+                args.push_front("stake-proposal".to_owned());
+                args
+            }
+            Self::SubAccount(sub_account) => {
+                let mut args = sub_account.to_cli_args();
+                args.push_front("sub-account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -49,12 +49,19 @@ impl Sender {
 impl Sender {
     fn input_sender_account_id() -> String {
         println!();
+        crate::common::ensure_interactive("<sender-account-id>");
         Input::new()
             .with_prompt("What is the account ID of the validator?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.transfer.to_cli_args();
+        args.push_front(self.sender_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -43,6 +43,16 @@ impl TransactionsSigning {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::TransactionsSigningPublicKey(transactions_signing_public_key) => {
+                let mut args = transactions_signing_public_key.to_cli_args();
+                args.push_front("transactions-signing-public-key".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -109,12 +119,19 @@ impl TransactionsSigningAction {
 
 impl TransactionsSigningAction {
     fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("<transactions-signing-public-key>");
         Input::new()
             .with_prompt("Enter a public key for this server")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_option.to_cli_args();
+        args.push_front(self.transactions_signing_public_key.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -143,7 +160,7 @@ impl TransactionsSigningAction {
                     transaction_info,
                     network_connection_config,
                 )
-                .await;
+                .await?;
             }
             None => {}
         };
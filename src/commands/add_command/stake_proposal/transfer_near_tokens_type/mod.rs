@@ -41,6 +41,16 @@ impl Transfer {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Amount(amount) => {
+                let mut args = amount.to_cli_args();
+                args.push_front("amount".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -105,12 +115,19 @@ impl TransferNEARTokensAction {
 
 impl TransferNEARTokensAction {
     fn input_amount() -> crate::common::NearBalance {
+        crate::common::ensure_interactive("<amount>");
         Input::new()
             .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_transactions.to_cli_args();
+        args.push_front(self.amount.to_cli_arg());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
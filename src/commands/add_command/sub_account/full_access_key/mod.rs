@@ -45,6 +45,7 @@ impl FullAccessKey {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("full-access-key subcommand");
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to add")
             .items(&actions)
@@ -63,6 +64,16 @@ impl FullAccessKey {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SubAccountFullAccess(sub_account_full_access) => {
+                let mut args = sub_account_full_access.to_cli_args();
+                args.push_front("sub-account-full-access".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -117,6 +128,10 @@ impl SubAccountFullAccess {
 }
 
 impl SubAccountFullAccess {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.public_key_mode.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
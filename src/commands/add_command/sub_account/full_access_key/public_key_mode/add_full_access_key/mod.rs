@@ -52,12 +52,20 @@ impl AddAccessKeyAction {
 
 impl AddAccessKeyAction {
     fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("<public-key>");
         Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.deposit.to_cli_args();
+        args.push_front(self.nonce.to_string());
+        args.push_front(self.public_key.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
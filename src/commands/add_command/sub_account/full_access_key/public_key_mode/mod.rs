@@ -56,6 +56,7 @@ impl PublicKeyMode {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("public-key-mode subcommand");
         let select_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
@@ -76,6 +77,21 @@ impl PublicKeyMode {
         }
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::PublicKey(public_key) => {
+                let mut args = public_key.to_cli_args();
+                args.push_front("public-key".to_owned());
+                args
+            }
+            Self::GenerateKeypair(generate_keypair) => {
+                let mut args = generate_keypair.to_cli_args();
+                args.push_front("generate-keypair".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
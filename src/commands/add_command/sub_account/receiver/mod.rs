@@ -39,6 +39,16 @@ impl SendTo {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SubAccount(sub_account) => {
+                let mut args = sub_account.to_cli_args();
+                args.push_front("sub-account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -103,12 +113,19 @@ impl SubAccount {
 
 impl SubAccount {
     fn input_sub_account_id() -> String {
+        crate::common::ensure_interactive("<sub-account-id>");
         Input::new()
             .with_prompt("What is the sub-account ID?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.full_access_key.to_cli_args();
+        args.push_front(self.sub_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
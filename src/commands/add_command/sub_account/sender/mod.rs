@@ -46,12 +46,19 @@ impl Sender {
 impl Sender {
     fn input_owner_account_id() -> String {
         println!();
+        crate::common::ensure_interactive("<owner-account-id>");
         Input::new()
             .with_prompt("What is the owner account ID?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.send_to.to_cli_args();
+        args.push_front(self.owner_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -30,6 +30,10 @@ impl NetworkArgs {
 }
 
 impl NetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.selected_server.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -38,6 +38,16 @@ impl SendTo {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Receiver(receiver) => {
+                let mut args = receiver.to_cli_args();
+                args.push_front("receiver".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -102,12 +112,19 @@ impl Receiver {
 
 impl Receiver {
     pub fn input_receiver_account_id() -> String {
+        crate::common::ensure_interactive("<receiver-account-id>");
         Input::new()
             .with_prompt("What is the account ID of the receiver?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.action.to_cli_args();
+        args.push_front(self.receiver_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
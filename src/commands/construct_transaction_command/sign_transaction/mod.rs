@@ -84,6 +84,7 @@ impl SignTransaction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("sign-option subcommand");
         let select_sign_options = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Would you like to sign the transaction?")
             .items(&sign_options)
@@ -107,6 +108,31 @@ impl SignTransaction {
         Self::from(cli_sign_option, connection_config, sender_account_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SignPrivateKey(sign_private_key) => {
+                let mut args = sign_private_key.to_cli_args();
+                args.push_front("sign-private-key".to_owned());
+                args
+            }
+            Self::SignWithKeychain(sign_with_keychain) => {
+                let mut args = sign_with_keychain.to_cli_args();
+                args.push_front("sign-with-keychain".to_owned());
+                args
+            }
+            Self::SignWithLedger(sign_with_ledger) => {
+                let mut args = sign_with_ledger.to_cli_args();
+                args.push_front("sign-with-ledger".to_owned());
+                args
+            }
+            Self::SignManually(sign_manually) => {
+                let mut args = sign_manually.to_cli_args();
+                args.push_front("sign-manually".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -137,6 +163,7 @@ impl SignTransaction {
 }
 
 fn input_signer_public_key() -> near_crypto::PublicKey {
+    crate::common::ensure_interactive("--signer-public-key");
     Input::new()
         .with_prompt("To create an unsigned transaction enter sender's public key")
         .interact_text()
@@ -144,6 +171,7 @@ fn input_signer_public_key() -> near_crypto::PublicKey {
 }
 
 fn input_signer_secret_key() -> near_crypto::SecretKey {
+    crate::common::ensure_interactive("--signer-secret-key");
     Input::new()
         .with_prompt("Enter sender's private key")
         .interact_text()
@@ -151,7 +179,8 @@ fn input_signer_secret_key() -> near_crypto::SecretKey {
 }
 
 fn input_access_key_nonce(public_key: &str) -> u64 {
-    println!("Your public key: `{}`", public_key);
+    crate::common::print_message(&format!("Your public key: `{}`", public_key));
+    crate::common::ensure_interactive("--nonce");
     Input::new()
         .with_prompt(
             "Enter transaction nonce for this public key (query the access key information with \
@@ -165,6 +194,7 @@ fn input_access_key_nonce(public_key: &str) -> u64 {
 }
 
 fn input_block_hash() -> near_primitives::hash::CryptoHash {
+    crate::common::ensure_interactive("--block-hash");
     let input_block_hash: crate::common::BlockHashAsBase58 = Input::new()
         .with_prompt(
             "Enter recent block hash (query information about the hash of the last block with \
@@ -62,6 +62,17 @@ impl SignManually {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        args.push_front(self.block_hash.to_string());
+        args.push_front("--block-hash".to_owned());
+        args.push_front(self.nonce.to_string());
+        args.push_front("--nonce".to_owned());
+        args.push_front(self.signer_public_key.to_string());
+        args.push_front("--signer-public-key".to_owned());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -88,7 +99,7 @@ impl SignManually {
                     })
                     .await
                     .map_err(|err| {
-                        color_eyre::Report::msg(format!(
+                        crate::common::CliError::Rpc(format!(
                             "Failed to fetch public key information for nonce: {:?}",
                             err
                         ))
@@ -111,15 +122,21 @@ impl SignManually {
             }
         };
 
-        println!();
-        println!("Unsigned transaction:");
+        crate::common::print_message("\nUnsigned transaction:");
         crate::common::print_transaction(unsigned_transaction.clone());
         let serialize_to_base64 = near_primitives::serialize::to_base64(
             unsigned_transaction
                 .try_to_vec()
                 .expect("Transaction is not expected to fail on serialization"),
         );
-        println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!("\nSerialize_to_base64:\n{}", &serialize_to_base64)
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("unsigned_transaction_base64", &serialize_to_base64)
+            }
+        }
         Ok(None)
     }
 }
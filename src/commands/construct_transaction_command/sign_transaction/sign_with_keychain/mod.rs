@@ -14,6 +14,9 @@ pub struct CliSignKeychain {
     nonce: Option<u64>,
     #[clap(long)]
     block_hash: Option<near_primitives::hash::CryptoHash>,
+    /// Read the passphrase of an encrypted keychain from this file descriptor
+    #[clap(long)]
+    passphrase_fd: Option<i32>,
     #[clap(subcommand)]
     submit: Option<super::sign_with_private_key::Submit>,
 }
@@ -22,7 +25,8 @@ pub struct CliSignKeychain {
 pub struct SignKeychain {
     nonce: u64,
     block_hash: near_primitives::hash::CryptoHash,
-    pub submit: Option<super::sign_with_private_key::Submit>,
+    passphrase_fd: Option<i32>,
+    pub submit: super::sign_with_private_key::Submit,
 }
 
 impl SignKeychain {
@@ -31,11 +35,15 @@ impl SignKeychain {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: String,
     ) -> color_eyre::eyre::Result<Self> {
-        let submit: Option<super::sign_with_private_key::Submit> = item.submit;
+        let submit: super::sign_with_private_key::Submit = match item.submit {
+            Some(submit) => submit,
+            None => super::sign_with_private_key::Submit::choose_submit(),
+        };
         match connection_config {
             Some(_) => Ok(Self {
                 nonce: 0,
                 block_hash: Default::default(),
+                passphrase_fd: item.passphrase_fd,
                 submit,
             }),
             None => {
@@ -45,11 +53,9 @@ impl SignKeychain {
                 let dir_name = crate::consts::DIR_NAME_KEY_CHAIN;
                 path.push(dir_name);
                 path.push(file_name);
-                let data = std::fs::read_to_string(path).map_err(|err| {
-                    color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
-                })?;
+                let data = crate::keychain::read_key_file(&path, item.passphrase_fd)?;
                 let account_json: User = serde_json::from_str(&data).map_err(|err| {
-                    color_eyre::Report::msg(format!(
+                    crate::common::CliError::Keychain(format!(
                         "Data for the access key was not found in the file! Error: {}",
                         err
                     ))
@@ -66,6 +72,7 @@ impl SignKeychain {
                 Ok(SignKeychain {
                     nonce,
                     block_hash,
+                    passphrase_fd: item.passphrase_fd,
                     submit,
                 })
             }
@@ -85,6 +92,22 @@ impl SignKeychain {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.submit.to_cli_args();
+        if let Some(passphrase_fd) = self.passphrase_fd {
+            args.push_front(passphrase_fd.to_string());
+            args.push_front("--passphrase-fd".to_owned());
+        }
+        // The nonce and the block hash are only asked for in the offline mode
+        if self.block_hash != Default::default() {
+            args.push_front(self.block_hash.to_string());
+            args.push_front("--block-hash".to_owned());
+            args.push_front(self.nonce.to_string());
+            args.push_front("--nonce".to_owned());
+        }
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -102,86 +125,88 @@ impl SignKeychain {
                 path
             }
             Some(connection_config) => {
-                let dir_name = connection_config.dir_name();
-                path.push(dir_name);
-                path.push(file_name);
-
-                if path.exists() {
-                    path
-                } else {
-                    let query_view_method_response = self
-                        .rpc_client(connection_config.rpc_url().as_str())
-                        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                            block_reference: near_primitives::types::Finality::Final.into(),
-                            request: near_primitives::views::QueryRequest::ViewAccessKeyList {
-                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
-                            },
-                        })
-                        .await
-                        .map_err(|err| {
-                            color_eyre::Report::msg(format!(
-                                "Failed to fetch query for view key list: {:?}",
-                                err
-                            ))
-                        })?;
-                    let access_key_view =
-                        if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(
-                            result,
-                        ) = query_view_method_response.kind
-                        {
-                            result
-                        } else {
-                            return Err(color_eyre::Report::msg(format!("Error call result")));
-                        };
-                    let mut path = std::path::PathBuf::from(&home_dir);
-                    path.push(dir_name);
-                    path.push(&prepopulated_unsigned_transaction.signer_id);
-                    let mut data_path = std::path::PathBuf::new();
-                    'outer: for access_key in access_key_view.keys {
-                        let account_public_key = access_key.public_key.to_string();
-                        let is_full_access_key: bool = match &access_key.access_key.permission {
-                            near_primitives::views::AccessKeyPermissionView::FullAccess => true,
-                            near_primitives::views::AccessKeyPermissionView::FunctionCall {
-                                allowance: _,
-                                receiver_id: _,
-                                method_names: _,
-                            } => false,
-                        };
-                        let dir = path
-                            .read_dir()
-                            .map_err(|err| {
-                                color_eyre::Report::msg(format!("There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain. {}", err))
-                            })?;
-                        for entry in dir {
-                            if let Ok(entry) = entry {
-                                if entry
-                                    .path()
-                                    .file_stem()
-                                    .unwrap()
-                                    .to_str()
-                                    .unwrap()
-                                    .contains(account_public_key.rsplit(':').next().unwrap())
-                                    && is_full_access_key
-                                {
-                                    data_path.push(entry.path());
-                                    break 'outer;
-                                }
-                            } else {
-                                return Err(color_eyre::Report::msg(format!(
-                                    "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
-                                )));
-                            };
-                        }
+                let query_view_method_response = self
+                    .rpc_client(connection_config.rpc_url().as_str())
+                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                        block_reference: near_primitives::types::Finality::Final.into(),
+                        request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                            account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                        },
+                    })
+                    .await
+                    .map_err(|err| {
+                        crate::common::CliError::Rpc(format!(
+                            "Failed to fetch query for view key list: {:?}",
+                            err
+                        ))
+                    })?;
+                let access_key_view =
+                    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(
+                        result,
+                    ) = query_view_method_response.kind
+                    {
+                        result
+                    } else {
+                        return Err(color_eyre::Report::msg(format!("Error call result")));
+                    };
+                let keychain_keys = crate::keychain::account_keys(
+                    &network_connection_config,
+                    &prepopulated_unsigned_transaction.signer_id,
+                )?;
+                if keychain_keys.is_empty() {
+                    return Err(crate::common::CliError::Keychain(format!(
+                        "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
+                    ))
+                    .into());
+                }
+                let gas_price = self
+                    .rpc_client(connection_config.rpc_url().as_str())
+                    .gas_price(None)
+                    .await
+                    .map_err(|err| {
+                        crate::common::CliError::Rpc(format!(
+                            "Failed to fetch the gas price: {:?}",
+                            err
+                        ))
+                    })?
+                    .gas_price;
+                let permissions = keychain_keys
+                    .iter()
+                    .map(|keychain_key| {
+                        let access_key = access_key_view
+                            .keys
+                            .iter()
+                            .find(|access_key| access_key.public_key == keychain_key.public_key);
+                        (
+                            keychain_key.public_key.clone(),
+                            access_key.map(|access_key| &access_key.access_key.permission),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                match choose_access_key(
+                    &permissions,
+                    &prepopulated_unsigned_transaction,
+                    crate::submission::prepaid_gas_cost(
+                        &prepopulated_unsigned_transaction,
+                        gas_price,
+                    ),
+                ) {
+                    Ok(index) => keychain_keys[index].file_paths[0].clone(),
+                    Err(unfit_keys) => {
+                        return Err(crate::common::CliError::Keychain(format!(
+                            "None of the access keys of <{}> in the keychain can sign this transaction:\n{}",
+                            prepopulated_unsigned_transaction.signer_id,
+                            unfit_keys.join("\n")
+                        ))
+                        .into())
                     }
-                    data_path
                 }
             }
         };
-        let data = std::fs::read_to_string(data_path).map_err(|err| {
-            color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
+        let data = crate::keychain::read_key_file(&data_path, self.passphrase_fd)?;
+        let account_json: User = serde_json::from_str(&data).map_err(|err| {
+            crate::common::CliError::Keychain(format!("Error reading data: {}", err))
         })?;
-        let account_json: User = serde_json::from_str(&data)
-            .map_err(|err| color_eyre::Report::msg(format!("Error reading data: {}", err)))?;
         let sign_with_private_key = super::sign_with_private_key::SignPrivateKey {
             signer_public_key: account_json.public_key,
             signer_secret_key: account_json.private_key,
@@ -194,3 +219,125 @@ impl SignKeychain {
             .await
     }
 }
+
+/// The index of the least privileged key that can sign the transaction, or why none can.
+/// A key without a permission is no longer on the account.
+fn choose_access_key(
+    keys: &[(
+        near_crypto::PublicKey,
+        Option<&near_primitives::views::AccessKeyPermissionView>,
+    )],
+    transaction: &near_primitives::transaction::Transaction,
+    gas_cost: near_primitives::types::Balance,
+) -> Result<usize, Vec<String>> {
+    let mut unfit_keys = vec![];
+    let mut fit_keys = vec![];
+    for (index, (public_key, permission)) in keys.iter().enumerate() {
+        match permission {
+            Some(permission) => {
+                match crate::submission::permission_mismatch(permission, transaction, gas_cost) {
+                    Some(reason) => unfit_keys.push(format!("{}: {}", public_key, reason)),
+                    None => fit_keys.push((privilege_rank(permission), index)),
+                }
+            }
+            None => unfit_keys.push(format!(
+                "{}: the key is no longer on the account",
+                public_key
+            )),
+        }
+    }
+    match fit_keys.into_iter().min_by_key(|(rank, _)| *rank) {
+        Some((_, index)) => Ok(index),
+        None => Err(unfit_keys),
+    }
+}
+
+/// Function call keys limited to some methods are the least privileged, full access keys the most
+fn privilege_rank(permission: &near_primitives::views::AccessKeyPermissionView) -> u8 {
+    match permission {
+        near_primitives::views::AccessKeyPermissionView::FunctionCall { method_names, .. }
+            if !method_names.is_empty() =>
+        {
+            0
+        }
+        near_primitives::views::AccessKeyPermissionView::FunctionCall { .. } => 1,
+        near_primitives::views::AccessKeyPermissionView::FullAccess => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_call_permission(
+        allowance: Option<near_primitives::types::Balance>,
+        method_names: &[&str],
+    ) -> near_primitives::views::AccessKeyPermissionView {
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id: "counter.testnet".to_string(),
+            method_names: method_names.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    fn increment_transaction() -> near_primitives::transaction::Transaction {
+        near_primitives::transaction::Transaction {
+            signer_id: "volodymyr.testnet".to_string(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: "counter.testnet".to_string(),
+            block_hash: Default::default(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: "increment".to_string(),
+                    args: vec![],
+                    gas: 100_000_000_000_000,
+                    deposit: 0,
+                },
+            )],
+        }
+    }
+
+    fn public_key(seed: &str) -> near_crypto::PublicKey {
+        near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, seed).public_key()
+    }
+
+    #[test]
+    fn method_limited_keys_rank_below_function_call_and_full_access_keys() {
+        let method_limited = privilege_rank(&function_call_permission(None, &["increment"]));
+        let any_method = privilege_rank(&function_call_permission(None, &[]));
+        let full_access =
+            privilege_rank(&near_primitives::views::AccessKeyPermissionView::FullAccess);
+        assert!(method_limited < any_method);
+        assert!(any_method < full_access);
+    }
+
+    #[test]
+    fn least_privileged_fit_key_is_chosen() {
+        let full_access = near_primitives::views::AccessKeyPermissionView::FullAccess;
+        let function_call = function_call_permission(None, &["increment"]);
+        let keys = vec![
+            (public_key("gone"), None),
+            (public_key("full"), Some(&full_access)),
+            (public_key("call"), Some(&function_call)),
+        ];
+        assert_eq!(choose_access_key(&keys, &increment_transaction(), 1), Ok(2));
+    }
+
+    #[test]
+    fn function_call_key_short_of_allowance_is_passed_over() {
+        let full_access = near_primitives::views::AccessKeyPermissionView::FullAccess;
+        let function_call = function_call_permission(Some(1), &["increment"]);
+        let keys = vec![
+            (public_key("call"), Some(&function_call)),
+            (public_key("full"), Some(&full_access)),
+        ];
+        assert_eq!(choose_access_key(&keys, &increment_transaction(), 2), Ok(1));
+        assert_eq!(
+            choose_access_key(&keys[..1], &increment_transaction(), 2)
+                .unwrap_err()
+                .len(),
+            1
+        );
+    }
+}
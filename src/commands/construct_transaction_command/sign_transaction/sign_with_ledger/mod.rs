@@ -26,7 +26,7 @@ pub struct SignLedger {
     pub signer_public_key: near_crypto::PublicKey,
     nonce: u64,
     block_hash: near_primitives::hash::CryptoHash,
-    pub submit: Option<Submit>,
+    pub submit: Submit,
 }
 
 impl SignLedger {
@@ -38,10 +38,10 @@ impl SignLedger {
             Some(hd_path) => hd_path,
             None => SignLedger::input_seed_phrase_hd_path(),
         };
-        println!(
+        crate::common::print_message(&format!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
             seed_phrase_hd_path
-        );
+        ));
         let public_key = actix::System::new()
             .block_on(async { near_ledger::get_public_key(seed_phrase_hd_path.clone()).await })
             .map_err(|near_ledger_error| {
@@ -53,7 +53,10 @@ impl SignLedger {
         let signer_public_key = near_crypto::PublicKey::ED25519(
             near_crypto::ED25519PublicKey::from(public_key.to_bytes()),
         );
-        let submit: Option<Submit> = item.submit;
+        let submit: Submit = match item.submit {
+            Some(submit) => submit,
+            None => Submit::choose_submit(),
+        };
         match connection_config {
             Some(_) => Ok(Self {
                 seed_phrase_hd_path,
@@ -89,6 +92,7 @@ impl SignLedger {
     }
 
     pub fn input_seed_phrase_hd_path() -> slip10::BIP32Path {
+        crate::common::ensure_interactive("--seed-phrase-hd-path");
         Input::new()
             .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
             .with_initial_text("44'/397'/0'/0'/1'")
@@ -96,6 +100,20 @@ impl SignLedger {
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.submit.to_cli_args();
+        // The nonce and the block hash are only asked for in the offline mode
+        if self.block_hash != Default::default() {
+            args.push_front(self.block_hash.to_string());
+            args.push_front("--block-hash".to_owned());
+            args.push_front(self.nonce.to_string());
+            args.push_front("--nonce".to_owned());
+        }
+        args.push_front(self.seed_phrase_hd_path.to_string());
+        args.push_front("--seed-phrase-hd-path".to_owned());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -105,7 +123,7 @@ impl SignLedger {
         let public_key = self.signer_public_key.clone();
         let nonce = self.nonce.clone();
         let block_hash = self.block_hash.clone();
-        let submit: Option<Submit> = self.submit.clone();
+        let submit: Submit = self.submit.clone();
         match network_connection_config {
             None => {
                 let unsigned_transaction = near_primitives::transaction::Transaction {
@@ -114,12 +132,12 @@ impl SignLedger {
                     block_hash,
                     ..prepopulated_unsigned_transaction
                 };
-                println!("\nUnsigned transaction:\n");
+                crate::common::print_message("\nUnsigned transaction:\n");
                 crate::common::print_transaction(unsigned_transaction.clone());
-                println!(
+                crate::common::print_message(&format!(
                     "Confirm transaction signing on your Ledger device (HD Path: {})",
                     seed_phrase_hd_path,
-                );
+                ));
                 let signature = match near_ledger::sign_transaction(
                     unsigned_transaction
                         .try_to_vec()
@@ -150,16 +168,21 @@ impl SignLedger {
                         .try_to_vec()
                         .expect("Transaction is not expected to fail on serialization"),
                 );
-                println!("Your transaction was signed successfully.");
-                match submit {
-                    Some(submit) => submit.process_offline(serialize_to_base64),
-                    None => {
-                        let submit = Submit::choose_submit();
-                        submit.process_offline(serialize_to_base64)
-                    }
-                }
+                crate::common::print_message("Your transaction was signed successfully.");
+                submit.process_offline(serialize_to_base64)
             }
             Some(network_connection_config) => {
+                if crate::submission::submission_settings().dry_run {
+                    crate::submission::dry_run(
+                        &network_connection_config,
+                        &near_primitives::transaction::Transaction {
+                            public_key,
+                            ..prepopulated_unsigned_transaction
+                        },
+                    )
+                    .await?;
+                    return Ok(None);
+                }
                 let online_signer_access_key_response = self
                     .rpc_client(network_connection_config.rpc_url().as_str())
                     .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
@@ -171,7 +194,7 @@ impl SignLedger {
                     })
                     .await
                     .map_err(|err| {
-                        color_eyre::Report::msg(format!(
+                        crate::common::CliError::Rpc(format!(
                             "Failed to fetch public key information for nonce: {:?}",
                             err
                         ))
@@ -191,12 +214,12 @@ impl SignLedger {
                     nonce: current_nonce + 1,
                     ..prepopulated_unsigned_transaction
                 };
-                println!("\nUnsigned transaction:\n");
+                crate::common::print_message("\nUnsigned transaction:\n");
                 crate::common::print_transaction(unsigned_transaction.clone());
-                println!(
+                crate::common::print_message(&format!(
                     "Confirm transaction signing on your Ledger device (HD Path: {})",
                     seed_phrase_hd_path,
-                );
+                ));
                 let signature = match near_ledger::sign_transaction(
                     unsigned_transaction
                         .try_to_vec()
@@ -227,28 +250,14 @@ impl SignLedger {
                         .try_to_vec()
                         .expect("Transaction is not expected to fail on serialization"),
                 );
-                println!("Your transaction was signed successfully.");
-                match submit {
-                    None => {
-                        let submit = Submit::choose_submit();
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
-                    Some(submit) => {
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
-                }
+                crate::common::print_message("Your transaction was signed successfully.");
+                submit
+                    .process_online(
+                        network_connection_config,
+                        signed_transaction,
+                        serialize_to_base64,
+                    )
+                    .await
             }
         }
     }
@@ -266,6 +275,13 @@ pub enum Submit {
 }
 
 impl Submit {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Send => std::collections::VecDeque::from(vec!["send".to_owned()]),
+            Self::Display => std::collections::VecDeque::from(vec!["display".to_owned()]),
+        }
+    }
+
     pub fn choose_submit() -> Self {
         println!();
         let variants = SubmitDiscriminants::iter().collect::<Vec<_>>();
@@ -273,6 +289,7 @@ impl Submit {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("submit subcommand");
         let select_submit = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&submits)
@@ -289,7 +306,14 @@ impl Submit {
         self,
         serialize_to_base64: String,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
-        println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!("\nSerialize_to_base64:\n{}", &serialize_to_base64)
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("signed_transaction_base64", &serialize_to_base64)
+            }
+        }
         Ok(None)
     }
 
@@ -301,38 +325,25 @@ impl Submit {
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         match self {
             Submit::Send => {
-                println!("Transaction sent ...");
-                let json_rcp_client =
-                    near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
-                let transaction_info = loop {
-                    let transaction_info_result = json_rcp_client
-                        .broadcast_tx_commit(near_primitives::serialize::to_base64(
-                            signed_transaction
-                                .try_to_vec()
-                                .expect("Transaction is not expected to fail on serialization"),
-                        ))
-                        .await;
-                    match transaction_info_result {
-                        Ok(response) => {
-                            break response;
-                        }
-                        Err(err) => {
-                            if let Some(serde_json::Value::String(data)) = &err.data {
-                                if data.contains("Timeout") {
-                                    println!("Timeout error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
-                                    continue;
-                                } else {
-                                    println!("Error transaction: {:#?}", err)
-                                }
-                            };
-                            return Ok(None);
-                        }
-                    };
-                };
+                // Signing again would take another confirmation on the Ledger device
+                let transaction_info = crate::submission::send_transaction(
+                    &network_connection_config,
+                    signed_transaction,
+                    None,
+                )
+                .await?;
                 Ok(Some(transaction_info))
             }
             Submit::Display => {
-                println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+                match crate::common::output_format() {
+                    crate::common::OutputFormat::Plaintext => {
+                        println!("\nSerialize_to_base64:\n{}", &serialize_to_base64)
+                    }
+                    crate::common::OutputFormat::Json => crate::common::set_json_output(
+                        "signed_transaction_base64",
+                        &serialize_to_base64,
+                    ),
+                }
                 Ok(None)
             }
         }
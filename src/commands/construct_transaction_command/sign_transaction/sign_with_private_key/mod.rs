@@ -28,7 +28,7 @@ pub struct SignPrivateKey {
     pub signer_secret_key: near_crypto::SecretKey,
     pub nonce: u64,
     pub block_hash: near_primitives::hash::CryptoHash,
-    pub submit: Option<Submit>,
+    pub submit: Submit,
 }
 
 impl SignPrivateKey {
@@ -44,7 +44,10 @@ impl SignPrivateKey {
             Some(cli_secret_key) => cli_secret_key,
             None => super::input_signer_secret_key(),
         };
-        let submit: Option<Submit> = item.submit;
+        let submit: Submit = match item.submit {
+            Some(submit) => submit,
+            None => Submit::choose_submit(),
+        };
         match connection_config {
             Some(_) => Self {
                 signer_public_key,
@@ -73,7 +76,9 @@ impl SignPrivateKey {
                         submit,
                     }
                 } else {
-                    println!("\nError: The key pair does not match. Re-enter the keys.\n");
+                    crate::common::print_message(
+                        "\nError: The key pair does not match. Re-enter the keys.\n",
+                    );
                     let signer_public_key: near_crypto::PublicKey =
                         super::input_signer_public_key();
                     let signer_secret_key: near_crypto::SecretKey =
@@ -84,7 +89,7 @@ impl SignPrivateKey {
                             signer_secret_key: Some(signer_secret_key),
                             nonce: Some(nonce),
                             block_hash: Some(block_hash),
-                            submit: None,
+                            submit: Some(submit),
                         },
                         connection_config,
                     )
@@ -99,6 +104,22 @@ impl SignPrivateKey {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.submit.to_cli_args();
+        // The nonce and the block hash are only asked for in the offline mode
+        if self.block_hash != Default::default() {
+            args.push_front(self.block_hash.to_string());
+            args.push_front("--block-hash".to_owned());
+            args.push_front(self.nonce.to_string());
+            args.push_front("--nonce".to_owned());
+        }
+        args.push_front(crate::common::SECRET_PLACEHOLDER.to_owned());
+        args.push_front("--signer-secret-key".to_owned());
+        args.push_front(self.signer_public_key.to_string());
+        args.push_front("--signer-public-key".to_owned());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -108,7 +129,7 @@ impl SignPrivateKey {
         let signer_secret_key: near_crypto::SecretKey = self.signer_secret_key.clone();
         let nonce: u64 = self.nonce.clone();
         let block_hash: near_primitives::hash::CryptoHash = self.block_hash.clone();
-        let submit: Option<Submit> = self.submit.clone();
+        let submit: Submit = self.submit.clone();
         match network_connection_config {
             None => {
                 let unsigned_transaction = near_primitives::transaction::Transaction {
@@ -128,18 +149,23 @@ impl SignPrivateKey {
                         .try_to_vec()
                         .expect("Transaction is not expected to fail on serialization"),
                 );
-                println!("\nSigned transaction:\n");
+                crate::common::print_message("\nSigned transaction:\n");
                 crate::common::print_transaction(signed_transaction.transaction.clone());
-                println!("Your transaction was signed successfully.");
-                match submit {
-                    Some(submit) => submit.process_offline(serialize_to_base64),
-                    None => {
-                        let submit = Submit::choose_submit();
-                        submit.process_offline(serialize_to_base64)
-                    }
-                }
+                crate::common::print_message("Your transaction was signed successfully.");
+                submit.process_offline(serialize_to_base64)
             }
             Some(network_connection_config) => {
+                if crate::submission::submission_settings().dry_run {
+                    crate::submission::dry_run(
+                        &network_connection_config,
+                        &near_primitives::transaction::Transaction {
+                            public_key,
+                            ..prepopulated_unsigned_transaction
+                        },
+                    )
+                    .await?;
+                    return Ok(None);
+                }
                 let online_signer_access_key_response = self
                     .rpc_client(network_connection_config.rpc_url().as_str())
                     .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
@@ -151,7 +177,7 @@ impl SignPrivateKey {
                     })
                     .await
                     .map_err(|err| {
-                        color_eyre::Report::msg(format!(
+                        crate::common::CliError::Rpc(format!(
                             "Failed to fetch public key information for nonce: {:?}",
                             err
                         ))
@@ -182,30 +208,17 @@ impl SignPrivateKey {
                         .try_to_vec()
                         .expect("Transaction is not expected to fail on serialization"),
                 );
-                println!("\nSigned transaction:\n");
+                crate::common::print_message("\nSigned transaction:\n");
                 crate::common::print_transaction(signed_transaction.transaction.clone());
-                println!("Your transaction was signed successfully.");
-                match submit {
-                    None => {
-                        let submit = Submit::choose_submit();
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
-                    Some(submit) => {
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
-                }
+                crate::common::print_message("Your transaction was signed successfully.");
+                submit
+                    .process_online(
+                        network_connection_config,
+                        signed_transaction,
+                        serialize_to_base64,
+                        Some(&signer_secret_key),
+                    )
+                    .await
             }
         }
     }
@@ -223,6 +236,13 @@ pub enum Submit {
 }
 
 impl Submit {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Send => std::collections::VecDeque::from(vec!["send".to_owned()]),
+            Self::Display => std::collections::VecDeque::from(vec!["display".to_owned()]),
+        }
+    }
+
     pub fn choose_submit() -> Self {
         println!();
         let variants = SubmitDiscriminants::iter().collect::<Vec<_>>();
@@ -230,6 +250,7 @@ impl Submit {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("submit subcommand");
         let select_submit = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&submits)
@@ -246,7 +267,14 @@ impl Submit {
         self,
         serialize_to_base64: String,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
-        println!("Srialize_to_base64:\n{}", &serialize_to_base64);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!("Srialize_to_base64:\n{}", &serialize_to_base64)
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("signed_transaction_base64", &serialize_to_base64)
+            }
+        }
         Ok(None)
     }
 
@@ -255,41 +283,28 @@ impl Submit {
         network_connection_config: crate::common::ConnectionConfig,
         signed_transaction: near_primitives::transaction::SignedTransaction,
         serialize_to_base64: String,
+        signer_secret_key: Option<&near_crypto::SecretKey>,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         match self {
             Submit::Send => {
-                println!("Transaction sent ...");
-                let json_rcp_client =
-                    near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
-                let transaction_info = loop {
-                    let transaction_info_result = json_rcp_client
-                        .broadcast_tx_commit(near_primitives::serialize::to_base64(
-                            signed_transaction
-                                .try_to_vec()
-                                .expect("Transaction is not expected to fail on serialization"),
-                        ))
-                        .await;
-                    match transaction_info_result {
-                        Ok(response) => {
-                            break response;
-                        }
-                        Err(err) => {
-                            if let Some(serde_json::Value::String(data)) = &err.data {
-                                if data.contains("Timeout") {
-                                    println!("Timeout error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
-                                    continue;
-                                } else {
-                                    println!("Error transaction: {:#?}", err)
-                                }
-                            };
-                            return Ok(None);
-                        }
-                    };
-                };
+                let transaction_info = crate::submission::send_transaction(
+                    &network_connection_config,
+                    signed_transaction,
+                    signer_secret_key,
+                )
+                .await?;
                 Ok(Some(transaction_info))
             }
             Submit::Display => {
-                println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+                match crate::common::output_format() {
+                    crate::common::OutputFormat::Plaintext => {
+                        println!("\nSerialize_to_base64:\n{}", &serialize_to_base64)
+                    }
+                    crate::common::OutputFormat::Json => crate::common::set_json_output(
+                        "signed_transaction_base64",
+                        &serialize_to_base64,
+                    ),
+                }
                 Ok(None)
             }
         }
@@ -41,6 +41,10 @@ impl FullAccessType {
 }
 
 impl FullAccessType {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.next_action.to_cli_args()
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
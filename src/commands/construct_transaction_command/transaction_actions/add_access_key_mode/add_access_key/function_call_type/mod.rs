@@ -82,6 +82,7 @@ impl FunctionCallType {
             "Yes, I want to input a list of method names that can be used",
             "No, I don't to input a list of method names that can be used",
         ];
+        crate::common::ensure_interactive("--method-names");
         let select_choose_input = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Do You want to input a list of method names that can be used")
             .items(&choose_input)
@@ -117,6 +118,7 @@ impl FunctionCallType {
             "Yes, I want to input allowance for receiver ID",
             "No, I don't to input allowance for receiver ID",
         ];
+        crate::common::ensure_interactive("--allowance");
         let select_choose_input = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Do You want to input an allowance for receiver ID")
             .items(&choose_input)
@@ -138,12 +140,26 @@ impl FunctionCallType {
 
     pub fn input_receiver_id() -> near_primitives::types::AccountId {
         println!();
+        crate::common::ensure_interactive("--receiver-id");
         Input::new()
             .with_prompt("Enter a receiver to use by this access key to pay for function call gas and transaction fees.")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(self.method_names.join(","));
+        args.push_front("--method-names".to_owned());
+        args.push_front(self.receiver_id.to_string());
+        args.push_front("--receiver-id".to_owned());
+        if let Some(allowance) = &self.allowance {
+            args.push_front(crate::common::NearBalance::from_yoctonear(*allowance).to_cli_arg());
+            args.push_front("--allowance".to_owned());
+        }
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
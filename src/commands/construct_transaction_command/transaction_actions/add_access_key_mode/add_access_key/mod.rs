@@ -57,6 +57,7 @@ impl AddAccessKeyAction {
 
 impl AddAccessKeyAction {
     fn input_access_key_nonce() -> near_primitives::types::Nonce {
+        crate::common::ensure_interactive("--nonce");
         Input::new()
             .with_prompt("Enter the nonce for this access key")
             .interact_text()
@@ -64,12 +65,21 @@ impl AddAccessKeyAction {
     }
 
     fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("<public-key>");
         Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.permission.to_cli_args();
+        args.push_front(self.nonce.to_string());
+        args.push_front("--nonce".to_owned());
+        args.push_front(self.public_key.to_string());
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
@@ -157,6 +167,7 @@ impl AccessKeyPermission {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("permission subcommand");
         let select_permission = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&permissions)
@@ -177,3 +188,20 @@ impl AccessKeyPermission {
         }
     }
 }
+
+impl AccessKeyPermission {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::GrantFunctionCallAccess(grant_function_call_access) => {
+                let mut args = grant_function_call_access.to_cli_args();
+                args.push_front("grant-function-call-access".to_owned());
+                args
+            }
+            Self::GrantFullAccess(grant_full_access) => {
+                let mut args = grant_full_access.to_cli_args();
+                args.push_front("grant-full-access".to_owned());
+                args
+            }
+        }
+    }
+}
@@ -40,6 +40,10 @@ impl GenerateKeypair {
 }
 
 impl GenerateKeypair {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.permission.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -51,10 +55,14 @@ impl GenerateKeypair {
             network_connection_config.clone(),
             key_pair_properties.clone(),
             &prepopulated_unsigned_transaction.receiver_id,
+            None,
         )
         .await
         .map_err(|err| {
-            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+            crate::common::CliError::Keychain(format!(
+                "Failed to save a file with access key: {}",
+                err
+            ))
         })?;
 
         match self.permission {
@@ -38,6 +38,10 @@ impl AddAccessKeyMode {
 }
 
 impl AddAccessKeyMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.public_key_mode.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -101,6 +105,7 @@ impl PublicKeyMode {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("public-key-mode subcommand");
         let select_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
@@ -121,6 +126,21 @@ impl PublicKeyMode {
         }
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::PublicKey(public_key) => {
+                let mut args = public_key.to_cli_args();
+                args.push_front("public-key".to_owned());
+                args
+            }
+            Self::GenerateKeypair(generate_keypair) => {
+                let mut args = generate_keypair.to_cli_args();
+                args.push_front("generate-keypair".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -11,8 +11,11 @@ use dialoguer::Input;
 pub struct CliCallFunctionAction {
     #[clap(long)]
     method_name: Option<String>,
-    #[clap(long)]
+    /// The args for function as JSON
+    #[clap(long, conflicts_with_all = crate::function_args::ARGS_SOURCE_FLAGS)]
     args: Option<String>,
+    #[clap(flatten)]
+    args_source: crate::function_args::CliArgsSource,
     #[clap(long)]
     gas: Option<crate::common::NearGas>,
     #[clap(long)]
@@ -24,7 +27,7 @@ pub struct CliCallFunctionAction {
 #[derive(Debug)]
 pub struct CallFunctionAction {
     method_name: String,
-    args: Vec<u8>,
+    args: crate::function_args::FunctionArgs,
     gas: near_primitives::types::Gas,
     deposit: near_primitives::types::Balance,
     next_action: Box<super::NextAction>,
@@ -40,10 +43,7 @@ impl CallFunctionAction {
             Some(cli_method_name) => cli_method_name,
             None => CallFunctionAction::input_method_name(),
         };
-        let args: Vec<u8> = match item.args {
-            Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionAction::input_args(),
-        };
+        let args = crate::function_args::FunctionArgs::from(item.args, item.args_source, "--args");
         let gas: near_primitives::types::Gas = match item.gas {
             Some(cli_gas) => match cli_gas {
                 crate::common::NearGas { inner: num } => num,
@@ -75,6 +75,7 @@ impl CallFunctionAction {
 impl CallFunctionAction {
     fn input_method_name() -> String {
         println!();
+        crate::common::ensure_interactive("--method-name");
         Input::new()
             .with_prompt("Enter a method name")
             .interact_text()
@@ -84,6 +85,7 @@ impl CallFunctionAction {
     fn input_gas() -> near_primitives::types::Gas {
         println!();
         let gas: u64 = loop {
+            crate::common::ensure_interactive("--gas");
             let input_gas: crate::common::NearGas = Input::new()
                 .with_prompt("Enter a gas for function")
                 .with_initial_text("100 TeraGas")
@@ -101,17 +103,9 @@ impl CallFunctionAction {
         gas
     }
 
-    fn input_args() -> Vec<u8> {
-        println!();
-        let input: String = Input::new()
-            .with_prompt("Enter args for function")
-            .interact_text()
-            .unwrap();
-        input.into_bytes()
-    }
-
     fn input_deposit() -> near_primitives::types::Balance {
         println!();
+        crate::common::ensure_interactive("--deposit");
         let deposit: crate::common::NearBalance = Input::new()
             .with_prompt(
                 "Enter a deposit for function (example: 10NEAR or 0.5near or 10000yoctonear).",
@@ -122,6 +116,23 @@ impl CallFunctionAction {
         deposit.to_yoctonear()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(crate::common::NearBalance::from_yoctonear(self.deposit).to_cli_arg());
+        args.push_front("--deposit".to_owned());
+        args.push_front(crate::common::NearGas { inner: self.gas }.to_cli_arg());
+        args.push_front("--gas".to_owned());
+        for arg in self.args.to_cli_args().into_iter().rev() {
+            args.push_front(arg);
+        }
+        if let crate::function_args::FunctionArgs::Json(_) = self.args {
+            args.push_front("--args".to_owned());
+        }
+        args.push_front(self.method_name.to_string());
+        args.push_front("--method-name".to_owned());
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
@@ -131,7 +142,7 @@ impl CallFunctionAction {
         let action = near_primitives::transaction::Action::FunctionCall(
             near_primitives::transaction::FunctionCallAction {
                 method_name: self.method_name.clone(),
-                args: self.args.clone(),
+                args: self.args.to_bytes()?,
                 gas: self.gas.clone(),
                 deposit: self.deposit.clone(),
             },
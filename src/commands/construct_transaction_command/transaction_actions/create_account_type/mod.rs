@@ -38,6 +38,10 @@ impl CreateAccountAction {
 }
 
 impl CreateAccountAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.next_action.to_cli_args()
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
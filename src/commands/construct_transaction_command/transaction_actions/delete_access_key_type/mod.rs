@@ -48,12 +48,20 @@ impl DeleteAccessKeyAction {
 
 impl DeleteAccessKeyAction {
     pub fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("--public-key");
         Input::new()
             .with_prompt("Enter the access key to remove it")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(self.public_key.to_string());
+        args.push_front("--public-key".to_owned());
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
@@ -49,12 +49,20 @@ impl DeleteAccountAction {
 impl DeleteAccountAction {
     pub fn input_beneficiary_id() -> near_primitives::types::AccountId {
         println!();
+        crate::common::ensure_interactive("--beneficiary-id");
         Input::new()
             .with_prompt("Enter the beneficiary ID to delete this account ID")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(self.beneficiary_id.to_string());
+        args.push_front("--beneficiary-id".to_owned());
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
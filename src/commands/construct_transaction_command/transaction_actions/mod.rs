@@ -89,6 +89,7 @@ impl NextAction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("next-action subcommand");
         let select_next_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&next_action)
@@ -106,6 +107,21 @@ impl NextAction {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AddAction(add_action) => {
+                let mut args = add_action.to_cli_args();
+                args.push_front("add-action".to_owned());
+                args
+            }
+            Self::Skip(skip) => {
+                let mut args = skip.to_cli_args();
+                args.push_front("skip".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -164,6 +180,10 @@ impl SelectAction {
 }
 
 impl SelectAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.transaction_subcommand.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -292,6 +312,7 @@ impl ActionSubcommand {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action-command subcommand");
         let select_action_subcommand = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&action_subcommands)
@@ -324,6 +345,46 @@ impl ActionSubcommand {
         Self::from(cli_action_subcomand, connection_config, sender_account_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::TransferNEARTokens(transfer_near_tokens) => {
+                let mut args = transfer_near_tokens.to_cli_args();
+                args.push_front("transfer-near-tokens".to_owned());
+                args
+            }
+            Self::CallFunction(call_function) => {
+                let mut args = call_function.to_cli_args();
+                args.push_front("call-function".to_owned());
+                args
+            }
+            Self::StakeNEARTokens(stake_near_tokens) => {
+                let mut args = stake_near_tokens.to_cli_args();
+                args.push_front("stake-near-tokens".to_owned());
+                args
+            }
+            Self::CreateAccount(create_account) => {
+                let mut args = create_account.to_cli_args();
+                args.push_front("create-account".to_owned());
+                args
+            }
+            Self::DeleteAccount(delete_account) => {
+                let mut args = delete_account.to_cli_args();
+                args.push_front("delete-account".to_owned());
+                args
+            }
+            Self::AddAccessKey(add_access_key) => {
+                let mut args = add_access_key.to_cli_args();
+                args.push_front("add-access-key".to_owned());
+                args
+            }
+            Self::DeleteAccessKey(delete_access_key) => {
+                let mut args = delete_access_key.to_cli_args();
+                args.push_front("delete-access-key".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -408,6 +469,10 @@ impl SkipAction {
 }
 
 impl SkipAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.sign_option.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -426,7 +491,7 @@ impl SkipAction {
                     transaction_info,
                     network_connection_config,
                 )
-                .await;
+                .await?;
             }
             None => {}
         };
@@ -54,6 +54,7 @@ impl StakeNEARTokensAction {
 
 impl StakeNEARTokensAction {
     fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("<public-key>");
         Input::new()
             .with_prompt("Enter a public key for this stake")
             .interact_text()
@@ -61,12 +62,20 @@ impl StakeNEARTokensAction {
     }
 
     fn input_stake() -> crate::common::NearBalance {
+        crate::common::ensure_interactive("<stake>");
         Input::new()
             .with_prompt("How many NEAR Tokens do you want to stake?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(self.public_key.to_string());
+        args.push_front(self.stake.to_cli_arg());
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
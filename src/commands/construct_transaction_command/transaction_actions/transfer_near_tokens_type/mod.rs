@@ -47,12 +47,19 @@ impl TransferNEARTokensAction {
 
 impl TransferNEARTokensAction {
     fn input_amount() -> crate::common::NearBalance {
+        crate::common::ensure_interactive("<amount>");
         Input::new()
             .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.next_action.to_cli_args();
+        args.push_front(self.amount.to_cli_arg());
+        args
+    }
+
     #[async_recursion(?Send)]
     pub async fn process(
         self,
@@ -44,6 +44,16 @@ impl DeleteAccessKeyAction {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::PublicKey(public_key) => {
+                let mut args = public_key.to_cli_args();
+                args.push_front("public-key".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -104,12 +114,19 @@ impl DeleteAccessKeyType {
 
 impl DeleteAccessKeyType {
     fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("<public-key>");
         Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_option.to_cli_args();
+        args.push_front(self.public_key.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -136,7 +153,7 @@ impl DeleteAccessKeyType {
                     transaction_info,
                     network_connection_config,
                 )
-                .await;
+                .await?;
             }
             None => {}
         };
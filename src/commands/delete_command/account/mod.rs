@@ -49,12 +49,19 @@ impl DeleteAccountAction {
 impl DeleteAccountAction {
     pub fn input_beneficiary_id() -> near_primitives::types::AccountId {
         println!();
+        crate::common::ensure_interactive("<beneficiary-id>");
         Input::new()
             .with_prompt("Enter the beneficiary ID to delete this account ID")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_option.to_cli_args();
+        args.push_front(self.beneficiary_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -80,7 +87,7 @@ impl DeleteAccountAction {
                     transaction_info,
                     network_connection_config,
                 )
-                .await;
+                .await?;
             }
             None => {}
         };
@@ -22,6 +22,15 @@ pub struct CliServer {
 pub struct CliCustomServer {
     #[clap(long)]
     pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
     #[clap(subcommand)]
     send_from: Option<CliSendFrom>,
 }
@@ -52,12 +61,20 @@ impl CliCustomServer {
     pub fn into_server(self) -> color_eyre::eyre::Result<Server> {
         let url: crate::common::AvailableRpcServerUrl = match self.url {
             Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the RPC endpoint?")
-                .interact_text()
-                .unwrap(),
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
         };
-        let connection_config = Some(crate::common::ConnectionConfig::Custom { url: url.inner });
+        let connection_config = Some(crate::common::ConnectionConfig::custom(
+            url.inner,
+            self.archival_url,
+            self.wallet_url,
+            self.explorer_url,
+        ));
         let send_from = match self.send_from {
             Some(cli_send_from) => SendFrom::from(cli_send_from, connection_config.clone())?,
             None => SendFrom::choose_send_from(connection_config.clone())?,
@@ -70,6 +87,16 @@ impl CliCustomServer {
 }
 
 impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .connection_config
+            .as_ref()
+            .map(crate::common::ConnectionConfig::to_cli_args)
+            .unwrap_or_default();
+        args.extend(self.send_from.to_cli_args());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -114,6 +141,16 @@ impl SendFrom {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
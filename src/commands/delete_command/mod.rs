@@ -32,6 +32,10 @@ impl DeleteAction {
 }
 
 impl DeleteAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -61,10 +65,10 @@ impl Action {
     fn from(item: CliAction) -> color_eyre::eyre::Result<Self> {
         match item {
             CliAction::AccessKey(cli_operation_mode) => Ok(Action::AccessKey(
-                self::access_key::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::access_key::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::Account(cli_operation_mode) => Ok(Action::Account(
-                self::account::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::account::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
         }
     }
@@ -78,6 +82,7 @@ impl Action {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action subcommand");
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to delete")
             .items(&actions)
@@ -91,6 +96,21 @@ impl Action {
         Ok(Self::from(cli_action)?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AccessKey(access_key) => {
+                let mut args = access_key.to_cli_args();
+                args.push_front("access-key".to_owned());
+                args
+            }
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
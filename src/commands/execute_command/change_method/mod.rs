@@ -45,6 +45,7 @@ impl CallFunction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("call-function subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Call your function")
             .items(&commands)
@@ -57,6 +58,16 @@ impl CallFunction {
         Ok(Self::from(cli_call, connection_config)?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Call(call) => {
+                let mut args = call.to_cli_args();
+                args.push_front("call".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -32,6 +32,10 @@ impl OperationMode {
 }
 
 impl OperationMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.mode.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -80,6 +84,7 @@ impl Mode {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("mode subcommand");
         let selected_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt(
                 "To construct a transaction you will need to provide information about sender (signer) and receiver accounts, and actions that needs to be performed.
@@ -96,6 +101,21 @@ impl Mode {
         Ok(Self::from(cli_mode)?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Network(network) => {
+                let mut args = network.to_cli_args();
+                args.push_front("network".to_owned());
+                args
+            }
+            Self::Offline(offline) => {
+                let mut args = offline.to_cli_args();
+                args.push_front("offline".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
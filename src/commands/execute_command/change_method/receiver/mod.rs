@@ -35,6 +35,16 @@ impl SendTo {
         )?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Contract(contract) => {
+                let mut args = contract.to_cli_args();
+                args.push_front("contract".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -91,12 +101,19 @@ impl Receiver {
 
 impl Receiver {
     fn input_receiver_account_id() -> String {
+        crate::common::ensure_interactive("<receiver-account-id>");
         Input::new()
             .with_prompt("What is the account ID of the contract?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.call.to_cli_args();
+        args.push_front(self.receiver_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
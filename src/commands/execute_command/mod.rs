@@ -21,17 +21,21 @@ pub struct OptionMethod {
     method: Method,
 }
 
-impl From<CliOptionMethod> for OptionMethod {
-    fn from(item: CliOptionMethod) -> Self {
+impl OptionMethod {
+    pub fn from(item: CliOptionMethod) -> color_eyre::eyre::Result<Self> {
         let method = match item.method {
-            Some(cli_method) => Method::from(cli_method),
-            None => Method::choose_method(),
+            Some(cli_method) => Method::from(cli_method)?,
+            None => Method::choose_method()?,
         };
-        Self { method }
+        Ok(Self { method })
     }
 }
 
 impl OptionMethod {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.method.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -57,28 +61,28 @@ enum Method {
     ViewMethod(self::view_method::operation_mode::OperationMode),
 }
 
-impl From<CliMethod> for Method {
-    fn from(item: CliMethod) -> Self {
+impl Method {
+    fn from(item: CliMethod) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliMethod::ChangeMethod(cli_operation_mode) => Method::ChangeMethod(
-                self::change_method::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
-            ),
-            CliMethod::ViewMethod(cli_operation_mode) => {
-                Method::ViewMethod(cli_operation_mode.into())
-            }
+            CliMethod::ChangeMethod(cli_operation_mode) => Ok(Method::ChangeMethod(
+                self::change_method::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliMethod::ViewMethod(cli_operation_mode) => Ok(Method::ViewMethod(
+                self::view_method::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
         }
     }
 }
 
 impl Method {
-    fn choose_method() -> Self {
+    fn choose_method() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = MethodDiscriminants::iter().collect::<Vec<_>>();
         let methods = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("method subcommand");
         let selected_method = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your method")
             .items(&methods)
@@ -92,6 +96,21 @@ impl Method {
         Self::from(cli_method)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::ChangeMethod(change_method) => {
+                let mut args = change_method.to_cli_args();
+                args.push_front("change-method".to_owned());
+                args
+            }
+            Self::ViewMethod(view_method) => {
+                let mut args = view_method.to_cli_args();
+                args.push_front("view-method".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -23,6 +23,7 @@ impl From<CliBlockIdHash> for BlockIdHash {
 
 impl BlockIdHash {
     pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
+        crate::common::ensure_interactive("<block-id-hash>");
         Input::new()
             .with_prompt("Type the block ID hash for this contract")
             .interact_text()
@@ -33,12 +34,17 @@ impl BlockIdHash {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.block_id_hash.to_string()])
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
         contract_account_id: String,
         method_name: String,
         args: Vec<u8>,
+        result_output: super::super::result_format::ResultOutput,
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
@@ -56,25 +62,19 @@ impl BlockIdHash {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!("Failed to fetch query for view method: {:?}", err))
+                crate::common::CliError::Rpc(format!(
+                    "Failed to fetch query for view method: {:?}",
+                    err
+                ))
             })?;
         let call_result =
             if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
                 query_view_method_response.kind
             {
-                result.result
+                result
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
-        let call_result_str = String::from_utf8(call_result).unwrap();
-        let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
-            .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
-        println!("--------------");
-        println!();
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&serde_call_result).unwrap()
-        );
-        Ok(())
+        result_output.print(call_result)
     }
 }
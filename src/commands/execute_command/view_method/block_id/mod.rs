@@ -47,6 +47,7 @@ impl BlockId {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&blocks)
@@ -61,12 +62,31 @@ impl BlockId {
         Self::from(cli_block_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AtFinalBlock => {
+                std::collections::VecDeque::from(vec!["at-final-block".to_owned()])
+            }
+            Self::AtBlockHeight(at_block_height) => {
+                let mut args = at_block_height.to_cli_args();
+                args.push_front("at-block-height".to_owned());
+                args
+            }
+            Self::AtBlockHash(at_block_hash) => {
+                let mut args = at_block_hash.to_cli_args();
+                args.push_front("at-block-hash".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         contract_account_id: String,
         network_connection_config: crate::common::ConnectionConfig,
         method_name: String,
         args: Vec<u8>,
+        result_output: super::result_format::ResultOutput,
     ) -> crate::CliResult {
         println!();
         match self {
@@ -77,6 +97,7 @@ impl BlockId {
                         contract_account_id,
                         method_name,
                         args,
+                        result_output,
                     )
                     .await
             }
@@ -87,6 +108,7 @@ impl BlockId {
                         contract_account_id,
                         method_name,
                         args,
+                        result_output,
                     )
                     .await
             }
@@ -96,6 +118,7 @@ impl BlockId {
                     contract_account_id,
                     method_name,
                     args,
+                    result_output,
                 )
                 .await
             }
@@ -112,6 +135,7 @@ impl BlockId {
         contract_account_id: String,
         method_name: String,
         args: Vec<u8>,
+        result_output: super::result_format::ResultOutput,
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
@@ -127,25 +151,19 @@ impl BlockId {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!("Failed to fetch query for view method: {:?}", err))
+                crate::common::CliError::Rpc(format!(
+                    "Failed to fetch query for view method: {:?}",
+                    err
+                ))
             })?;
         let call_result =
             if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
                 query_view_method_response.kind
             {
-                result.result
+                result
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
-        let call_result_str = String::from_utf8(call_result).unwrap();
-        let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
-            .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
-        println!("--------------");
-        println!();
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&serde_call_result).unwrap()
-        );
-        Ok(())
+        result_output.print(call_result)
     }
 }
@@ -9,7 +9,17 @@ use dialoguer::Input;
 )]
 pub struct CliCallFunctionView {
     method_name: Option<String>,
+    /// The args for function as JSON
+    #[clap(conflicts_with_all = crate::function_args::ARGS_SOURCE_FLAGS)]
     args: Option<String>,
+    #[clap(flatten)]
+    args_source: crate::function_args::CliArgsSource,
+    /// How to show the result: auto, json, utf8, base64, hex or raw (written to --result-file)
+    #[clap(long, default_value = "auto")]
+    result_format: super::result_format::ResultFormat,
+    /// The file for the raw result (only with --result-format raw)
+    #[clap(long)]
+    result_file: Option<std::path::PathBuf>,
     #[clap(subcommand)]
     selected_block_id: Option<super::block_id::CliBlockId>,
 }
@@ -17,48 +27,53 @@ pub struct CliCallFunctionView {
 #[derive(Debug)]
 pub struct CallFunctionView {
     method_name: String,
-    args: Vec<u8>,
+    args: crate::function_args::FunctionArgs,
+    result_output: super::result_format::ResultOutput,
     selected_block_id: super::block_id::BlockId,
 }
 
-impl From<CliCallFunctionView> for CallFunctionView {
-    fn from(item: CliCallFunctionView) -> Self {
+impl CallFunctionView {
+    pub fn from(item: CliCallFunctionView) -> color_eyre::eyre::Result<Self> {
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
             None => CallFunctionView::input_method_name(),
         };
-        let args: Vec<u8> = match item.args {
-            Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionView::input_args(),
-        };
+        let args = crate::function_args::FunctionArgs::from(item.args, item.args_source, "<args>");
+        let result_output =
+            super::result_format::ResultOutput::from(item.result_format, item.result_file)?;
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
             Some(cli_block_id) => cli_block_id.into(),
             None => super::block_id::BlockId::choose_block_id(),
         };
-        Self {
+        Ok(Self {
             method_name,
             args,
+            result_output,
             selected_block_id,
-        }
+        })
     }
 }
 
 impl CallFunctionView {
     fn input_method_name() -> String {
         println!();
+        crate::common::ensure_interactive("<method-name>");
         Input::new()
             .with_prompt("Enter a method name")
             .interact_text()
             .unwrap()
     }
 
-    fn input_args() -> Vec<u8> {
-        println!();
-        let input: String = Input::new()
-            .with_prompt("Enter args for function")
-            .interact_text()
-            .unwrap();
-        input.into_bytes()
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_block_id.to_cli_args();
+        for arg in self.result_output.to_cli_args().into_iter().rev() {
+            args.push_front(arg);
+        }
+        for arg in self.args.to_cli_args().into_iter().rev() {
+            args.push_front(arg);
+        }
+        args.push_front(self.method_name.to_string());
+        args
     }
 
     pub async fn process(
@@ -71,7 +86,8 @@ impl CallFunctionView {
                 contract_account_id,
                 network_connection_config,
                 self.method_name,
-                self.args,
+                self.args.to_bytes()?,
+                self.result_output,
             )
             .await
     }
@@ -5,6 +5,7 @@ mod block_id;
 mod call_function_type;
 pub mod operation_mode;
 mod receiver;
+mod result_format;
 
 #[derive(Debug, clap::Clap)]
 pub enum CliCallFunction {
@@ -19,24 +20,25 @@ pub enum CallFunction {
     Call(self::call_function_type::CallFunctionView),
 }
 
-impl From<CliCallFunction> for CallFunction {
-    fn from(item: CliCallFunction) -> Self {
+impl CallFunction {
+    pub fn from(item: CliCallFunction) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliCallFunction::Call(cli_call_function_view) => {
-                CallFunction::Call(cli_call_function_view.into())
-            }
+            CliCallFunction::Call(cli_call_function_view) => Ok(CallFunction::Call(
+                self::call_function_type::CallFunctionView::from(cli_call_function_view)?,
+            )),
         }
     }
 }
 
 impl CallFunction {
-    pub fn choose_call_function() -> Self {
+    pub fn choose_call_function() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = CallFunctionDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("call-function subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Call your function")
             .items(&commands)
@@ -49,6 +51,16 @@ impl CallFunction {
         Self::from(cli_call)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Call(call) => {
+                let mut args = call.to_cli_args();
+                args.push_front("call".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
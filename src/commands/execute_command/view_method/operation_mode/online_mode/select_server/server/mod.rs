@@ -22,6 +22,15 @@ pub struct CliServer {
 pub struct CliCustomServer {
     #[clap(long)]
     pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
     #[clap(subcommand)]
     send_to: Option<super::super::super::super::receiver::CliSendTo>,
 }
@@ -33,39 +42,56 @@ pub struct Server {
 }
 
 impl CliServer {
-    pub fn into_server(self, network_connection_config: crate::common::ConnectionConfig) -> Server {
+    pub fn into_server(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Server> {
         let send_to = match self.send_to {
-            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(cli_send_to),
-            None => super::super::super::super::receiver::SendTo::send_to(),
+            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(cli_send_to)?,
+            None => super::super::super::super::receiver::SendTo::send_to()?,
         };
-        Server {
+        Ok(Server {
             network_connection_config,
             send_to,
-        }
+        })
     }
 }
 
 impl CliCustomServer {
-    pub fn into_server(self) -> Server {
+    pub fn into_server(self) -> color_eyre::eyre::Result<Server> {
         let url: crate::common::AvailableRpcServerUrl = match self.url {
             Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the RPC endpoint?")
-                .interact_text()
-                .unwrap(),
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
         };
         let send_to = match self.send_to {
-            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(cli_send_to),
-            None => super::super::super::super::receiver::SendTo::send_to(),
+            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(cli_send_to)?,
+            None => super::super::super::super::receiver::SendTo::send_to()?,
         };
-        Server {
-            network_connection_config: crate::common::ConnectionConfig::Custom { url: url.inner },
+        Ok(Server {
+            network_connection_config: crate::common::ConnectionConfig::custom(
+                url.inner,
+                self.archival_url,
+                self.wallet_url,
+                self.explorer_url,
+            ),
             send_to,
-        }
+        })
     }
 }
 
 impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.network_connection_config.to_cli_args();
+        args.extend(self.send_to.to_cli_args());
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
         self.send_to.process(self.network_connection_config).await
     }
@@ -11,22 +11,32 @@ pub enum SendTo {
     Contract(Receiver),
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
+impl SendTo {
+    pub fn from(item: CliSendTo) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSendTo::Contract(cli_receiver) => {
-                let receiver = Receiver::from(cli_receiver);
-                Self::Contract(receiver)
+                let receiver = Receiver::from(cli_receiver)?;
+                Ok(Self::Contract(receiver))
             }
         }
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
         Self::from(CliSendTo::Contract(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Contract(contract) => {
+                let mut args = contract.to_cli_args();
+                args.push_front("contract".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -56,31 +66,38 @@ pub struct Receiver {
     pub call: super::CallFunction,
 }
 
-impl From<CliReceiver> for Receiver {
-    fn from(item: CliReceiver) -> Self {
+impl Receiver {
+    fn from(item: CliReceiver) -> color_eyre::eyre::Result<Self> {
         let contract_account_id: String = match item.contract_account_id {
             Some(cli_contract_account_id) => cli_contract_account_id,
             None => Receiver::input_contract_account_id(),
         };
         let call = match item.call {
-            Some(cli_call) => cli_call.into(),
-            None => super::CallFunction::choose_call_function(),
+            Some(cli_call) => super::CallFunction::from(cli_call)?,
+            None => super::CallFunction::choose_call_function()?,
         };
-        Self {
+        Ok(Self {
             contract_account_id,
             call,
-        }
+        })
     }
 }
 
 impl Receiver {
     pub fn input_contract_account_id() -> String {
+        crate::common::ensure_interactive("<contract-account-id>");
         Input::new()
             .with_prompt("What is the account ID of the contract?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.call.to_cli_args();
+        args.push_front(self.contract_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -0,0 +1,229 @@
+use dialoguer::Input;
+
+/// How the bytes returned by a view method are shown
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    strum_macros::IntoStaticStr,
+    strum_macros::EnumString,
+    strum_macros::EnumVariantNames,
+    smart_default::SmartDefault,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum ResultFormat {
+    /// JSON if the result is JSON, otherwise UTF-8 text, otherwise base64
+    #[default]
+    Auto,
+    Json,
+    Utf8,
+    Base64,
+    Hex,
+    /// The bytes as they are, written to a file
+    Raw,
+}
+
+#[derive(Debug)]
+pub enum ResultOutput {
+    /// The result decoded in a format other than raw
+    Decoded(ResultFormat),
+    /// The bytes as they are, written to this file
+    RawFile(std::path::PathBuf),
+}
+
+impl ResultOutput {
+    pub fn from(
+        format: ResultFormat,
+        file: Option<std::path::PathBuf>,
+    ) -> color_eyre::eyre::Result<Self> {
+        match (format, file) {
+            (ResultFormat::Raw, Some(file)) => Ok(Self::RawFile(file)),
+            (ResultFormat::Raw, None) => Ok(Self::RawFile(Self::input_file())),
+            (format, None) => Ok(Self::Decoded(format)),
+            (format, Some(_)) => Err(crate::common::CliError::UserInput(format!(
+                "--result-file is only used with --result-format raw, the {} result is printed",
+                <&str>::from(format)
+            ))
+            .into()),
+        }
+    }
+
+    fn input_file() -> std::path::PathBuf {
+        println!();
+        crate::common::ensure_interactive("--result-file");
+        Input::<String>::new()
+            .with_prompt("Enter the path to the file for the result")
+            .interact_text()
+            .unwrap()
+            .into()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        match self {
+            Self::Decoded(format) => {
+                if *format != ResultFormat::default() {
+                    args.push_back("--result-format".to_owned());
+                    args.push_back(<&str>::from(*format).to_owned());
+                }
+            }
+            Self::RawFile(file) => {
+                args.push_back("--result-format".to_owned());
+                args.push_back(<&str>::from(ResultFormat::Raw).to_owned());
+                args.push_back("--result-file".to_owned());
+                args.push_back(file.display().to_string());
+            }
+        }
+        args
+    }
+
+    pub fn print(&self, call_result: near_primitives::views::CallResult) -> crate::CliResult {
+        let result = match self {
+            Self::RawFile(file) => {
+                std::fs::write(file, &call_result.result).map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to write the result to {}: {}",
+                        file.display(),
+                        err
+                    ))
+                })?;
+                crate::common::print_message(&format!(
+                    "The result ({} bytes) is saved in a file {}",
+                    call_result.result.len(),
+                    file.display()
+                ));
+                crate::common::set_json_output("result_file", file.display().to_string());
+                None
+            }
+            Self::Decoded(format) => Some(decode_result(*format, &call_result.result)?),
+        };
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                if let Some(result) = result {
+                    println!("--------------");
+                    println!();
+                    match result {
+                        serde_json::Value::String(string)
+                            if !matches!(self, Self::Decoded(ResultFormat::Json)) =>
+                        {
+                            println!("{}", string)
+                        }
+                        result => println!("{}", serde_json::to_string_pretty(&result).unwrap()),
+                    }
+                }
+                if !call_result.logs.is_empty() {
+                    println!("\nLogs:");
+                    for log in &call_result.logs {
+                        println!("  {}", log);
+                    }
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                if let Some(result) = result {
+                    crate::common::set_json_output("result", result);
+                }
+                crate::common::set_json_output("logs", call_result.logs);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The result as JSON, or as a string in the other formats
+fn decode_result(
+    format: ResultFormat,
+    result: &[u8],
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    Ok(match format {
+        ResultFormat::Auto => {
+            if let Ok(json) = serde_json::from_slice(result) {
+                json
+            } else if let Ok(string) = std::str::from_utf8(result) {
+                serde_json::Value::String(string.to_owned())
+            } else {
+                crate::common::print_message(
+                    "The result is neither JSON nor UTF-8, it is shown in base64",
+                );
+                serde_json::Value::String(near_primitives::serialize::to_base64(result))
+            }
+        }
+        ResultFormat::Json => serde_json::from_slice(result).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "The result is not JSON ({}), try another --result-format",
+                err
+            ))
+        })?,
+        ResultFormat::Utf8 => {
+            serde_json::Value::String(String::from_utf8(result.to_vec()).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The result is not UTF-8 ({}), try another --result-format",
+                    err
+                ))
+            })?)
+        }
+        ResultFormat::Base64 => {
+            serde_json::Value::String(near_primitives::serialize::to_base64(result))
+        }
+        ResultFormat::Hex => serde_json::Value::String(hex::encode(result)),
+        ResultFormat::Raw => unreachable!("The raw result is written to a file"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_results_are_decoded() {
+        assert_eq!(
+            decode_result(ResultFormat::Auto, br#"{"total": 5}"#).unwrap(),
+            serde_json::json!({"total": 5})
+        );
+        assert_eq!(
+            decode_result(ResultFormat::Json, b"[1, 2]").unwrap(),
+            serde_json::json!([1, 2])
+        );
+    }
+
+    #[test]
+    fn utf8_results_that_are_not_json_are_shown_as_text() {
+        assert_eq!(
+            decode_result(ResultFormat::Auto, b"hello").unwrap(),
+            serde_json::json!("hello")
+        );
+        assert_eq!(
+            decode_result(ResultFormat::Utf8, b"{not json").unwrap(),
+            serde_json::json!("{not json")
+        );
+        assert!(decode_result(ResultFormat::Json, b"hello").is_err());
+    }
+
+    #[test]
+    fn results_that_are_not_utf8_are_encoded() {
+        let result = [0xff, 0xfe, 0x00];
+        assert_eq!(
+            decode_result(ResultFormat::Auto, &result).unwrap(),
+            serde_json::json!("//4A")
+        );
+        assert_eq!(
+            decode_result(ResultFormat::Hex, &result).unwrap(),
+            serde_json::json!("fffe00")
+        );
+        assert!(decode_result(ResultFormat::Utf8, &result).is_err());
+        assert!(decode_result(ResultFormat::Json, &result).is_err());
+    }
+
+    #[test]
+    fn result_file_needs_the_raw_format() {
+        assert!(ResultOutput::from(ResultFormat::Json, Some("result.bin".into())).is_err());
+        assert!(matches!(
+            ResultOutput::from(ResultFormat::Raw, Some("result.bin".into())),
+            Ok(ResultOutput::RawFile(_))
+        ));
+        assert!(matches!(
+            ResultOutput::from(ResultFormat::Hex, None),
+            Ok(ResultOutput::Decoded(ResultFormat::Hex))
+        ));
+    }
+}
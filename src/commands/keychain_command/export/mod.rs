@@ -0,0 +1,85 @@
+/// Print the private key of a key of an account, and the seed phrase it was derived from
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliExport {
+    account_id: Option<String>,
+    /// The key to export if the account has several keys in the keychain
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+    /// Read the passphrase of an encrypted keychain from this file descriptor
+    #[clap(long)]
+    passphrase_fd: Option<i32>,
+    #[clap(subcommand)]
+    network: Option<super::network::CliKeychainNetwork>,
+}
+
+#[derive(Debug)]
+pub struct Export {
+    selected_key: super::SelectedKey,
+    passphrase_fd: Option<i32>,
+}
+
+impl Export {
+    pub fn from(item: CliExport) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            selected_key: super::SelectedKey::from(item.account_id, item.public_key, item.network)?,
+            passphrase_fd: item.passphrase_fd,
+        })
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct KeyFile {
+    master_seed_phrase: Option<String>,
+    seed_phrase_hd_path: Option<String>,
+    account_id: String,
+    public_key: near_crypto::PublicKey,
+    private_key: near_crypto::SecretKey,
+}
+
+impl Export {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_key.to_cli_args();
+        if let Some(passphrase_fd) = self.passphrase_fd {
+            args.push_front(passphrase_fd.to_string());
+            args.push_front("--passphrase-fd".to_owned());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let key = self.selected_key.key()?;
+        let data = crate::keychain::read_key_file(&key.file_paths[0], self.passphrase_fd)?;
+        let key_file: KeyFile = serde_json::from_str(&data).map_err(|err| {
+            crate::common::CliError::Keychain(format!("Error reading data: {}", err))
+        })?;
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "Account ID: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
+                    key_file.account_id, key_file.public_key, key_file.private_key
+                );
+                if let (Some(master_seed_phrase), Some(seed_phrase_hd_path)) =
+                    (&key_file.master_seed_phrase, &key_file.seed_phrase_hd_path)
+                {
+                    println!(
+                        "Master Seed Phrase: {}\nSeed Phrase HD Path: {}",
+                        master_seed_phrase, seed_phrase_hd_path
+                    );
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("account_id", key_file.account_id);
+                crate::common::set_json_output("public_key", key_file.public_key);
+                crate::common::set_json_output("private_key", key_file.private_key);
+                crate::common::set_json_output("master_seed_phrase", key_file.master_seed_phrase);
+                crate::common::set_json_output("seed_phrase_hd_path", key_file.seed_phrase_hd_path);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::str::FromStr;
+
+/// Save a key of an account given by its private key or by a seed phrase
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliImport {
+    account_id: Option<String>,
+    #[clap(long, conflicts_with = "seed-phrase")]
+    private_key: Option<near_crypto::SecretKey>,
+    #[clap(long)]
+    seed_phrase: Option<String>,
+    /// The HD path to derive the key from the seed phrase along (m/44'/397'/0' by default)
+    #[clap(long, requires = "seed-phrase")]
+    seed_phrase_hd_path: Option<slip10::BIP32Path>,
+    /// Read the passphrase of an encrypted keychain from this file descriptor
+    #[clap(long)]
+    passphrase_fd: Option<i32>,
+    #[clap(subcommand)]
+    network: Option<super::network::CliKeychainNetwork>,
+}
+
+#[derive(Debug)]
+pub enum KeySource {
+    PrivateKey(near_crypto::SecretKey),
+    SeedPhrase {
+        master_seed_phrase: String,
+        seed_phrase_hd_path: slip10::BIP32Path,
+    },
+}
+
+#[derive(Debug)]
+pub struct Import {
+    account_id: String,
+    key_source: KeySource,
+    passphrase_fd: Option<i32>,
+    network: super::network::KeychainNetwork,
+}
+
+impl Import {
+    pub fn from(item: CliImport) -> color_eyre::eyre::Result<Self> {
+        let account_id = match item.account_id {
+            Some(account_id) => account_id,
+            None => super::input_account_id(),
+        };
+        let key_source = match (item.private_key, item.seed_phrase) {
+            (Some(private_key), _) => KeySource::PrivateKey(private_key),
+            (None, Some(master_seed_phrase)) => KeySource::SeedPhrase {
+                master_seed_phrase,
+                seed_phrase_hd_path: item
+                    .seed_phrase_hd_path
+                    .unwrap_or_else(Import::default_seed_phrase_hd_path),
+            },
+            (None, None) => Import::input_key_source(),
+        };
+        Ok(Self {
+            account_id,
+            key_source,
+            passphrase_fd: item.passphrase_fd,
+            network: super::network::KeychainNetwork::from(item.network)?,
+        })
+    }
+}
+
+impl Import {
+    fn default_seed_phrase_hd_path() -> slip10::BIP32Path {
+        slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap()
+    }
+
+    fn input_key_source() -> KeySource {
+        println!();
+        crate::common::ensure_interactive("--private-key");
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How would you like to provide the key?")
+            .items(&["Private key", "Seed phrase"])
+            .default(0)
+            .interact()
+            .unwrap();
+        if selection == 0 {
+            KeySource::PrivateKey(
+                Input::new()
+                    .with_prompt("Enter the private key")
+                    .interact_text()
+                    .unwrap(),
+            )
+        } else {
+            KeySource::SeedPhrase {
+                master_seed_phrase: Input::new()
+                    .with_prompt("Enter the seed phrase")
+                    .interact_text()
+                    .unwrap(),
+                seed_phrase_hd_path: Input::new()
+                    .with_prompt("Enter the seed phrase HD path")
+                    .with_initial_text("m/44'/397'/0'")
+                    .interact_text()
+                    .unwrap(),
+            }
+        }
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.network.to_cli_args();
+        if let Some(passphrase_fd) = self.passphrase_fd {
+            args.push_front(passphrase_fd.to_string());
+            args.push_front("--passphrase-fd".to_owned());
+        }
+        match &self.key_source {
+            KeySource::PrivateKey(_) => {
+                args.push_front(crate::common::SECRET_PLACEHOLDER.to_owned());
+                args.push_front("--private-key".to_owned());
+            }
+            KeySource::SeedPhrase {
+                seed_phrase_hd_path,
+                ..
+            } => {
+                args.push_front(seed_phrase_hd_path.to_string());
+                args.push_front("--seed-phrase-hd-path".to_owned());
+                args.push_front(crate::common::SECRET_PLACEHOLDER.to_owned());
+                args.push_front("--seed-phrase".to_owned());
+            }
+        }
+        args.push_front(self.account_id.clone());
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let public_key = match &self.key_source {
+            KeySource::PrivateKey(private_key) => private_key.public_key(),
+            KeySource::SeedPhrase {
+                master_seed_phrase,
+                seed_phrase_hd_path,
+            } => near_crypto::PublicKey::from_str(
+                &crate::common::key_pair_properties_from_seed_phrase(
+                    master_seed_phrase,
+                    seed_phrase_hd_path.clone(),
+                )?
+                .public_key_str,
+            )?,
+        };
+        if let Some(connection_config) = &self.network.connection_config {
+            match super::access_key_list(connection_config, &self.account_id).await {
+                Ok(access_keys) => {
+                    if let super::OnChainStatus::Missing =
+                        super::OnChainStatus::new(Some(access_keys.as_slice()), &public_key)
+                    {
+                        crate::common::print_message(&format!(
+                            "Warning: the key {} is not on the account <{}>",
+                            public_key, self.account_id
+                        ));
+                    }
+                }
+                Err(err) => crate::common::print_message(&format!(
+                    "Warning: the key could not be checked on the account: {}",
+                    err
+                )),
+            }
+        }
+        match self.key_source {
+            KeySource::PrivateKey(private_key) => {
+                let buf = serde_json::json!({
+                    "account_id": self.account_id,
+                    "public_key": public_key,
+                    "private_key": private_key,
+                })
+                .to_string();
+                crate::common::save_key_file_to_keychain(
+                    self.network.connection_config,
+                    &buf,
+                    &public_key.to_string(),
+                    &self.account_id,
+                    self.passphrase_fd,
+                )
+            }
+            KeySource::SeedPhrase {
+                master_seed_phrase,
+                seed_phrase_hd_path,
+            } => {
+                let key_pair_properties = crate::common::key_pair_properties_from_seed_phrase(
+                    &master_seed_phrase,
+                    seed_phrase_hd_path,
+                )?;
+                crate::common::save_access_key_to_keychain(
+                    self.network.connection_config,
+                    key_pair_properties,
+                    &self.account_id,
+                    self.passphrase_fd,
+                )
+                .await
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/// List the accounts and keys of every keychain
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliList {
+    /// Do not ask the networks whether the keys are still on the accounts
+    #[clap(long)]
+    skip_on_chain_check: bool,
+}
+
+impl CliList {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if self.skip_on_chain_check {
+            args.push_front("--skip-on-chain-check".to_owned());
+        }
+        args
+    }
+
+    /// The keychains with their names; only those of the known networks can be checked on-chain
+    fn keychains() -> color_eyre::eyre::Result<
+        Vec<(
+            String,
+            std::path::PathBuf,
+            Option<crate::common::ConnectionConfig>,
+        )>,
+    > {
+        let mut connection_configs = vec![
+            (
+                "testnet".to_owned(),
+                crate::common::ConnectionConfig::Testnet,
+            ),
+            (
+                "mainnet".to_owned(),
+                crate::common::ConnectionConfig::Mainnet,
+            ),
+            (
+                "betanet".to_owned(),
+                crate::common::ConnectionConfig::Betanet,
+            ),
+        ];
+        for (name, network) in crate::config::Config::load()?.networks {
+            connection_configs.push((name, crate::common::ConnectionConfig::Network(network)));
+        }
+        let mut keychains = connection_configs
+            .into_iter()
+            .map(|(name, connection_config)| {
+                let connection_config = Some(connection_config);
+                (
+                    name,
+                    crate::keychain::keychain_dir(&connection_config),
+                    connection_config,
+                )
+            })
+            .collect::<Vec<_>>();
+        let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+        let custom_dir = home_dir.join(crate::consts::DIR_NAME_CUSTOM);
+        if custom_dir.is_dir() {
+            for entry in std::fs::read_dir(&custom_dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    let name = format!("custom {}", path.file_name().unwrap().to_string_lossy());
+                    keychains.push((name, path, None));
+                }
+            }
+        }
+        keychains.push((
+            "offline".to_owned(),
+            crate::keychain::keychain_dir(&None),
+            None,
+        ));
+        Ok(keychains)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut keychains_json = vec![];
+        for (name, dir, connection_config) in Self::keychains()? {
+            let keys = crate::keychain::keys(&dir)?;
+            if keys.is_empty() {
+                continue;
+            }
+            if let crate::common::OutputFormat::Plaintext = crate::common::output_format() {
+                println!("{} ({})", name, dir.display());
+            }
+            let mut keys_json = vec![];
+            let mut account_id = None;
+            let mut access_keys = None;
+            for key in keys {
+                if account_id.as_ref() != Some(&key.account_id) {
+                    account_id = Some(key.account_id.clone());
+                    access_keys = None;
+                    if let (Some(connection_config), false) =
+                        (&connection_config, self.skip_on_chain_check)
+                    {
+                        match super::access_key_list(connection_config, &key.account_id).await {
+                            Ok(keys) => access_keys = Some(keys),
+                            Err(err) => crate::common::print_message(&format!(
+                                "Failed to check the keys of <{}>: {}",
+                                key.account_id, err
+                            )),
+                        }
+                    }
+                    if let crate::common::OutputFormat::Plaintext = crate::common::output_format() {
+                        println!("  {}", key.account_id);
+                    }
+                }
+                let on_chain_status =
+                    super::OnChainStatus::new(access_keys.as_deref(), &key.public_key);
+                match crate::common::output_format() {
+                    crate::common::OutputFormat::Plaintext => println!(
+                        "    {}{}: {}",
+                        key.public_key,
+                        if key.is_encrypted { " (encrypted)" } else { "" },
+                        on_chain_status
+                    ),
+                    crate::common::OutputFormat::Json => {
+                        keys_json.push(super::key_to_json(&key, &on_chain_status))
+                    }
+                }
+            }
+            keychains_json.push(serde_json::json!({
+                "network": name,
+                "dir": dir.display().to_string(),
+                "keys": keys_json,
+            }));
+        }
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                if keychains_json.is_empty() {
+                    println!("There are no keys in the keychain.");
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("keychains", keychains_json)
+            }
+        }
+        Ok(())
+    }
+}
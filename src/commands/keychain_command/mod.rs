@@ -0,0 +1,399 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod export;
+mod import;
+mod list;
+pub mod network;
+mod prune;
+mod remove;
+mod show;
+
+/// управление ключами доступа, сохранёнными в keychain
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliKeychain {
+    #[clap(subcommand)]
+    action: Option<CliKeychainAction>,
+}
+
+#[derive(Debug)]
+pub struct Keychain {
+    pub action: KeychainAction,
+}
+
+impl Keychain {
+    pub fn from(item: CliKeychain) -> color_eyre::eyre::Result<Self> {
+        let action = match item.action {
+            Some(cli_action) => KeychainAction::from(cli_action)?,
+            None => KeychainAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+}
+
+impl Keychain {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action.to_cli_args()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, clap::Clap)]
+enum CliKeychainAction {
+    /// List the accounts and keys of every network, and whether the keys are still on the accounts
+    List(self::list::CliList),
+    /// Show a key of an account
+    Show(self::show::CliShow),
+    /// Save a private key or a key derived from a seed phrase
+    Import(self::import::CliImport),
+    /// Print the private key (and the seed phrase) of a key
+    Export(self::export::CliExport),
+    /// Delete a key from the keychain
+    Remove(self::remove::CliRemove),
+    /// Delete the keys that are no longer on their accounts
+    Prune(self::prune::CliPrune),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum KeychainAction {
+    #[strum_discriminants(strum(message = "List the keys"))]
+    List(self::list::CliList),
+    #[strum_discriminants(strum(message = "Show a key"))]
+    Show(self::show::Show),
+    #[strum_discriminants(strum(message = "Import a key"))]
+    Import(self::import::Import),
+    #[strum_discriminants(strum(message = "Export a key"))]
+    Export(self::export::Export),
+    #[strum_discriminants(strum(message = "Remove a key"))]
+    Remove(self::remove::Remove),
+    #[strum_discriminants(strum(message = "Remove the keys deleted from the accounts"))]
+    Prune(self::prune::Prune),
+}
+
+impl KeychainAction {
+    fn from(item: CliKeychainAction) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliKeychainAction::List(cli_list) => Ok(Self::List(cli_list)),
+            CliKeychainAction::Show(cli_show) => Ok(Self::Show(self::show::Show::from(cli_show)?)),
+            CliKeychainAction::Import(cli_import) => {
+                Ok(Self::Import(self::import::Import::from(cli_import)?))
+            }
+            CliKeychainAction::Export(cli_export) => {
+                Ok(Self::Export(self::export::Export::from(cli_export)?))
+            }
+            CliKeychainAction::Remove(cli_remove) => {
+                Ok(Self::Remove(self::remove::Remove::from(cli_remove)?))
+            }
+            CliKeychainAction::Prune(cli_prune) => {
+                Ok(Self::Prune(self::prune::Prune::from(cli_prune)?))
+            }
+        }
+    }
+}
+
+impl KeychainAction {
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = KeychainActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("keychain subcommand");
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selection] {
+            KeychainActionDiscriminants::List => CliKeychainAction::List(Default::default()),
+            KeychainActionDiscriminants::Show => CliKeychainAction::Show(Default::default()),
+            KeychainActionDiscriminants::Import => CliKeychainAction::Import(Default::default()),
+            KeychainActionDiscriminants::Export => CliKeychainAction::Export(Default::default()),
+            KeychainActionDiscriminants::Remove => CliKeychainAction::Remove(Default::default()),
+            KeychainActionDiscriminants::Prune => CliKeychainAction::Prune(Default::default()),
+        };
+        Self::from(cli_action)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let (mut args, action_name) = match self {
+            Self::List(list) => (list.to_cli_args(), "list"),
+            Self::Show(show) => (show.to_cli_args(), "show"),
+            Self::Import(import) => (import.to_cli_args(), "import"),
+            Self::Export(export) => (export.to_cli_args(), "export"),
+            Self::Remove(remove) => (remove.to_cli_args(), "remove"),
+            Self::Prune(prune) => (prune.to_cli_args(), "prune"),
+        };
+        args.push_front(action_name.to_owned());
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::List(list) => list.process().await,
+            Self::Show(show) => show.process().await,
+            Self::Import(import) => import.process().await,
+            Self::Export(export) => export.process().await,
+            Self::Remove(remove) => remove.process().await,
+            Self::Prune(prune) => prune.process().await,
+        }
+    }
+}
+
+/// A key of an account in the keychain of a network, as given on the command line
+#[derive(Debug)]
+pub struct SelectedKey {
+    account_id: String,
+    public_key: Option<near_crypto::PublicKey>,
+    network: self::network::KeychainNetwork,
+}
+
+impl SelectedKey {
+    fn from(
+        account_id: Option<String>,
+        public_key: Option<near_crypto::PublicKey>,
+        network: Option<self::network::CliKeychainNetwork>,
+    ) -> color_eyre::eyre::Result<Self> {
+        let account_id = match account_id {
+            Some(account_id) => account_id,
+            None => input_account_id(),
+        };
+        let network = self::network::KeychainNetwork::from(network)?;
+        let public_key = match public_key {
+            Some(public_key) => Some(public_key),
+            None => Self::choose_public_key(&account_id, &network),
+        };
+        Ok(Self {
+            account_id,
+            public_key,
+            network,
+        })
+    }
+
+    /// Asks only if the account has several keys in the keychain
+    fn choose_public_key(
+        account_id: &str,
+        network: &self::network::KeychainNetwork,
+    ) -> Option<near_crypto::PublicKey> {
+        let public_keys = crate::keychain::keys(&network.dir())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|key| key.account_id == account_id)
+            .map(|key| key.public_key)
+            .collect::<Vec<_>>();
+        if public_keys.len() < 2 {
+            return public_keys.into_iter().next();
+        }
+        println!();
+        crate::common::ensure_interactive("--public-key");
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Select the key")
+            .items(&public_keys)
+            .default(0)
+            .interact()
+            .unwrap();
+        Some(public_keys[selection].clone())
+    }
+
+    fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.network.to_cli_args();
+        if let Some(public_key) = &self.public_key {
+            args.push_front(public_key.to_string());
+            args.push_front("--public-key".to_owned());
+        }
+        args.push_front(self.account_id.clone());
+        args
+    }
+
+    fn key(&self) -> color_eyre::eyre::Result<crate::keychain::KeychainKey> {
+        crate::keychain::keys(&self.network.dir())?
+            .into_iter()
+            .find(|key| {
+                key.account_id == self.account_id
+                    && self
+                        .public_key
+                        .as_ref()
+                        .map_or(true, |public_key| &key.public_key == public_key)
+            })
+            .ok_or_else(|| {
+                crate::common::CliError::Keychain(format!(
+                    "There is no such key of <{}> in {}",
+                    self.account_id,
+                    self.network.dir().display()
+                ))
+                .into()
+            })
+    }
+}
+
+fn input_account_id() -> String {
+    println!();
+    crate::common::ensure_interactive("<account-id>");
+    Input::new()
+        .with_prompt("Enter the account ID")
+        .interact_text()
+        .unwrap()
+}
+
+/// Whether the key of the keychain is still on the account
+#[derive(Debug)]
+enum OnChainStatus {
+    NotChecked,
+    Missing,
+    Present(near_primitives::views::AccessKeyPermissionView),
+}
+
+impl OnChainStatus {
+    fn new(
+        access_keys: Option<&[near_primitives::views::AccessKeyInfoView]>,
+        public_key: &near_crypto::PublicKey,
+    ) -> Self {
+        let access_keys = match access_keys {
+            Some(access_keys) => access_keys,
+            None => return Self::NotChecked,
+        };
+        match access_keys
+            .iter()
+            .find(|access_key| &access_key.public_key == public_key)
+        {
+            Some(access_key) => Self::Present(access_key.access_key.permission.clone()),
+            None => Self::Missing,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::NotChecked => serde_json::json!({ "on_chain": null }),
+            Self::Missing => serde_json::json!({ "on_chain": false }),
+            Self::Present(permission) => {
+                serde_json::json!({ "on_chain": true, "permission": permission })
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for OnChainStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotChecked => write!(f, "not checked"),
+            Self::Missing => write!(f, "not on the account"),
+            Self::Present(near_primitives::views::AccessKeyPermissionView::FullAccess) => {
+                write!(f, "full access")
+            }
+            Self::Present(near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                receiver_id,
+                method_names,
+                ..
+            }) => {
+                if method_names.is_empty() {
+                    write!(f, "function calls to {}", receiver_id)
+                } else {
+                    write!(
+                        f,
+                        "function calls to {} ({})",
+                        receiver_id,
+                        method_names.join(", ")
+                    )
+                }
+            }
+        }
+    }
+}
+
+fn key_to_json(
+    key: &crate::keychain::KeychainKey,
+    on_chain_status: &OnChainStatus,
+) -> serde_json::Value {
+    let mut value = serde_json::json!({
+        "account_id": key.account_id,
+        "public_key": key.public_key,
+        "file_paths": key
+            .file_paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>(),
+        "encrypted": key.is_encrypted,
+    });
+    if let (Some(value), serde_json::Value::Object(status)) =
+        (value.as_object_mut(), on_chain_status.to_json())
+    {
+        value.extend(status);
+    }
+    value
+}
+
+/// The access keys of the account at the final block
+async fn access_key_list(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &str,
+) -> color_eyre::eyre::Result<Vec<near_primitives::views::AccessKeyInfoView>> {
+    let query_view_method_response =
+        near_jsonrpc_client::new_client(connection_config.rpc_url().as_str())
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                    account_id: account_id.to_string(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                crate::common::CliError::Rpc(format!(
+                    "Failed to fetch query for view key list: {:?}",
+                    err
+                ))
+            })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(result) =
+        query_view_method_response.kind
+    {
+        Ok(result.keys)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+/// Asks to go on unless `yes` is given
+fn confirm(prompt: &str, yes: bool) -> bool {
+    if yes {
+        return true;
+    }
+    println!();
+    crate::common::ensure_interactive("--yes");
+    dialoguer::Confirm::new()
+        .with_prompt(prompt)
+        .interact()
+        .unwrap()
+}
+
+/// Deletes the files of the key, and the account directory once it is empty
+fn remove_key_files(key: &crate::keychain::KeychainKey) -> color_eyre::eyre::Result<Vec<String>> {
+    let mut removed_files = vec![];
+    for path in &key.file_paths {
+        std::fs::remove_file(path).map_err(|err| {
+            crate::common::CliError::Keychain(format!(
+                "Failed to remove {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        crate::common::print_message(&format!("Removed {}", path.display()));
+        removed_files.push(path.display().to_string());
+        if let Some(dir) = path.parent() {
+            if dir.file_name() == Some(std::ffi::OsStr::new(&key.account_id)) {
+                // Fails unless the directory is empty, which is fine
+                let _ = std::fs::remove_dir(dir);
+            }
+        }
+    }
+    Ok(removed_files)
+}
@@ -0,0 +1,180 @@
+use clap::Clap;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+/// The keychain to work with: the one of a network, or the one for the offline mode
+#[derive(Debug, clap::Clap)]
+pub enum CliKeychainNetwork {
+    /// Keys of a network
+    Network(CliNetworkArgs),
+    /// Keys for signing transactions in the offline mode
+    Offline(CliOffline),
+}
+
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliNetworkArgs {
+    #[clap(subcommand)]
+    selected_server: Option<CliSelectServer>,
+}
+
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliOffline {}
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSelectServer {
+    /// предоставление данных для сервера https://rpc.testnet.near.org
+    Testnet(CliServer),
+    /// предоставление данных для сервера https://rpc.mainnet.near.org
+    Mainnet(CliServer),
+    /// предоставление данных для сервера https://rpc.betanet.near.org
+    Betanet(CliServer),
+    /// предоставление данных для сети, описанной в ~/.config/near-cli/config.toml
+    #[clap(external_subcommand)]
+    ConfigNetwork(Vec<String>),
+    /// предоставление данных для сервера, указанного вручную
+    Custom(CliCustomServer),
+}
+
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliServer {}
+
+/// данные для custom server
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliCustomServer {
+    #[clap(long)]
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
+}
+
+/// `None` stands for the keychain of the offline mode
+#[derive(Debug)]
+pub struct KeychainNetwork {
+    pub connection_config: Option<crate::common::ConnectionConfig>,
+}
+
+impl KeychainNetwork {
+    pub fn from(item: Option<CliKeychainNetwork>) -> color_eyre::eyre::Result<Self> {
+        let selected_server = match item {
+            Some(CliKeychainNetwork::Network(cli_network_args)) => {
+                match cli_network_args.selected_server {
+                    Some(cli_selected_server) => cli_selected_server,
+                    None => return Self::choose_network(),
+                }
+            }
+            Some(CliKeychainNetwork::Offline(_)) => {
+                return Ok(Self {
+                    connection_config: None,
+                })
+            }
+            None => return Self::choose_network(),
+        };
+        let connection_config = match selected_server {
+            CliSelectServer::Testnet(_) => crate::common::ConnectionConfig::Testnet,
+            CliSelectServer::Mainnet(_) => crate::common::ConnectionConfig::Mainnet,
+            CliSelectServer::Betanet(_) => crate::common::ConnectionConfig::Betanet,
+            CliSelectServer::ConfigNetwork(args) => {
+                let network = crate::config::Config::load()?.network(&args[0])?;
+                CliServer::parse_from(args);
+                crate::common::ConnectionConfig::Network(network)
+            }
+            CliSelectServer::Custom(cli_custom_server) => {
+                let url: crate::common::AvailableRpcServerUrl = match cli_custom_server.url {
+                    Some(url) => url,
+                    None => {
+                        crate::common::ensure_interactive("--url");
+                        Input::new()
+                            .with_prompt("What is the RPC endpoint?")
+                            .interact_text()
+                            .unwrap()
+                    }
+                };
+                crate::common::ConnectionConfig::custom(
+                    url.inner,
+                    cli_custom_server.archival_url,
+                    cli_custom_server.wallet_url,
+                    cli_custom_server.explorer_url,
+                )
+            }
+        };
+        Ok(Self {
+            connection_config: Some(connection_config),
+        })
+    }
+}
+
+impl KeychainNetwork {
+    fn choose_network() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let network_names = crate::config::Config::load()?.network_names();
+        let mut networks = vec![
+            "Testnet".to_owned(),
+            "Mainnet".to_owned(),
+            "Betanet".to_owned(),
+        ];
+        networks.extend(network_names.iter().cloned());
+        networks.push("Custom".to_owned());
+        networks.push("Offline mode keys".to_owned());
+        crate::common::ensure_interactive("network subcommand");
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Select the keychain:")
+            .items(&networks)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_selected_server = match selection {
+            0 => CliSelectServer::Testnet(Default::default()),
+            1 => CliSelectServer::Mainnet(Default::default()),
+            2 => CliSelectServer::Betanet(Default::default()),
+            selection if selection < 3 + network_names.len() => {
+                CliSelectServer::ConfigNetwork(vec![network_names[selection - 3].clone()])
+            }
+            selection if selection == 3 + network_names.len() => {
+                CliSelectServer::Custom(Default::default())
+            }
+            _ => return Self::from(Some(CliKeychainNetwork::Offline(Default::default()))),
+        };
+        Self::from(Some(CliKeychainNetwork::Network(CliNetworkArgs {
+            selected_server: Some(cli_selected_server),
+        })))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let connection_config = match &self.connection_config {
+            Some(connection_config) => connection_config,
+            None => return std::collections::VecDeque::from(vec!["offline".to_owned()]),
+        };
+        let mut args = connection_config.to_cli_args();
+        match connection_config {
+            crate::common::ConnectionConfig::Testnet => args.push_front("testnet".to_owned()),
+            crate::common::ConnectionConfig::Mainnet => args.push_front("mainnet".to_owned()),
+            crate::common::ConnectionConfig::Betanet => args.push_front("betanet".to_owned()),
+            crate::common::ConnectionConfig::Network(network) => {
+                args.push_front(network.name.clone())
+            }
+            crate::common::ConnectionConfig::Custom(_) => args.push_front("custom".to_owned()),
+        }
+        args.push_front("network".to_owned());
+        args
+    }
+
+    pub fn dir(&self) -> std::path::PathBuf {
+        crate::keychain::keychain_dir(&self.connection_config)
+    }
+}
@@ -0,0 +1,111 @@
+/// Delete the keys of the keychain that are no longer on their accounts
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliPrune {
+    /// Prune only the keys of this account
+    #[clap(long)]
+    account_id: Option<String>,
+    /// Do not ask for confirmation
+    #[clap(long)]
+    yes: bool,
+    #[clap(subcommand)]
+    network: Option<super::network::CliKeychainNetwork>,
+}
+
+#[derive(Debug)]
+pub struct Prune {
+    account_id: Option<String>,
+    yes: bool,
+    network: super::network::KeychainNetwork,
+}
+
+impl Prune {
+    pub fn from(item: CliPrune) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            account_id: item.account_id,
+            yes: item.yes,
+            network: super::network::KeychainNetwork::from(item.network)?,
+        })
+    }
+}
+
+impl Prune {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.network.to_cli_args();
+        if self.yes {
+            args.push_front("--yes".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.clone());
+            args.push_front("--account-id".to_owned());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let connection_config = match &self.network.connection_config {
+            Some(connection_config) => connection_config,
+            None => {
+                return Err(crate::common::CliError::UserInput(
+                    "The keys of the offline mode cannot be checked on-chain, choose a network"
+                        .to_string(),
+                )
+                .into())
+            }
+        };
+        let mut stale_keys = vec![];
+        let mut skipped_accounts = vec![];
+        let mut account_id: Option<String> = None;
+        let mut access_keys = None;
+        for key in crate::keychain::keys(&self.network.dir())? {
+            if let Some(only_account_id) = &self.account_id {
+                if &key.account_id != only_account_id {
+                    continue;
+                }
+            }
+            if account_id.as_ref() != Some(&key.account_id) {
+                account_id = Some(key.account_id.clone());
+                // A key is only pruned when the account is known to not have it
+                access_keys = match super::access_key_list(connection_config, &key.account_id).await
+                {
+                    Ok(access_keys) => Some(access_keys),
+                    Err(err) => {
+                        crate::common::print_message(&format!(
+                            "Skipped <{}>: {}",
+                            key.account_id, err
+                        ));
+                        skipped_accounts.push(key.account_id.clone());
+                        None
+                    }
+                };
+            }
+            if let super::OnChainStatus::Missing =
+                super::OnChainStatus::new(access_keys.as_deref(), &key.public_key)
+            {
+                stale_keys.push(key);
+            }
+        }
+        crate::common::set_json_output("skipped_accounts", &skipped_accounts);
+        if stale_keys.is_empty() {
+            crate::common::print_message("All the keys in the keychain are on their accounts.");
+            crate::common::set_json_output("removed_files", Vec::<String>::new());
+            return Ok(());
+        }
+        crate::common::print_message("These keys are no longer on their accounts:");
+        for key in &stale_keys {
+            crate::common::print_message(&format!("  {} {}", key.account_id, key.public_key));
+        }
+        let mut removed_files = vec![];
+        if super::confirm("Remove them from the keychain?", self.yes) {
+            for key in &stale_keys {
+                removed_files.extend(super::remove_key_files(key)?);
+            }
+        }
+        crate::common::set_json_output("removed_files", removed_files);
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+/// Delete a key of an account from the keychain
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliRemove {
+    account_id: Option<String>,
+    /// The key to remove if the account has several keys in the keychain
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+    /// Do not ask for confirmation
+    #[clap(long)]
+    yes: bool,
+    #[clap(subcommand)]
+    network: Option<super::network::CliKeychainNetwork>,
+}
+
+#[derive(Debug)]
+pub struct Remove {
+    selected_key: super::SelectedKey,
+    yes: bool,
+}
+
+impl Remove {
+    pub fn from(item: CliRemove) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            selected_key: super::SelectedKey::from(item.account_id, item.public_key, item.network)?,
+            yes: item.yes,
+        })
+    }
+}
+
+impl Remove {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_key.to_cli_args();
+        if self.yes {
+            args.push_front("--yes".to_owned());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let key = self.selected_key.key()?;
+        let prompt = format!(
+            "Remove the key {} of <{}> from the keychain?",
+            key.public_key, key.account_id
+        );
+        if !super::confirm(&prompt, self.yes) {
+            return Ok(());
+        }
+        let removed_files = super::remove_key_files(&key)?;
+        crate::common::set_json_output("removed_files", removed_files);
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+/// Show a key of an account: its files and whether it is still on the account
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliShow {
+    account_id: Option<String>,
+    /// The key to show if the account has several keys in the keychain
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+    #[clap(subcommand)]
+    network: Option<super::network::CliKeychainNetwork>,
+}
+
+#[derive(Debug)]
+pub struct Show {
+    selected_key: super::SelectedKey,
+}
+
+impl Show {
+    pub fn from(item: CliShow) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            selected_key: super::SelectedKey::from(item.account_id, item.public_key, item.network)?,
+        })
+    }
+}
+
+impl Show {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.selected_key.to_cli_args()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let key = self.selected_key.key()?;
+        let access_keys = match &self.selected_key.network.connection_config {
+            Some(connection_config) => {
+                Some(super::access_key_list(connection_config, &key.account_id).await?)
+            }
+            None => None,
+        };
+        let on_chain_status = super::OnChainStatus::new(access_keys.as_deref(), &key.public_key);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "Account ID: {}\nPublic Key: {}\nEncrypted: {}\nOn-chain: {}\nFiles:",
+                    key.account_id,
+                    key.public_key,
+                    if key.is_encrypted { "yes" } else { "no" },
+                    on_chain_status
+                );
+                for path in &key.file_paths {
+                    println!("  {}", path.display());
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("key", super::key_to_json(&key, &on_chain_status))
+            }
+        }
+        Ok(())
+    }
+}
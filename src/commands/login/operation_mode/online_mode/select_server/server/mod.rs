@@ -5,93 +5,210 @@ use url_open::UrlOpen;
 
 /// предустановленный RPC-сервер
 #[derive(Debug, Default, clap::Clap)]
-pub struct CliServer {}
+pub struct CliServer {
+    /// Read the passphrase of an encrypted keychain from this file descriptor
+    #[clap(long)]
+    pub passphrase_fd: Option<i32>,
+}
 
 /// данные для custom server
 #[derive(Debug, Default, clap::Clap)]
 pub struct CliCustomServer {
     #[clap(long)]
-    pub url: Option<url::Url>,
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Read the passphrase of an encrypted keychain from this file descriptor
+    #[clap(long)]
+    pub passphrase_fd: Option<i32>,
 }
 
 #[derive(Debug)]
 pub struct Server {
     pub connection_config: crate::common::ConnectionConfig,
+    pub passphrase_fd: Option<i32>,
 }
 
 impl CliServer {
     pub fn into_server(self, connection_config: crate::common::ConnectionConfig) -> Server {
-        Server { connection_config }
+        Server {
+            connection_config,
+            passphrase_fd: self.passphrase_fd,
+        }
     }
 }
 
 impl CliCustomServer {
     pub fn into_server(self) -> Server {
-        let url: url::Url = match self.url {
+        let url: crate::common::AvailableRpcServerUrl = match self.url {
             Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the wallet url?")
-                .interact_text()
-                .unwrap(),
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
+        };
+        let wallet_url: url::Url = match self.wallet_url {
+            Some(wallet_url) => wallet_url,
+            None => {
+                crate::common::ensure_interactive("--wallet-url");
+                Input::new()
+                    .with_prompt("What is the wallet url?")
+                    .interact_text()
+                    .unwrap()
+            }
         };
         Server {
-            connection_config: crate::common::ConnectionConfig::Custom { url },
+            connection_config: crate::common::ConnectionConfig::custom(
+                url.inner,
+                None,
+                Some(wallet_url),
+                None,
+            ),
+            passphrase_fd: self.passphrase_fd,
         }
     }
 }
 
 impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.connection_config.to_cli_args();
+        if let Some(passphrase_fd) = self.passphrase_fd {
+            args.push_back("--passphrase-fd".to_owned());
+            args.push_back(passphrase_fd.to_string());
+        }
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let key_pair_properties: crate::common::KeyPairProperties =
             crate::common::generate_keypair().await?;
-        let mut url: url::Url = self.connection_config.wallet_url().join("login/")?;
+        let wallet_url = self.connection_config.wallet_url().ok_or_else(|| {
+            color_eyre::Report::msg("The wallet URL is not set for this network".to_string())
+        })?;
+
+        let (callback_sender, callback_receiver) =
+            futures::channel::oneshot::channel::<Result<LoginCallback, ()>>();
+        let callback_sender: actix_web::web::Data<LoginCallbackSender> =
+            actix_web::web::Data::new(std::sync::Mutex::new(Some(callback_sender)));
+        let http_server = actix_web::HttpServer::new(move || {
+            actix_web::App::new()
+                .app_data(callback_sender.clone())
+                .route("/success", actix_web::web::get().to(login_success))
+                .route("/failure", actix_web::web::get().to(login_failure))
+        })
+        .workers(1)
+        .disable_signals()
+        .bind(("127.0.0.1", 0))?;
+        let callback_url: url::Url = format!("http://{}/", http_server.addrs()[0]).parse()?;
+        let http_server = http_server.run();
+
+        let mut url: url::Url = wallet_url.join("login/")?;
         url.query_pairs_mut()
             .append_pair("title", "NEAR CLI")
-            .append_pair("public_key", &key_pair_properties.public_key_str);
-        // Use `success_url` once capture mode is implemented
-        //.append_pair("success_url", "http://127.0.0.1:8080");
-        println!(
+            .append_pair("public_key", &key_pair_properties.public_key_str)
+            .append_pair("success_url", callback_url.join("success")?.as_str())
+            .append_pair("failure_url", callback_url.join("failure")?.as_str());
+        crate::common::print_message(&format!(
             "If your browser doesn't automatically open, please visit this URL:\n {}\n",
             &url.as_str()
-        );
+        ));
         url.open();
 
+        let login_callback = actix_web::rt::time::timeout(LOGIN_TIMEOUT, callback_receiver).await;
+        http_server.stop(true).await;
+        let login_callback: LoginCallback = match login_callback {
+            Ok(Ok(Ok(login_callback))) => login_callback,
+            Ok(Ok(Err(()))) => {
+                return Err(crate::common::CliError::UserInput(
+                    "The access key was not approved in the wallet".to_string(),
+                )
+                .into())
+            }
+            Ok(Err(_)) | Err(_) => {
+                return Err(crate::common::CliError::UserInput(format!(
+                    "The wallet did not redirect back within {} seconds",
+                    LOGIN_TIMEOUT.as_secs()
+                ))
+                .into())
+            }
+        };
+
         let public_key: near_crypto::PublicKey =
             near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
-
-        let account_id = get_account_from_cli(public_key, self.connection_config.clone()).await?;
-        if !account_id.is_empty() {
-            // save_account(&account_id, key_pair_properties, self.connection_config).await?
-            crate::common::save_access_key_to_keychain(
-                Some(self.connection_config),
-                key_pair_properties.clone(),
-                &account_id,
-            )
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
-            })?;
-        };
+        if login_callback.public_key != public_key {
+            return Err(color_eyre::Report::msg(format!(
+                "The wallet approved the key {} instead of {}",
+                login_callback.public_key, public_key
+            )));
+        }
+        verify_account_id(
+            login_callback.account_id.clone(),
+            public_key,
+            self.connection_config.clone(),
+        )
+        .await?;
+        crate::common::save_access_key_to_keychain(
+            Some(self.connection_config),
+            key_pair_properties,
+            &login_callback.account_id,
+            self.passphrase_fd,
+        )
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Keychain(format!(
+                "Failed to save a file with access key: {}",
+                err
+            ))
+        })?;
         Ok(())
     }
 }
 
-async fn get_account_from_cli(
+/// How long to wait for the wallet to redirect to the local callback server
+const LOGIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+type LoginCallbackSender =
+    std::sync::Mutex<Option<futures::channel::oneshot::Sender<Result<LoginCallback, ()>>>>;
+
+/// The query of the `success_url` the wallet redirects to
+#[derive(Debug, serde::Deserialize)]
+struct LoginCallback {
+    account_id: String,
     public_key: near_crypto::PublicKey,
-    network_connection_config: crate::common::ConnectionConfig,
-) -> color_eyre::eyre::Result<String> {
-    let account_id = input_account_id();
-    verify_account_id(account_id.clone(), public_key, network_connection_config)
-        .await
-        .map_err(|err| color_eyre::Report::msg(format!("Failed account ID: {:?}", err)))?;
-    Ok(account_id)
 }
 
-fn input_account_id() -> String {
-    Input::new()
-        .with_prompt("Enter account ID")
-        .interact_text()
-        .unwrap()
+async fn login_success(
+    login_callback: actix_web::web::Query<LoginCallback>,
+    callback_sender: actix_web::web::Data<LoginCallbackSender>,
+) -> actix_web::HttpResponse {
+    send_login_callback(&callback_sender, Ok(login_callback.into_inner()));
+    login_page("You are logged in. You can close this page and return to the terminal.")
+}
+
+async fn login_failure(
+    callback_sender: actix_web::web::Data<LoginCallbackSender>,
+) -> actix_web::HttpResponse {
+    send_login_callback(&callback_sender, Err(()));
+    login_page("The login was cancelled. You can close this page and return to the terminal.")
+}
+
+fn send_login_callback(
+    callback_sender: &LoginCallbackSender,
+    login_callback: Result<LoginCallback, ()>,
+) {
+    // Only the first redirect counts, the listener is stopped right after it
+    if let Some(sender) = callback_sender.lock().unwrap().take() {
+        let _ = sender.send(login_callback);
+    }
+}
+
+fn login_page(message: &str) -> actix_web::HttpResponse {
+    actix_web::HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(format!("<html><body><p>{}</p></body></html>", message))
 }
 
 fn rpc_client(selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
@@ -113,7 +230,7 @@ async fn verify_account_id(
         })
         .await
         .map_err(|err| {
-            color_eyre::Report::msg(format!(
+            crate::common::CliError::Rpc(format!(
                 "Failed to fetch query for view access key: {:?}",
                 err
             ))
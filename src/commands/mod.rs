@@ -6,7 +6,9 @@ pub mod construct_transaction_command;
 pub mod delete_command;
 pub mod execute_command;
 pub mod generate_shell_completions_command;
+pub mod keychain_command;
 pub mod login;
+pub mod stake_command;
 pub mod transfer_command;
 pub mod utils_command;
 pub mod view_command;
@@ -23,8 +25,12 @@ pub enum CliTopLevelCommand {
     Execute(self::execute_command::CliOptionMethod),
     /// Use these to generate static shell completions
     GenerateShellCompletions(self::generate_shell_completions_command::CliGenerateShellCompletions),
+    /// Use these to list, import, export and remove the access keys saved in the keychain
+    Keychain(self::keychain_command::CliKeychain),
     /// Use these to login with wallet authorization
     Login(self::login::operation_mode::CliOperationMode),
+    /// Use these to deposit, stake, unstake and withdraw with a staking pool
+    Stake(self::stake_command::CliStake),
     /// Use these to transfer tokens
     Transfer(self::transfer_command::CliCurrency),
     /// Helpers
@@ -44,6 +50,8 @@ pub enum TopLevelCommand {
     View(self::view_command::ViewQueryRequest),
     #[strum_discriminants(strum(message = "Transfer tokens"))]
     Transfer(self::transfer_command::Currency),
+    #[strum_discriminants(strum(message = "Delegate to a staking pool"))]
+    Stake(self::stake_command::Stake),
     #[strum_discriminants(strum(message = "Execute function (contract method)"))]
     Execute(self::execute_command::OptionMethod),
     #[strum_discriminants(strum(
@@ -52,57 +60,67 @@ pub enum TopLevelCommand {
     Add(self::add_command::AddAction),
     #[strum_discriminants(strum(message = "Delete access key, account"))]
     Delete(self::delete_command::DeleteAction),
+    #[strum_discriminants(strum(message = "Manage the keys in the keychain"))]
+    Keychain(self::keychain_command::Keychain),
     #[strum_discriminants(strum(message = "Construct a new transaction"))]
     ConstructTransaction(self::construct_transaction_command::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Helpers"))]
     Utils(self::utils_command::Utils),
 }
 
-impl From<CliTopLevelCommand> for TopLevelCommand {
-    fn from(cli_top_level_command: CliTopLevelCommand) -> Self {
+impl TopLevelCommand {
+    pub fn from(cli_top_level_command: CliTopLevelCommand) -> color_eyre::eyre::Result<Self> {
         match cli_top_level_command {
-            CliTopLevelCommand::Add(cli_add_action) => {
-                TopLevelCommand::Add(self::add_command::AddAction::from(cli_add_action).unwrap())
-            }
+            CliTopLevelCommand::Add(cli_add_action) => Ok(TopLevelCommand::Add(
+                self::add_command::AddAction::from(cli_add_action)?,
+            )),
             CliTopLevelCommand::ConstructTransaction(cli_operation_mode) => {
-                TopLevelCommand::ConstructTransaction(
+                Ok(TopLevelCommand::ConstructTransaction(
                     self::construct_transaction_command::operation_mode::OperationMode::from(
                         cli_operation_mode,
-                    )
-                    .unwrap(),
-                )
-            }
-            CliTopLevelCommand::Delete(cli_delete_action) => TopLevelCommand::Delete(
-                self::delete_command::DeleteAction::from(cli_delete_action).unwrap(),
-            ),
-            CliTopLevelCommand::Execute(cli_option_method) => {
-                TopLevelCommand::Execute(cli_option_method.into())
+                    )?,
+                ))
             }
+            CliTopLevelCommand::Delete(cli_delete_action) => Ok(TopLevelCommand::Delete(
+                self::delete_command::DeleteAction::from(cli_delete_action)?,
+            )),
+            CliTopLevelCommand::Execute(cli_option_method) => Ok(TopLevelCommand::Execute(
+                self::execute_command::OptionMethod::from(cli_option_method)?,
+            )),
             CliTopLevelCommand::GenerateShellCompletions(_) => {
                 unreachable!("This variant is handled in the main function")
             }
-            CliTopLevelCommand::Login(cli_option_method) => {
-                TopLevelCommand::Login(cli_option_method.into())
-            }
-            CliTopLevelCommand::Transfer(cli_currency) => TopLevelCommand::Transfer(
-                self::transfer_command::Currency::from(cli_currency).unwrap(),
-            ),
-            CliTopLevelCommand::Utils(cli_util) => TopLevelCommand::Utils(cli_util.into()),
-            CliTopLevelCommand::View(cli_view_query_request) => {
-                TopLevelCommand::View(cli_view_query_request.into())
-            }
+            CliTopLevelCommand::Keychain(cli_keychain) => Ok(TopLevelCommand::Keychain(
+                self::keychain_command::Keychain::from(cli_keychain)?,
+            )),
+            CliTopLevelCommand::Login(cli_operation_mode) => Ok(TopLevelCommand::Login(
+                self::login::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliTopLevelCommand::Stake(cli_stake) => Ok(TopLevelCommand::Stake(
+                self::stake_command::Stake::from(cli_stake)?,
+            )),
+            CliTopLevelCommand::Transfer(cli_currency) => Ok(TopLevelCommand::Transfer(
+                self::transfer_command::Currency::from(cli_currency)?,
+            )),
+            CliTopLevelCommand::Utils(cli_utils) => Ok(TopLevelCommand::Utils(
+                self::utils_command::Utils::from(cli_utils)?,
+            )),
+            CliTopLevelCommand::View(cli_view_query_request) => Ok(TopLevelCommand::View(
+                self::view_command::ViewQueryRequest::from(cli_view_query_request)?,
+            )),
         }
     }
 }
 
 impl TopLevelCommand {
-    pub fn choose_command() -> Self {
+    pub fn choose_command() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = TopLevelCommandDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("command subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&commands)
@@ -118,7 +136,11 @@ impl TopLevelCommand {
             TopLevelCommandDiscriminants::Execute => {
                 CliTopLevelCommand::Execute(Default::default())
             }
+            TopLevelCommandDiscriminants::Keychain => {
+                CliTopLevelCommand::Keychain(Default::default())
+            }
             TopLevelCommandDiscriminants::Login => CliTopLevelCommand::Login(Default::default()),
+            TopLevelCommandDiscriminants::Stake => CliTopLevelCommand::Stake(Default::default()),
             TopLevelCommandDiscriminants::Transfer => {
                 CliTopLevelCommand::Transfer(Default::default())
             }
@@ -128,6 +150,61 @@ impl TopLevelCommand {
         Self::from(cli_top_level_command)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Login(login) => {
+                let mut args = login.to_cli_args();
+                args.push_front("login".to_owned());
+                args
+            }
+            Self::View(view) => {
+                let mut args = view.to_cli_args();
+                args.push_front("view".to_owned());
+                args
+            }
+            Self::Transfer(transfer) => {
+                let mut args = transfer.to_cli_args();
+                args.push_front("transfer".to_owned());
+                args
+            }
+            Self::Stake(stake) => {
+                let mut args = stake.to_cli_args();
+                args.push_front("stake".to_owned());
+                args
+            }
+            Self::Execute(execute) => {
+                let mut args = execute.to_cli_args();
+                args.push_front("execute".to_owned());
+                args
+            }
+            Self::Add(add) => {
+                let mut args = add.to_cli_args();
+                args.push_front("add".to_owned());
+                args
+            }
+            Self::Delete(delete) => {
+                let mut args = delete.to_cli_args();
+                args.push_front("delete".to_owned());
+                args
+            }
+            Self::Keychain(keychain) => {
+                let mut args = keychain.to_cli_args();
+                args.push_front("keychain".to_owned());
+                args
+            }
+            Self::ConstructTransaction(construct_transaction) => {
+                let mut args = construct_transaction.to_cli_args();
+                args.push_front("construct-transaction".to_owned());
+                args
+            }
+            Self::Utils(utils) => {
+                let mut args = utils.to_cli_args();
+                args.push_front("utils".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let unsigned_transaction = near_primitives::transaction::Transaction {
             signer_id: "".to_string(),
@@ -142,7 +219,9 @@ impl TopLevelCommand {
             Self::ConstructTransaction(mode) => mode.process(unsigned_transaction).await,
             Self::Delete(delete_action) => delete_action.process(unsigned_transaction).await,
             Self::Execute(option_method) => option_method.process(unsigned_transaction).await,
+            Self::Keychain(keychain) => keychain.process().await,
             Self::Login(mode) => mode.process().await,
+            Self::Stake(stake) => stake.process(unsigned_transaction).await,
             Self::Transfer(currency) => currency.process(unsigned_transaction).await,
             Self::Utils(util_type) => util_type.process().await,
             Self::View(view_query_request) => view_query_request.process().await,
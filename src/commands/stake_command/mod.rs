@@ -0,0 +1,69 @@
+use dialoguer::Input;
+
+pub mod operation_mode;
+mod sender;
+mod stake_action;
+
+/// делегирование staking pool
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliStake {
+    pool_account_id: Option<String>,
+    #[clap(subcommand)]
+    mode: Option<self::operation_mode::CliMode>,
+}
+
+#[derive(Debug)]
+pub struct Stake {
+    pub pool_account_id: String,
+    mode: self::operation_mode::Mode,
+}
+
+impl Stake {
+    pub fn from(item: CliStake) -> color_eyre::eyre::Result<Self> {
+        let pool_account_id = match item.pool_account_id {
+            Some(pool_account_id) => pool_account_id,
+            None => Stake::input_pool_account_id(),
+        };
+        let mode = match item.mode {
+            Some(cli_mode) => self::operation_mode::Mode::from(cli_mode)?,
+            None => self::operation_mode::Mode::choose_mode()?,
+        };
+        Ok(Self {
+            pool_account_id,
+            mode,
+        })
+    }
+}
+
+impl Stake {
+    fn input_pool_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<pool-account-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the staking pool?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.mode.to_cli_args();
+        args.push_front(self.pool_account_id.clone());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.pool_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.mode.process(unsigned_transaction).await
+    }
+}
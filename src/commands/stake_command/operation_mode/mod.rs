@@ -0,0 +1,57 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+pub mod online_mode;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliMode {
+    /// Prepare and, optionally, submit a new transaction with online mode
+    Network(self::online_mode::CliNetworkArgs),
+}
+
+/// The balances of the delegator are checked before unstaking and withdrawing, so there is no
+/// offline mode
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum Mode {
+    #[strum_discriminants(strum(message = "Yes, I keep it simple"))]
+    Network(self::online_mode::NetworkArgs),
+}
+
+impl Mode {
+    pub fn from(item: CliMode) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliMode::Network(cli_network_args) => Ok(Self::Network(
+                self::online_mode::NetworkArgs::from(cli_network_args)?,
+            )),
+        }
+    }
+}
+
+impl Mode {
+    pub fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        Self::from(CliMode::Network(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Network(network) => {
+                let mut args = network.to_cli_args();
+                args.push_front("network".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        match self {
+            Self::Network(network_args) => {
+                network_args
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+        }
+    }
+}
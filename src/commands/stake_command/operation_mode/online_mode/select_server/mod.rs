@@ -0,0 +1,153 @@
+use clap::Clap;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+pub mod server;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSelectServer {
+    /// предоставление данных для сервера https://rpc.testnet.near.org
+    Testnet(self::server::CliServer),
+    /// предоставление данных для сервера https://rpc.mainnet.near.org
+    Mainnet(self::server::CliServer),
+    /// предоставление данных для сервера https://rpc.betanet.near.org
+    Betanet(self::server::CliServer),
+    /// предоставление данных для сети, описанной в ~/.config/near-cli/config.toml
+    #[clap(external_subcommand)]
+    ConfigNetwork(Vec<String>),
+    /// предоставление данных для сервера, указанного вручную
+    Custom(self::server::CliCustomServer),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum SelectServer {
+    #[strum_discriminants(strum(message = "Testnet"))]
+    Testnet(self::server::Server),
+    #[strum_discriminants(strum(message = "Mainnet"))]
+    Mainnet(self::server::Server),
+    #[strum_discriminants(strum(message = "Betanet"))]
+    Betanet(self::server::Server),
+    #[strum_discriminants(strum(message = "Network from the config file"))]
+    ConfigNetwork(String, self::server::Server),
+    #[strum_discriminants(strum(message = "Custom"))]
+    Custom(self::server::Server),
+}
+
+impl SelectServer {
+    pub fn from(item: CliSelectServer) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSelectServer::Testnet(cli_server) => Ok(Self::Testnet(
+                cli_server.into_server(crate::common::ConnectionConfig::Testnet)?,
+            )),
+            CliSelectServer::Mainnet(cli_server) => Ok(Self::Mainnet(
+                cli_server.into_server(crate::common::ConnectionConfig::Mainnet)?,
+            )),
+            CliSelectServer::Betanet(cli_server) => Ok(Self::Betanet(
+                cli_server.into_server(crate::common::ConnectionConfig::Betanet)?,
+            )),
+            CliSelectServer::ConfigNetwork(args) => {
+                let network = crate::config::Config::load()?.network(&args[0])?;
+                let cli_server = self::server::CliServer::parse_from(args);
+                Ok(Self::ConfigNetwork(
+                    network.name.clone(),
+                    cli_server.into_server(crate::common::ConnectionConfig::Network(network))?,
+                ))
+            }
+            CliSelectServer::Custom(cli_custom_server) => {
+                Ok(Self::Custom(cli_custom_server.into_server()?))
+            }
+        }
+    }
+}
+
+impl SelectServer {
+    pub fn choose_server() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let network_names = crate::config::Config::load()?.network_names();
+        let variants = SelectServerDiscriminants::iter()
+            .flat_map(|variant| match variant {
+                SelectServerDiscriminants::ConfigNetwork => network_names
+                    .iter()
+                    .map(|network_name| (variant, network_name.clone()))
+                    .collect::<Vec<_>>(),
+                _ => vec![(variant, variant.get_message().unwrap().to_owned())],
+            })
+            .collect::<Vec<_>>();
+        let servers = variants
+            .iter()
+            .map(|(_, server_name)| server_name.clone())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("server subcommand");
+        let selected_server = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Select NEAR protocol RPC server:")
+            .items(&servers)
+            .default(0)
+            .interact()
+            .unwrap();
+        let (variant, server_name) = &variants[selected_server];
+        let cli_select_server = match variant {
+            SelectServerDiscriminants::Testnet => CliSelectServer::Testnet(Default::default()),
+            SelectServerDiscriminants::Mainnet => CliSelectServer::Mainnet(Default::default()),
+            SelectServerDiscriminants::Betanet => CliSelectServer::Betanet(Default::default()),
+            SelectServerDiscriminants::ConfigNetwork => {
+                CliSelectServer::ConfigNetwork(vec![server_name.clone()])
+            }
+            SelectServerDiscriminants::Custom => CliSelectServer::Custom(Default::default()),
+        };
+        Ok(Self::from(cli_select_server)?)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Testnet(testnet) => {
+                let mut args = testnet.to_cli_args();
+                args.push_front("testnet".to_owned());
+                args
+            }
+            Self::Mainnet(mainnet) => {
+                let mut args = mainnet.to_cli_args();
+                args.push_front("mainnet".to_owned());
+                args
+            }
+            Self::Betanet(betanet) => {
+                let mut args = betanet.to_cli_args();
+                args.push_front("betanet".to_owned());
+                args
+            }
+            Self::ConfigNetwork(network_name, server) => {
+                let mut args = server.to_cli_args();
+                args.push_front(network_name.clone());
+                args
+            }
+            Self::Custom(custom) => {
+                let mut args = custom.to_cli_args();
+                args.push_front("custom".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        Ok(match self {
+            SelectServer::Testnet(server) => {
+                server.process(prepopulated_unsigned_transaction).await?;
+            }
+            SelectServer::Mainnet(server) => {
+                server.process(prepopulated_unsigned_transaction).await?;
+            }
+            SelectServer::Betanet(server) => {
+                server.process(prepopulated_unsigned_transaction).await?;
+            }
+            SelectServer::ConfigNetwork(_, server) => {
+                server.process(prepopulated_unsigned_transaction).await?;
+            }
+            SelectServer::Custom(server) => {
+                server.process(prepopulated_unsigned_transaction).await?;
+            }
+        })
+    }
+}
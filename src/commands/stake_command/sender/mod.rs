@@ -0,0 +1,78 @@
+use dialoguer::Input;
+
+/// данные об отправителе транзакции
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    #[clap(subcommand)]
+    action: Option<super::stake_action::CliStakeAction>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    pub action: super::stake_action::StakeAction,
+}
+
+impl Sender {
+    pub fn from(
+        item: CliSender,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let action: super::stake_action::StakeAction = match item.action {
+            Some(cli_action) => super::stake_action::StakeAction::from(
+                cli_action,
+                connection_config,
+                sender_account_id.clone(),
+            )?,
+            None => super::stake_action::StakeAction::choose_action(
+                connection_config,
+                sender_account_id.clone(),
+            )?,
+        };
+        Ok(Self {
+            sender_account_id,
+            action,
+        })
+    }
+}
+
+impl Sender {
+    pub fn input_sender_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<sender-account-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the delegator?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.action.to_cli_args();
+        args.push_front(self.sender_account_id.to_string());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.sender_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
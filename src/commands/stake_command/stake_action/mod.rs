@@ -0,0 +1,357 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+#[derive(Debug, clap::Clap)]
+pub enum CliStakeAction {
+    /// Deposit the amount to the pool and stake it
+    DepositAndStake(CliAmountAction),
+    /// Unstake the amount, it can be withdrawn in 4 epochs
+    Unstake(CliAmountAction),
+    /// Unstake the whole staked balance
+    UnstakeAll(CliAllAction),
+    /// Withdraw the amount of the unstaked balance
+    Withdraw(CliAmountAction),
+    /// Withdraw the whole unstaked balance
+    WithdrawAll(CliAllAction),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum StakeAction {
+    #[strum_discriminants(strum(message = "Deposit and stake"))]
+    DepositAndStake(AmountAction),
+    #[strum_discriminants(strum(message = "Unstake"))]
+    Unstake(AmountAction),
+    #[strum_discriminants(strum(message = "Unstake all"))]
+    UnstakeAll(AllAction),
+    #[strum_discriminants(strum(message = "Withdraw"))]
+    Withdraw(AmountAction),
+    #[strum_discriminants(strum(message = "Withdraw all"))]
+    WithdrawAll(AllAction),
+}
+
+impl StakeAction {
+    pub fn from(
+        item: CliStakeAction,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliStakeAction::DepositAndStake(cli_amount_action) => Ok(Self::DepositAndStake(
+                AmountAction::from(cli_amount_action, connection_config, sender_account_id)?,
+            )),
+            CliStakeAction::Unstake(cli_amount_action) => Ok(Self::Unstake(AmountAction::from(
+                cli_amount_action,
+                connection_config,
+                sender_account_id,
+            )?)),
+            CliStakeAction::UnstakeAll(cli_all_action) => Ok(Self::UnstakeAll(AllAction::from(
+                cli_all_action,
+                connection_config,
+                sender_account_id,
+            )?)),
+            CliStakeAction::Withdraw(cli_amount_action) => Ok(Self::Withdraw(AmountAction::from(
+                cli_amount_action,
+                connection_config,
+                sender_account_id,
+            )?)),
+            CliStakeAction::WithdrawAll(cli_all_action) => Ok(Self::WithdrawAll(AllAction::from(
+                cli_all_action,
+                connection_config,
+                sender_account_id,
+            )?)),
+        }
+    }
+}
+
+impl StakeAction {
+    pub fn choose_action(
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = StakeActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("stake action subcommand");
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the staking pool?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selected_action] {
+            StakeActionDiscriminants::DepositAndStake => {
+                CliStakeAction::DepositAndStake(Default::default())
+            }
+            StakeActionDiscriminants::Unstake => CliStakeAction::Unstake(Default::default()),
+            StakeActionDiscriminants::UnstakeAll => CliStakeAction::UnstakeAll(Default::default()),
+            StakeActionDiscriminants::Withdraw => CliStakeAction::Withdraw(Default::default()),
+            StakeActionDiscriminants::WithdrawAll => {
+                CliStakeAction::WithdrawAll(Default::default())
+            }
+        };
+        Self::from(cli_action, connection_config, sender_account_id)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::DepositAndStake(amount_action) => {
+                let mut args = amount_action.to_cli_args();
+                args.push_front("deposit-and-stake".to_owned());
+                args
+            }
+            Self::Unstake(amount_action) => {
+                let mut args = amount_action.to_cli_args();
+                args.push_front("unstake".to_owned());
+                args
+            }
+            Self::UnstakeAll(all_action) => {
+                let mut args = all_action.to_cli_args();
+                args.push_front("unstake-all".to_owned());
+                args
+            }
+            Self::Withdraw(amount_action) => {
+                let mut args = amount_action.to_cli_args();
+                args.push_front("withdraw".to_owned());
+                args
+            }
+            Self::WithdrawAll(all_action) => {
+                let mut args = all_action.to_cli_args();
+                args.push_front("withdraw-all".to_owned());
+                args
+            }
+        }
+    }
+
+    /// Fails early if the pool would reject the call for the balances of the delegator
+    async fn check_delegation(
+        pool_call: &crate::staking_pool::PoolCall,
+        pool_account_id: &str,
+        account_id: &str,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let delegation = crate::staking_pool::delegation(
+            &network_connection_config.rpc_url(),
+            pool_account_id,
+            account_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        let error = match pool_call {
+            crate::staking_pool::PoolCall::DepositAndStake(_) => None,
+            crate::staking_pool::PoolCall::Unstake(amount)
+                if *amount > delegation.staked_balance =>
+            {
+                Some(format!(
+                    "<{}> has only {} staked with <{}>",
+                    account_id,
+                    crate::common::NearBalance::from_yoctonear(delegation.staked_balance),
+                    pool_account_id
+                ))
+            }
+            crate::staking_pool::PoolCall::UnstakeAll if delegation.staked_balance == 0 => {
+                Some(format!(
+                    "<{}> has nothing staked with <{}>",
+                    account_id, pool_account_id
+                ))
+            }
+            crate::staking_pool::PoolCall::Withdraw(amount)
+                if *amount > delegation.unstaked_balance =>
+            {
+                Some(format!(
+                    "<{}> has only {} unstaked with <{}>",
+                    account_id,
+                    crate::common::NearBalance::from_yoctonear(delegation.unstaked_balance),
+                    pool_account_id
+                ))
+            }
+            crate::staking_pool::PoolCall::WithdrawAll if delegation.unstaked_balance == 0 => {
+                Some(format!(
+                    "<{}> has nothing unstaked with <{}>",
+                    account_id, pool_account_id
+                ))
+            }
+            crate::staking_pool::PoolCall::Withdraw(_)
+            | crate::staking_pool::PoolCall::WithdrawAll
+                if !delegation.is_unstaked_balance_available =>
+            {
+                Some(format!(
+                    "The unstaked balance of <{}> with <{}> is not available yet, it can be withdrawn 4 epochs after unstaking",
+                    account_id, pool_account_id
+                ))
+            }
+            _ => None,
+        };
+        match error {
+            Some(error) => Err(crate::common::CliError::UserInput(error).into()),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let (pool_call, sign_option) = match self {
+            Self::DepositAndStake(amount_action) => (
+                crate::staking_pool::PoolCall::DepositAndStake(amount_action.amount.to_yoctonear()),
+                amount_action.sign_option,
+            ),
+            Self::Unstake(amount_action) => (
+                crate::staking_pool::PoolCall::Unstake(amount_action.amount.to_yoctonear()),
+                amount_action.sign_option,
+            ),
+            Self::UnstakeAll(all_action) => (
+                crate::staking_pool::PoolCall::UnstakeAll,
+                all_action.sign_option,
+            ),
+            Self::Withdraw(amount_action) => (
+                crate::staking_pool::PoolCall::Withdraw(amount_action.amount.to_yoctonear()),
+                amount_action.sign_option,
+            ),
+            Self::WithdrawAll(all_action) => (
+                crate::staking_pool::PoolCall::WithdrawAll,
+                all_action.sign_option,
+            ),
+        };
+        Self::check_delegation(
+            &pool_call,
+            &prepopulated_unsigned_transaction.receiver_id,
+            &prepopulated_unsigned_transaction.signer_id,
+            &network_connection_config,
+        )
+        .await?;
+        crate::common::print_message(&format!(
+            "\nCalling <{}> of the staking pool <{}> for <{}>",
+            pool_call.method_name(),
+            prepopulated_unsigned_transaction.receiver_id,
+            prepopulated_unsigned_transaction.signer_id
+        ));
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(pool_call.action());
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                )
+                .await?;
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
+
+/// вызов staking pool с суммой
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliAmountAction {
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct AmountAction {
+    pub amount: crate::common::NearBalance,
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl AmountAction {
+    fn from(
+        item: CliAmountAction,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        let amount: crate::common::NearBalance = match item.amount {
+            Some(cli_amount) => cli_amount,
+            None => AmountAction::input_amount(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), sender_account_id)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), sender_account_id)?,
+        };
+        Ok(Self {
+            amount,
+            sign_option,
+        })
+    }
+}
+
+impl AmountAction {
+    pub fn input_amount() -> crate::common::NearBalance {
+        crate::common::ensure_interactive("<amount>");
+        Input::new()
+            .with_prompt("How many NEAR Tokens? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_option.to_cli_args();
+        args.push_front(self.amount.to_cli_arg());
+        args
+    }
+}
+
+/// вызов staking pool для всего баланса
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliAllAction {
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct AllAction {
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl AllAction {
+    fn from(
+        item: CliAllAction,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), sender_account_id)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), sender_account_id)?,
+        };
+        Ok(Self { sign_option })
+    }
+}
+
+impl AllAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.sign_option.to_cli_args()
+    }
+}
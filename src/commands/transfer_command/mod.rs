@@ -4,7 +4,9 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 pub mod operation_mode;
 mod receiver;
 mod sender;
+mod transfer_ft;
 pub mod transfer_near_tokens_type;
+mod transfer_nft;
 
 /// инструмент выбора переводимой валюты
 #[derive(Debug, Default, clap::Clap)]
@@ -34,6 +36,10 @@ impl Currency {
 }
 
 impl Currency {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.currency_selection.to_cli_args()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -48,6 +54,10 @@ impl Currency {
 enum CliCurrencySelection {
     /// отправка трансфера в NEAR tokens
     NEAR(self::operation_mode::CliOperationMode),
+    /// отправка трансфера в fungible tokens (NEP-141)
+    Ft(self::transfer_ft::CliTransferFt),
+    /// отправка трансфера non-fungible token (NEP-171)
+    Nft(self::transfer_nft::CliTransferNft),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -55,6 +65,10 @@ enum CliCurrencySelection {
 enum CurrencySelection {
     #[strum_discriminants(strum(message = "NEAR tokens"))]
     NEAR(self::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Fungible tokens (NEP-141)"))]
+    Ft(self::transfer_ft::TransferFt),
+    #[strum_discriminants(strum(message = "Non-fungible token (NEP-171)"))]
+    Nft(self::transfer_nft::TransferNft),
 }
 
 impl CurrencySelection {
@@ -63,6 +77,12 @@ impl CurrencySelection {
             CliCurrencySelection::NEAR(cli_operation_mode) => Ok(Self::NEAR(
                 self::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
+            CliCurrencySelection::Ft(cli_transfer_ft) => Ok(Self::Ft(
+                self::transfer_ft::TransferFt::from(cli_transfer_ft)?,
+            )),
+            CliCurrencySelection::Nft(cli_transfer_nft) => Ok(Self::Nft(
+                self::transfer_nft::TransferNft::from(cli_transfer_nft)?,
+            )),
         }
     }
 }
@@ -75,6 +95,7 @@ impl CurrencySelection {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("currency subcommand");
         let selected_currency = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("What do you want to transfer?")
             .items(&currencies)
@@ -83,10 +104,32 @@ impl CurrencySelection {
             .unwrap();
         let cli_currency = match variants[selected_currency] {
             CurrencySelectionDiscriminants::NEAR => CliCurrencySelection::NEAR(Default::default()),
+            CurrencySelectionDiscriminants::Ft => CliCurrencySelection::Ft(Default::default()),
+            CurrencySelectionDiscriminants::Nft => CliCurrencySelection::Nft(Default::default()),
         };
         Ok(Self::from(cli_currency)?)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::NEAR(near) => {
+                let mut args = near.to_cli_args();
+                args.push_front("near".to_owned());
+                args
+            }
+            Self::Ft(transfer_ft) => {
+                let mut args = transfer_ft.to_cli_args();
+                args.push_front("ft".to_owned());
+                args
+            }
+            Self::Nft(transfer_nft) => {
+                let mut args = transfer_nft.to_cli_args();
+                args.push_front("nft".to_owned());
+                args
+            }
+        }
+    }
+
     async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -97,6 +140,12 @@ impl CurrencySelection {
                     .process(prepopulated_unsigned_transaction)
                     .await
             }
+            Self::Ft(transfer_ft) => transfer_ft.process(prepopulated_unsigned_transaction).await,
+            Self::Nft(transfer_nft) => {
+                transfer_nft
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
         }
     }
 }
@@ -0,0 +1,70 @@
+use dialoguer::Input;
+
+pub mod operation_mode;
+mod receiver;
+mod sender;
+mod transfer_ft_tokens_type;
+
+/// перевод fungible tokens (NEP-141)
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliTransferFt {
+    token_contract_id: Option<String>,
+    #[clap(subcommand)]
+    mode: Option<self::operation_mode::CliMode>,
+}
+
+#[derive(Debug)]
+pub struct TransferFt {
+    pub token_contract_id: String,
+    mode: self::operation_mode::Mode,
+}
+
+impl TransferFt {
+    pub fn from(item: CliTransferFt) -> color_eyre::eyre::Result<Self> {
+        let token_contract_id = match item.token_contract_id {
+            Some(token_contract_id) => token_contract_id,
+            None => TransferFt::input_token_contract_id(),
+        };
+        let mode = match item.mode {
+            Some(cli_mode) => self::operation_mode::Mode::from(cli_mode, &token_contract_id)?,
+            None => self::operation_mode::Mode::choose_mode(&token_contract_id)?,
+        };
+        Ok(Self {
+            token_contract_id,
+            mode,
+        })
+    }
+}
+
+impl TransferFt {
+    fn input_token_contract_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<token-contract-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the token contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.mode.to_cli_args();
+        args.push_front(self.token_contract_id.clone());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.token_contract_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.mode.process(unsigned_transaction).await
+    }
+}
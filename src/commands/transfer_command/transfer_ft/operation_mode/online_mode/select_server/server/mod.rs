@@ -0,0 +1,175 @@
+use dialoguer::Input;
+
+/// предустановленный RPC-сервер
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliServer {
+    #[clap(subcommand)]
+    pub send_from: Option<CliSendFrom>,
+}
+
+/// данные для custom server
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliCustomServer {
+    #[clap(long)]
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
+    #[clap(subcommand)]
+    send_from: Option<CliSendFrom>,
+}
+
+#[derive(Debug)]
+pub struct Server {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub send_from: SendFrom,
+}
+
+impl CliServer {
+    pub fn into_server(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        token_contract_id: &str,
+    ) -> color_eyre::eyre::Result<Server> {
+        let send_from = match self.send_from {
+            Some(cli_send_from) => {
+                SendFrom::from(cli_send_from, connection_config.clone(), token_contract_id)?
+            }
+            None => SendFrom::choose_send_from(connection_config.clone(), token_contract_id)?,
+        };
+        Ok(Server {
+            connection_config,
+            send_from,
+        })
+    }
+}
+
+impl CliCustomServer {
+    pub fn into_server(self, token_contract_id: &str) -> color_eyre::eyre::Result<Server> {
+        let url: crate::common::AvailableRpcServerUrl = match self.url {
+            Some(url) => url,
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
+        };
+        let connection_config = crate::common::ConnectionConfig::custom(
+            url.inner,
+            self.archival_url,
+            self.wallet_url,
+            self.explorer_url,
+        );
+        let send_from = match self.send_from {
+            Some(cli_send_from) => {
+                SendFrom::from(cli_send_from, connection_config.clone(), token_contract_id)?
+            }
+            None => SendFrom::choose_send_from(connection_config.clone(), token_contract_id)?,
+        };
+        Ok(Server {
+            connection_config,
+            send_from,
+        })
+    }
+}
+
+impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.connection_config.to_cli_args();
+        args.extend(self.send_from.to_cli_args());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.send_from
+            .process(prepopulated_unsigned_transaction, self.connection_config)
+            .await
+    }
+}
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendFrom {
+    /// Specify a sender
+    Sender(crate::commands::transfer_command::transfer_ft::sender::CliSender),
+}
+
+#[derive(Debug)]
+pub enum SendFrom {
+    Sender(crate::commands::transfer_command::transfer_ft::sender::Sender),
+}
+
+impl SendFrom {
+    pub fn from(
+        item: CliSendFrom,
+        connection_config: crate::common::ConnectionConfig,
+        token_contract_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSendFrom::Sender(cli_sender) => Ok(Self::Sender(
+                crate::commands::transfer_command::transfer_ft::sender::Sender::from(
+                    cli_sender,
+                    connection_config,
+                    token_contract_id,
+                )?,
+            )),
+        }
+    }
+}
+
+impl SendFrom {
+    pub fn choose_send_from(
+        connection_config: crate::common::ConnectionConfig,
+        token_contract_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self::from(
+            CliSendFrom::Sender(Default::default()),
+            connection_config,
+            token_contract_id,
+        )?)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Sender(sender) => {
+                let mut args = sender.to_cli_args();
+                args.push_front("sender".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendFrom::Sender(sender) => {
+                sender
+                    .process(prepopulated_unsigned_transaction, connection_config)
+                    .await
+            }
+        }
+    }
+}
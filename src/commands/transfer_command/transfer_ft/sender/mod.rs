@@ -0,0 +1,81 @@
+use dialoguer::Input;
+
+/// данные об отправителе транзакции
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    #[clap(subcommand)]
+    send_to: Option<super::receiver::CliSendTo>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    pub send_to: super::receiver::SendTo,
+}
+
+impl Sender {
+    pub fn from(
+        item: CliSender,
+        connection_config: crate::common::ConnectionConfig,
+        token_contract_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let send_to: super::receiver::SendTo = match item.send_to {
+            Some(cli_send_to) => super::receiver::SendTo::from(
+                cli_send_to,
+                connection_config,
+                sender_account_id.clone(),
+                token_contract_id,
+            )?,
+            None => super::receiver::SendTo::send_to(
+                connection_config,
+                sender_account_id.clone(),
+                token_contract_id,
+            )?,
+        };
+        Ok(Self {
+            sender_account_id,
+            send_to,
+        })
+    }
+}
+
+impl Sender {
+    pub fn input_sender_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<sender-account-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the sender?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.send_to.to_cli_args();
+        args.push_front(self.sender_account_id.to_string());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.sender_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.send_to
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,339 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliTransfer {
+    /// Enter an amount
+    Amount(CliTransferFtTokensAction),
+}
+
+#[derive(Debug)]
+pub enum Transfer {
+    Amount(TransferFtTokensAction),
+}
+
+impl Transfer {
+    pub fn from(
+        item: CliTransfer,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+        token_contract_id: &str,
+        receiver_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliTransfer::Amount(cli_transfer_ft_action) => {
+                Ok(Self::Amount(TransferFtTokensAction::from(
+                    cli_transfer_ft_action,
+                    connection_config,
+                    sender_account_id,
+                    token_contract_id,
+                    receiver_account_id,
+                )?))
+            }
+        }
+    }
+}
+
+impl Transfer {
+    pub fn choose_transfer_ft(
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+        token_contract_id: &str,
+        receiver_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        Self::from(
+            CliTransfer::Amount(Default::default()),
+            connection_config,
+            sender_account_id,
+            token_contract_id,
+            receiver_account_id,
+        )
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Amount(amount) => {
+                let mut args = amount.to_cli_args();
+                args.push_front("amount".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        receiver_account_id: String,
+    ) -> crate::CliResult {
+        match self {
+            Transfer::Amount(transfer_ft_action) => {
+                transfer_ft_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        receiver_account_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// создание перевода fungible tokens
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliTransferFtTokensAction {
+    /// The amount in the decimals of the token, e.g. 1.5
+    amount: Option<crate::fungible_token::FtAmount>,
+    /// A memo for the transfer
+    #[clap(long)]
+    memo: Option<String>,
+    /// Call `ft_on_transfer` of the receiver with this message (`ft_transfer_call`)
+    #[clap(long)]
+    msg: Option<String>,
+    /// Register the receiver with the token contract if it is not registered yet
+    #[clap(long)]
+    storage_deposit: bool,
+    /// The gas for the transfer (30 TeraGas by default, 100 TeraGas with --msg)
+    #[clap(long)]
+    prepaid_gas: Option<crate::common::NearGas>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct TransferFtTokensAction {
+    pub amount: crate::fungible_token::FtAmount,
+    memo: Option<String>,
+    msg: Option<String>,
+    storage_deposit: bool,
+    prepaid_gas: Option<crate::common::NearGas>,
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl TransferFtTokensAction {
+    fn from(
+        item: CliTransferFtTokensAction,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+        token_contract_id: &str,
+        receiver_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        let amount: crate::fungible_token::FtAmount = match item.amount {
+            Some(cli_amount) => cli_amount,
+            None => TransferFtTokensAction::input_amount(),
+        };
+        let storage_deposit = item.storage_deposit
+            || TransferFtTokensAction::input_storage_deposit(
+                &connection_config,
+                token_contract_id,
+                receiver_account_id,
+            )?;
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), sender_account_id)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), sender_account_id)?,
+        };
+        Ok(Self {
+            amount,
+            memo: item.memo,
+            msg: item.msg,
+            storage_deposit,
+            prepaid_gas: item.prepaid_gas,
+            sign_option,
+        })
+    }
+}
+
+impl TransferFtTokensAction {
+    pub fn input_amount() -> crate::fungible_token::FtAmount {
+        crate::common::ensure_interactive("<amount>");
+        Input::new()
+            .with_prompt("How many tokens do you want to transfer? (example: 10 or 0.5)")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_option.to_cli_args();
+        if let Some(prepaid_gas) = &self.prepaid_gas {
+            args.push_front(prepaid_gas.to_cli_arg());
+            args.push_front("--prepaid-gas".to_owned());
+        }
+        if self.storage_deposit {
+            args.push_front("--storage-deposit".to_owned());
+        }
+        if let Some(msg) = &self.msg {
+            args.push_front(msg.clone());
+            args.push_front("--msg".to_owned());
+        }
+        if let Some(memo) = &self.memo {
+            args.push_front(memo.clone());
+            args.push_front("--memo".to_owned());
+        }
+        args.push_front(self.amount.to_cli_arg());
+        args
+    }
+
+    /// Asked only if the receiver is not registered with the token contract yet, so that the
+    /// printed command carries `--storage-deposit` when it is needed
+    fn input_storage_deposit(
+        connection_config: &crate::common::ConnectionConfig,
+        token_contract_id: &str,
+        receiver_account_id: &str,
+    ) -> color_eyre::eyre::Result<bool> {
+        let rpc_url = connection_config.rpc_url();
+        let deposit = actix::System::new().block_on(async {
+            if crate::fungible_token::is_registered(
+                &rpc_url,
+                token_contract_id,
+                receiver_account_id,
+            )
+            .await?
+            {
+                return Ok(None);
+            }
+            let storage_balance_bounds =
+                crate::fungible_token::storage_balance_bounds(&rpc_url, token_contract_id).await?;
+            crate::fungible_token::parse_u128(&storage_balance_bounds.min).map(Some)
+        })?;
+        let deposit = match deposit {
+            Some(deposit) => deposit,
+            None => return Ok(false),
+        };
+        println!();
+        crate::common::ensure_interactive("--storage-deposit");
+        let storage_deposit = dialoguer::Confirm::new()
+            .with_prompt(format!(
+                "<{}> is not registered with the token contract. Attach a storage deposit of {} to register it?",
+                receiver_account_id,
+                crate::common::NearBalance::from_yoctonear(deposit)
+            ))
+            .default(true)
+            .interact()
+            .unwrap();
+        if !storage_deposit {
+            return Err(not_registered_error(receiver_account_id, token_contract_id));
+        }
+        Ok(true)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        receiver_account_id: String,
+    ) -> crate::CliResult {
+        let rpc_url = network_connection_config.rpc_url();
+        let token_contract_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let metadata = crate::fungible_token::ft_metadata(&rpc_url, &token_contract_id).await?;
+        let amount = self
+            .amount
+            .to_base_units(metadata.decimals)
+            .map_err(crate::common::CliError::UserInput)?;
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        if !crate::fungible_token::is_registered(&rpc_url, &token_contract_id, &receiver_account_id)
+            .await?
+        {
+            let storage_balance_bounds =
+                crate::fungible_token::storage_balance_bounds(&rpc_url, &token_contract_id).await?;
+            let deposit = crate::fungible_token::parse_u128(&storage_balance_bounds.min)?;
+            if !self.storage_deposit {
+                return Err(not_registered_error(
+                    &receiver_account_id,
+                    &token_contract_id,
+                ));
+            }
+            actions.push(near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: "storage_deposit".to_owned(),
+                    args: serde_json::json!({
+                        "account_id": receiver_account_id,
+                        "registration_only": true,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    gas: crate::fungible_token::STORAGE_DEPOSIT_GAS,
+                    deposit,
+                },
+            ));
+        }
+        let (method_name, args, default_gas) = match &self.msg {
+            Some(msg) => (
+                "ft_transfer_call",
+                serde_json::json!({
+                    "receiver_id": receiver_account_id,
+                    "amount": amount.to_string(),
+                    "memo": self.memo,
+                    "msg": msg,
+                }),
+                crate::fungible_token::FT_TRANSFER_CALL_GAS,
+            ),
+            None => (
+                "ft_transfer",
+                serde_json::json!({
+                    "receiver_id": receiver_account_id,
+                    "amount": amount.to_string(),
+                    "memo": self.memo,
+                }),
+                crate::fungible_token::FT_TRANSFER_GAS,
+            ),
+        };
+        actions.push(near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_owned(),
+                args: args.to_string().into_bytes(),
+                gas: self
+                    .prepaid_gas
+                    .as_ref()
+                    .map(|prepaid_gas| prepaid_gas.inner)
+                    .unwrap_or(default_gas),
+                deposit: crate::fungible_token::ONE_YOCTO_NEAR,
+            },
+        ));
+        crate::common::print_message(&format!(
+            "\nTransferring {} {} ({} in the smallest units) from <{}> to <{}>",
+            crate::fungible_token::format_amount(amount, metadata.decimals),
+            metadata.symbol,
+            amount,
+            prepopulated_unsigned_transaction.signer_id,
+            receiver_account_id
+        ));
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                )
+                .await?;
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
+
+fn not_registered_error(receiver_account_id: &str, token_contract_id: &str) -> color_eyre::Report {
+    crate::common::CliError::UserInput(format!(
+        "<{}> is not registered with the token contract <{}>, the transfer would fail",
+        receiver_account_id, token_contract_id
+    ))
+    .into()
+}
@@ -0,0 +1,85 @@
+use dialoguer::Input;
+
+pub mod operation_mode;
+mod receiver;
+mod sender;
+
+/// перевод non-fungible token (NEP-171)
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliTransferNft {
+    nft_contract_id: Option<String>,
+    token_id: Option<String>,
+    #[clap(subcommand)]
+    mode: Option<self::operation_mode::CliMode>,
+}
+
+#[derive(Debug)]
+pub struct TransferNft {
+    pub nft_contract_id: String,
+    pub token_id: String,
+    mode: self::operation_mode::Mode,
+}
+
+impl TransferNft {
+    pub fn from(item: CliTransferNft) -> color_eyre::eyre::Result<Self> {
+        let nft_contract_id = match item.nft_contract_id {
+            Some(nft_contract_id) => nft_contract_id,
+            None => TransferNft::input_nft_contract_id(),
+        };
+        let token_id = match item.token_id {
+            Some(token_id) => token_id,
+            None => TransferNft::input_token_id(),
+        };
+        let mode = match item.mode {
+            Some(cli_mode) => self::operation_mode::Mode::from(cli_mode)?,
+            None => self::operation_mode::Mode::choose_mode()?,
+        };
+        Ok(Self {
+            nft_contract_id,
+            token_id,
+            mode,
+        })
+    }
+}
+
+impl TransferNft {
+    fn input_nft_contract_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<nft-contract-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the NFT contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_token_id() -> String {
+        crate::common::ensure_interactive("<token-id>");
+        Input::new()
+            .with_prompt("What is the ID of the token?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.mode.to_cli_args();
+        args.push_front(self.token_id.clone());
+        args.push_front(self.nft_contract_id.clone());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.nft_contract_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.mode.process(unsigned_transaction, self.token_id).await
+    }
+}
@@ -0,0 +1,262 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a receiver
+    Receiver(CliReceiver),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Receiver(Receiver),
+}
+
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSendTo::Receiver(cli_receiver) => {
+                let receiver = Receiver::from(cli_receiver, connection_config, sender_account_id)?;
+                Ok(Self::Receiver(receiver))
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to(
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self::from(
+            CliSendTo::Receiver(Default::default()),
+            connection_config,
+            sender_account_id,
+        )?)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Receiver(receiver) => {
+                let mut args = receiver.to_cli_args();
+                args.push_front("receiver".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        token_id: String,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Receiver(receiver) => {
+                receiver
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        token_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о получателе токена
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliReceiver {
+    receiver_account_id: Option<String>,
+    /// A memo for the transfer
+    #[clap(long)]
+    memo: Option<String>,
+    /// Call `nft_on_transfer` of the receiver with this message (`nft_transfer_call`)
+    #[clap(long)]
+    msg: Option<String>,
+    /// The approval ID, when the sender is an approved account and not the owner
+    #[clap(long)]
+    approval_id: Option<u64>,
+    /// The gas for the transfer (30 TeraGas by default, 100 TeraGas with --msg)
+    #[clap(long)]
+    prepaid_gas: Option<crate::common::NearGas>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct Receiver {
+    pub receiver_account_id: String,
+    memo: Option<String>,
+    msg: Option<String>,
+    approval_id: Option<u64>,
+    prepaid_gas: Option<crate::common::NearGas>,
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl Receiver {
+    fn from(
+        item: CliReceiver,
+        connection_config: crate::common::ConnectionConfig,
+        sender_account_id: String,
+    ) -> color_eyre::eyre::Result<Self> {
+        let receiver_account_id: String = match item.receiver_account_id {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => Receiver::input_receiver_account_id(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), sender_account_id)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), sender_account_id)?,
+        };
+        Ok(Self {
+            receiver_account_id,
+            memo: item.memo,
+            msg: item.msg,
+            approval_id: item.approval_id,
+            prepaid_gas: item.prepaid_gas,
+            sign_option,
+        })
+    }
+}
+
+impl Receiver {
+    pub fn input_receiver_account_id() -> String {
+        crate::common::ensure_interactive("<receiver-account-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the receiver of the token?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.sign_option.to_cli_args();
+        if let Some(prepaid_gas) = &self.prepaid_gas {
+            args.push_front(prepaid_gas.to_cli_arg());
+            args.push_front("--prepaid-gas".to_owned());
+        }
+        if let Some(approval_id) = &self.approval_id {
+            args.push_front(approval_id.to_string());
+            args.push_front("--approval-id".to_owned());
+        }
+        if let Some(msg) = &self.msg {
+            args.push_front(msg.clone());
+            args.push_front("--msg".to_owned());
+        }
+        if let Some(memo) = &self.memo {
+            args.push_front(memo.clone());
+            args.push_front("--memo".to_owned());
+        }
+        args.push_front(self.receiver_account_id.to_string());
+        args
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        token_id: String,
+    ) -> crate::CliResult {
+        // The transaction goes to the NFT contract, the receiver is an argument of nft_transfer
+        let nft_contract_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let token = match crate::non_fungible_token::nft_token(
+            &network_connection_config.rpc_url(),
+            &nft_contract_id,
+            &token_id,
+        )
+        .await?
+        {
+            Some(token) => token,
+            None => {
+                return Err(crate::common::CliError::UserInput(format!(
+                    "The token <{}> does not exist on the contract <{}>",
+                    token_id, nft_contract_id
+                ))
+                .into())
+            }
+        };
+        // An approved account transfers the token of someone else with its approval ID
+        if token.owner_id != prepopulated_unsigned_transaction.signer_id
+            && self.approval_id.is_none()
+        {
+            return Err(crate::common::CliError::UserInput(format!(
+                "The token <{}> is owned by <{}>, not by <{}> (pass --approval-id to transfer it as an approved account)",
+                token_id, token.owner_id, prepopulated_unsigned_transaction.signer_id
+            ))
+            .into());
+        }
+        let (method_name, args, default_gas) = match &self.msg {
+            Some(msg) => (
+                "nft_transfer_call",
+                serde_json::json!({
+                    "receiver_id": self.receiver_account_id,
+                    "token_id": token_id,
+                    "approval_id": self.approval_id,
+                    "memo": self.memo,
+                    "msg": msg,
+                }),
+                crate::non_fungible_token::NFT_TRANSFER_CALL_GAS,
+            ),
+            None => (
+                "nft_transfer",
+                serde_json::json!({
+                    "receiver_id": self.receiver_account_id,
+                    "token_id": token_id,
+                    "approval_id": self.approval_id,
+                    "memo": self.memo,
+                }),
+                crate::non_fungible_token::NFT_TRANSFER_GAS,
+            ),
+        };
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_owned(),
+                args: args.to_string().into_bytes(),
+                gas: self
+                    .prepaid_gas
+                    .as_ref()
+                    .map(|prepaid_gas| prepaid_gas.inner)
+                    .unwrap_or(default_gas),
+                deposit: crate::non_fungible_token::ONE_YOCTO_NEAR,
+            },
+        ));
+        crate::common::print_message(&format!(
+            "\nTransferring the token <{}> of <{}> from <{}> to <{}>",
+            token_id, nft_contract_id, token.owner_id, self.receiver_account_id
+        ));
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                )
+                .await?;
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
@@ -42,6 +42,7 @@ impl From<CliCombineTransactionSignature> for CombineTransactionSignature {
 
 impl CombineTransactionSignature {
     pub fn input_signature() -> near_crypto::Signature {
+        crate::common::ensure_interactive("--signature");
         Input::new()
             .with_prompt("Enter the signature")
             .interact_text()
@@ -49,6 +50,7 @@ impl CombineTransactionSignature {
     }
 
     pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+        crate::common::ensure_interactive("--unsigned-transaction");
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
             .interact_text()
@@ -56,6 +58,20 @@ impl CombineTransactionSignature {
         input.inner
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        args.push_front(
+            crate::common::TransactionAsBase64 {
+                inner: self.unsigned_transaction.clone(),
+            }
+            .to_cli_arg(),
+        );
+        args.push_front("--unsigned-transaction".to_owned());
+        args.push_front(self.signature.to_string());
+        args.push_front("--signature".to_owned());
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let signed_transaction = near_primitives::transaction::SignedTransaction::new(
             self.signature,
@@ -66,14 +82,18 @@ impl CombineTransactionSignature {
                 .try_to_vec()
                 .expect("Transaction is not expected to fail on serialization"),
         );
-        println!("\nThe transaction has been successfully signed.");
-        println!("Signed transaction:");
-        crate::common::print_transaction(signed_transaction.transaction.clone());
-        println!("{:<13} {}", "signature:", signed_transaction.signature);
-        println!(
-            "Base64-encoded signed transaction:\n{}",
-            serialize_to_base64
-        );
+        crate::common::print_message("\nThe transaction has been successfully signed.");
+        crate::common::print_message("Signed transaction:");
+        crate::common::print_signed_transaction(signed_transaction.clone());
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => println!(
+                "Base64-encoded signed transaction:\n{}",
+                serialize_to_base64
+            ),
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("signed_transaction_base64", serialize_to_base64)
+            }
+        }
         Ok(())
     }
 }
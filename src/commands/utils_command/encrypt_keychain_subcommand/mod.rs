@@ -0,0 +1,80 @@
+/// Encrypt the plaintext key files of the keychain with a passphrase
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliEncryptKeychain {
+    /// Read the passphrase from this file descriptor
+    #[clap(long)]
+    passphrase_fd: Option<i32>,
+}
+
+impl CliEncryptKeychain {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(passphrase_fd) = self.passphrase_fd {
+            args.push_front(passphrase_fd.to_string());
+            args.push_front("--passphrase-fd".to_owned());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut plaintext_files = vec![];
+        let mut encrypted_file = None;
+        for dir in crate::keychain::keychain_dirs()? {
+            for path in crate::keychain::key_files(&dir)? {
+                let data = std::fs::read_to_string(&path)?;
+                if !crate::keychain::is_encrypted(&data) {
+                    plaintext_files.push((path, data));
+                } else if encrypted_file.is_none() {
+                    encrypted_file = Some((path, data));
+                }
+            }
+        }
+        if plaintext_files.is_empty() {
+            crate::common::print_message("There are no plaintext key files in the keychain.");
+            crate::common::set_json_output("encrypted_files", Vec::<String>::new());
+            return Ok(());
+        }
+        let passphrase = crate::keychain::passphrase(self.passphrase_fd, encrypted_file.is_none())?;
+        // The whole keychain is kept under one passphrase
+        if let Some((path, data)) = encrypted_file {
+            crate::keychain::decrypt(&data, &passphrase).map_err(|_| {
+                crate::common::CliError::Keychain(format!(
+                    "The passphrase does not open {}, which is already encrypted. Enter the passphrase of the encrypted key files.",
+                    path.display()
+                ))
+            })?;
+        }
+        let mut encrypted_files = vec![];
+        for (path, data) in plaintext_files {
+            match crate::keychain::encrypt(&data, &passphrase) {
+                Ok(encrypted_data) => {
+                    crate::keychain::write_private_file(&path, encrypted_data.as_bytes()).map_err(
+                        |err| {
+                            crate::common::CliError::Keychain(format!(
+                                "Failed to write to file {}: {}",
+                                path.display(),
+                                err
+                            ))
+                        },
+                    )?;
+                    crate::common::print_message(&format!("Encrypted {}", path.display()));
+                    encrypted_files.push(path.display().to_string());
+                }
+                Err(err) => {
+                    crate::common::print_message(&format!("Skipped {}: {}", path.display(), err))
+                }
+            }
+        }
+        crate::common::print_message(&format!(
+            "Set `encrypt = true` in the [keychain] section of {} to encrypt the new key files too.",
+            crate::config::Config::path().display()
+        ));
+        crate::common::set_json_output("encrypted_files", encrypted_files);
+        Ok(())
+    }
+}
@@ -10,8 +10,6 @@ pub struct CliGenerateKeypair {
     pub new_master_seed_phrase_words_count: usize,
     #[clap(long, default_value = "m/44'/397'/0'")]
     pub seed_phrase_hd_path: slip10::BIP32Path,
-    #[clap(long, default_value = "plaintext")]
-    pub format: crate::common::OutputFormat,
 }
 
 impl Default for CliGenerateKeypair {
@@ -20,15 +18,27 @@ impl Default for CliGenerateKeypair {
             master_seed_phrase: None,
             new_master_seed_phrase_words_count: 12,
             seed_phrase_hd_path: slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
-            format: crate::common::OutputFormat::Json,
         }
     }
 }
 
 impl CliGenerateKeypair {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        args.push_front(self.seed_phrase_hd_path.to_string());
+        args.push_front("--seed-phrase-hd-path".to_owned());
+        args.push_front(self.new_master_seed_phrase_words_count.to_string());
+        args.push_front("--new-master-seed-phrase-words-count".to_owned());
+        if self.master_seed_phrase.is_some() {
+            args.push_front(crate::common::SECRET_PLACEHOLDER.to_owned());
+            args.push_front("--master-seed-phrase".to_owned());
+        }
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let key_pair_properties = crate::common::generate_keypair().await?;
-        match self.format {
+        match crate::common::output_format() {
             crate::common::OutputFormat::Plaintext => {
                 println!(
                     "Master Seed Phrase: {}\nSeed Phrase HD Path: {}\nImplicit Account ID: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
@@ -40,16 +50,22 @@ impl CliGenerateKeypair {
                 );
             }
             crate::common::OutputFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&serde_json::json!({
-                        "master_seed_phrase": key_pair_properties.master_seed_phrase,
-                        "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
-                        "account_id": key_pair_properties.implicit_account_id,
-                        "public_key": key_pair_properties.public_key_str,
-                        "private_key": key_pair_properties.secret_keypair_str,
-                    }))
-                    .unwrap()
+                crate::common::set_json_output(
+                    "master_seed_phrase",
+                    key_pair_properties.master_seed_phrase,
+                );
+                crate::common::set_json_output(
+                    "seed_phrase_hd_path",
+                    key_pair_properties.seed_phrase_hd_path.to_string(),
+                );
+                crate::common::set_json_output(
+                    "account_id",
+                    key_pair_properties.implicit_account_id,
+                );
+                crate::common::set_json_output("public_key", key_pair_properties.public_key_str);
+                crate::common::set_json_output(
+                    "private_key",
+                    key_pair_properties.secret_keypair_str,
                 );
             }
         };
@@ -0,0 +1,82 @@
+use dialoguer::Input;
+
+/// Inspect a contract file: its exported functions, imports, memory, size and code hash
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliInspectWasm {
+    file_path: Option<std::path::PathBuf>,
+    /// The code hash of the account (see `view account-summary`) to compare with
+    #[clap(long)]
+    code_hash: Option<near_primitives::hash::CryptoHash>,
+}
+
+#[derive(Debug)]
+pub struct InspectWasm {
+    file_path: std::path::PathBuf,
+    code_hash: Option<near_primitives::hash::CryptoHash>,
+}
+
+impl From<CliInspectWasm> for InspectWasm {
+    fn from(item: CliInspectWasm) -> Self {
+        let file_path = match item.file_path {
+            Some(file_path) => file_path,
+            None => InspectWasm::input_file_path(),
+        };
+        Self {
+            file_path,
+            code_hash: item.code_hash,
+        }
+    }
+}
+
+impl InspectWasm {
+    fn input_file_path() -> std::path::PathBuf {
+        println!();
+        crate::common::ensure_interactive("<file-path>");
+        Input::<String>::new()
+            .with_prompt("What is a file location of the contract?")
+            .interact_text()
+            .unwrap()
+            .into()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::from(vec![self.file_path.display().to_string()]);
+        if let Some(code_hash) = &self.code_hash {
+            args.push_back("--code-hash".to_owned());
+            args.push_back(code_hash.to_string());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let code = std::fs::read(&self.file_path).map_err(|err| {
+            crate::common::CliError::UserInput(format!(
+                "Failed to read {}: {}",
+                self.file_path.display(),
+                err
+            ))
+        })?;
+        let wasm_info = crate::wasm::WasmInfo::new(&code).map_err(|err| {
+            crate::common::CliError::UserInput(format!(
+                "{} is not a valid WebAssembly module: {}",
+                self.file_path.display(),
+                err
+            ))
+        })?;
+        if !wasm_info.print(self.code_hash.as_ref(), crate::wasm::MAX_CONTRACT_SIZE) {
+            return Err(crate::common::CliError::CodeHashMismatch(format!(
+                "The code hash {} of {} does not match the code hash {}",
+                crate::wasm::display_code_hash(&wasm_info.code_hash),
+                self.file_path.display(),
+                crate::wasm::display_code_hash(&self.code_hash.unwrap())
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
@@ -6,25 +6,29 @@ use std::str::FromStr;
 pub struct CliLedgerPublicKey {
     #[clap(long, default_value = "44'/397'/0'/0'/1'")]
     pub seed_phrase_hd_path: slip10::BIP32Path,
-    #[clap(long, default_value = "plaintext")]
-    pub format: crate::common::OutputFormat,
 }
 
 impl Default for CliLedgerPublicKey {
     fn default() -> Self {
         Self {
             seed_phrase_hd_path: slip10::BIP32Path::from_str("44'/397'/0'/0'/1'").unwrap(),
-            format: crate::common::OutputFormat::Json,
         }
     }
 }
 
 impl CliLedgerPublicKey {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        args.push_front(self.seed_phrase_hd_path.to_string());
+        args.push_front("--seed-phrase-hd-path".to_owned());
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
-        println!(
+        crate::common::print_message(&format!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
             self.seed_phrase_hd_path.to_string(),
-        );
+        ));
         let public_key = near_ledger::get_public_key(self.seed_phrase_hd_path.clone())
             .await
             .map_err(|near_ledger_error| {
@@ -36,7 +40,7 @@ impl CliLedgerPublicKey {
 
         let implicit_account_id = hex::encode(&public_key);
 
-        match self.format {
+        match crate::common::output_format() {
             crate::common::OutputFormat::Plaintext => {
                 println!(
                     "Seed Phrase HD Path: {}\nImplicit Account ID: {}\nPublic Key: {}",
@@ -48,18 +52,16 @@ impl CliLedgerPublicKey {
                 );
             }
             crate::common::OutputFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&serde_json::json!({
-                        "seed_phrase_hd_path": self.seed_phrase_hd_path.to_string(),
-                        "account_id": implicit_account_id,
-                        "public_key": near_crypto::PublicKey::ED25519(
-                            near_crypto::ED25519PublicKey::from(
-                                public_key.to_bytes(),
-                            )
-                        ),
-                    }))
-                    .unwrap()
+                crate::common::set_json_output(
+                    "seed_phrase_hd_path",
+                    self.seed_phrase_hd_path.to_string(),
+                );
+                crate::common::set_json_output("account_id", implicit_account_id);
+                crate::common::set_json_output(
+                    "public_key",
+                    near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+                        public_key.to_bytes(),
+                    )),
                 );
             }
         };
@@ -2,7 +2,9 @@ use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod combine_transaction_subcommand_with_signature;
+mod encrypt_keychain_subcommand;
 pub mod generate_keypair_subcommand;
+mod inspect_wasm_subcommand;
 mod ledger_publickey_subcommand;
 mod send_signed_transaction;
 mod sign_transaction_subcommand_with_secret_key;
@@ -26,17 +28,21 @@ pub struct Utils {
     pub util: Util,
 }
 
-impl From<CliUtils> for Utils {
-    fn from(item: CliUtils) -> Self {
+impl Utils {
+    pub fn from(item: CliUtils) -> color_eyre::eyre::Result<Self> {
         let util = match item.util {
-            Some(cli_util) => Util::from(cli_util),
-            None => Util::choose_util(),
+            Some(cli_util) => Util::from(cli_util)?,
+            None => Util::choose_util()?,
         };
-        Self { util }
+        Ok(Self { util })
     }
 }
 
 impl Utils {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.util.to_cli_args()
+    }
+
     pub async fn process(self) -> crate::CliResult {
         self.util.process().await
     }
@@ -64,6 +70,10 @@ enum CliUtil {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     /// Send signed transaction
     SendSignedTransaction(self::send_signed_transaction::operation_mode::CliOperationMode),
+    /// Encrypt the plaintext key files of the keychain with a passphrase
+    EncryptKeychain(self::encrypt_keychain_subcommand::CliEncryptKeychain),
+    /// Inspect a contract file before deploying it
+    InspectWasm(self::inspect_wasm_subcommand::CliInspectWasm),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -89,11 +99,15 @@ pub enum Util {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     #[strum_discriminants(strum(message = "Send signed transaction"))]
     SendSignedTransaction(self::send_signed_transaction::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Encrypt the keychain"))]
+    EncryptKeychain(self::encrypt_keychain_subcommand::CliEncryptKeychain),
+    #[strum_discriminants(strum(message = "Inspect a contract file"))]
+    InspectWasm(self::inspect_wasm_subcommand::InspectWasm),
 }
 
-impl From<CliUtil> for Util {
-    fn from(item: CliUtil) -> Self {
-        match item {
+impl Util {
+    fn from(item: CliUtil) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
             CliUtil::GenerateKeypair(generate_keypair) => Util::GenerateKeypair(generate_keypair),
             CliUtil::SignTransactionSecretKey(cli_sign_transaction) => {
                 let sign_transaction =
@@ -120,21 +134,26 @@ impl From<CliUtil> for Util {
                 Util::ViewSerializedTransaction(view_serialized_transaction)
             }
             CliUtil::LedgerPublicKey(ledger_publickey) => Util::LedgerPublicKey(ledger_publickey),
-            CliUtil::SendSignedTransaction(cli_operation_mode) => {
-                Util::SendSignedTransaction(cli_operation_mode.into())
-            }
-        }
+            CliUtil::SendSignedTransaction(cli_operation_mode) => Util::SendSignedTransaction(
+                self::send_signed_transaction::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+            CliUtil::EncryptKeychain(encrypt_keychain) => Util::EncryptKeychain(encrypt_keychain),
+            CliUtil::InspectWasm(cli_inspect_wasm) => Util::InspectWasm(cli_inspect_wasm.into()),
+        })
     }
 }
 
 impl Util {
-    fn choose_util() -> Self {
+    fn choose_util() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = UtilDiscriminants::iter().collect::<Vec<_>>();
         let utils = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("util subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&utils)
@@ -163,10 +182,62 @@ impl Util {
             UtilDiscriminants::SendSignedTransaction => {
                 CliUtil::SendSignedTransaction(Default::default())
             }
+            UtilDiscriminants::EncryptKeychain => CliUtil::EncryptKeychain(Default::default()),
+            UtilDiscriminants::InspectWasm => CliUtil::InspectWasm(Default::default()),
         };
         Self::from(cli_util)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::GenerateKeypair(generate_keypair) => {
+                let mut args = generate_keypair.to_cli_args();
+                args.push_front("generate-keypair".to_owned());
+                args
+            }
+            Self::SignTransactionSecretKey(sign_transaction_secret_key) => {
+                let mut args = sign_transaction_secret_key.to_cli_args();
+                args.push_front("sign-transaction-secret-key".to_owned());
+                args
+            }
+            Self::SignTransactionWithLedger(sign_transaction_with_ledger) => {
+                let mut args = sign_transaction_with_ledger.to_cli_args();
+                args.push_front("sign-transaction-with-ledger".to_owned());
+                args
+            }
+            Self::CombineTransactionSignature(combine_transaction_signature) => {
+                let mut args = combine_transaction_signature.to_cli_args();
+                args.push_front("combine-transaction-signature".to_owned());
+                args
+            }
+            Self::ViewSerializedTransaction(view_serialized_transaction) => {
+                let mut args = view_serialized_transaction.to_cli_args();
+                args.push_front("view-serialized-transaction".to_owned());
+                args
+            }
+            Self::LedgerPublicKey(ledger_public_key) => {
+                let mut args = ledger_public_key.to_cli_args();
+                args.push_front("ledger-public-key".to_owned());
+                args
+            }
+            Self::SendSignedTransaction(send_signed_transaction) => {
+                let mut args = send_signed_transaction.to_cli_args();
+                args.push_front("send-signed-transaction".to_owned());
+                args
+            }
+            Self::EncryptKeychain(encrypt_keychain) => {
+                let mut args = encrypt_keychain.to_cli_args();
+                args.push_front("encrypt-keychain".to_owned());
+                args
+            }
+            Self::InspectWasm(inspect_wasm) => {
+                let mut args = inspect_wasm.to_cli_args();
+                args.push_front("inspect-wasm".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(self) -> crate::CliResult {
         match self {
             Self::GenerateKeypair(generate_keypair) => generate_keypair.process().await,
@@ -180,6 +251,8 @@ impl Util {
             }
             Self::LedgerPublicKey(ledger_publickey) => ledger_publickey.process().await,
             Self::SendSignedTransaction(operation_mode) => operation_mode.process().await,
+            Self::EncryptKeychain(encrypt_keychain) => encrypt_keychain.process().await,
+            Self::InspectWasm(inspect_wasm) => inspect_wasm.process().await,
         }
     }
 }
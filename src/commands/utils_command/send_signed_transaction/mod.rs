@@ -1,4 +1,5 @@
 use dialoguer::Input;
+use near_primitives::borsh::BorshDeserialize;
 
 pub mod operation_mode;
 
@@ -24,43 +25,38 @@ impl From<CliTransaction> for Transaction {
 
 impl Transaction {
     fn input_transaction() -> String {
+        crate::common::ensure_interactive("<transaction>");
         Input::new()
             .with_prompt("Enter the signed transaction hash you want to send")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.transaction.to_string()])
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
-        println!("Transaction sent ...");
-        let json_rcp_client =
-            near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
-        let transaction_info = loop {
-            let transaction_info_result = json_rcp_client
-                .broadcast_tx_commit(self.transaction.clone())
-                .await;
-            match transaction_info_result {
-                Ok(response) => {
-                    break response;
-                }
-                Err(err) => {
-                    if let Some(serde_json::Value::String(data)) = &err.data {
-                        if data.contains("Timeout") {
-                            println!("Timeout error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
-                            continue;
-                        }
-                    }
-                    return Err(color_eyre::Report::msg(format!(
-                        "Error transaction: {:?}",
-                        err
-                    )));
-                }
-            };
-        };
+        let signed_transaction = near_primitives::serialize::from_base64(&self.transaction)
+            .ok()
+            .and_then(|bytes| {
+                near_primitives::transaction::SignedTransaction::try_from_slice(&bytes).ok()
+            })
+            .ok_or_else(|| {
+                crate::common::CliError::UserInput(
+                    "The signed transaction is not a valid base64-encoded transaction".to_string(),
+                )
+            })?;
+        let transaction_info = crate::submission::send_transaction(
+            &network_connection_config,
+            signed_transaction,
+            None,
+        )
+        .await?;
         crate::common::print_transaction_status(transaction_info, Some(network_connection_config))
-            .await;
-        Ok(())
+            .await
     }
 }
@@ -12,6 +12,15 @@ pub struct CliServer {
 pub struct CliCustomServer {
     #[clap(long)]
     pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
     #[clap(subcommand)]
     send: Option<CliSend>,
 }
@@ -39,23 +48,37 @@ impl CliCustomServer {
     pub fn into_server(self) -> Server {
         let url: crate::common::AvailableRpcServerUrl = match self.url {
             Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the RPC endpoint?")
-                .interact_text()
-                .unwrap(),
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
         };
         let send = match self.send {
             Some(cli_send) => Send::from(cli_send),
             None => Send::send(),
         };
         Server {
-            connection_config: crate::common::ConnectionConfig::Custom { url: url.inner },
+            connection_config: crate::common::ConnectionConfig::custom(
+                url.inner,
+                self.archival_url,
+                self.wallet_url,
+                self.explorer_url,
+            ),
             send,
         }
     }
 }
 
 impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.connection_config.to_cli_args();
+        args.extend(self.send.to_cli_args());
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
         self.send.process(self.connection_config).await
     }
@@ -88,6 +111,16 @@ impl Send {
         Self::from(CliSend::Transaction(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Transaction(transaction) => {
+                let mut args = transaction.to_cli_args();
+                args.push_front("transaction".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -36,6 +36,7 @@ impl From<CliSignTransactionSecretKey> for SignTransactionSecretKey {
 
 impl SignTransactionSecretKey {
     pub fn input_signer_secret_key() -> near_crypto::SecretKey {
+        crate::common::ensure_interactive("--signer-secret-key");
         Input::new()
             .with_prompt("Enter the secret key")
             .interact_text()
@@ -43,6 +44,7 @@ impl SignTransactionSecretKey {
     }
 
     pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+        crate::common::ensure_interactive("--unsigned-transaction");
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
             .interact_text()
@@ -50,6 +52,20 @@ impl SignTransactionSecretKey {
         input.inner
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        args.push_front(
+            crate::common::TransactionAsBase64 {
+                inner: self.unsigned_transaction.clone(),
+            }
+            .to_cli_arg(),
+        );
+        args.push_front("--unsigned-transaction".to_owned());
+        args.push_front(crate::common::SECRET_PLACEHOLDER.to_owned());
+        args.push_front("--signer-secret-key".to_owned());
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let signature = self
             .signer_secret_key
@@ -66,14 +82,18 @@ impl SignTransactionSecretKey {
                 ))
             })?,
         );
-        println!("\n\nThe transaction has been successfully signed.");
-        println!("Signed transaction:");
-        crate::common::print_transaction(signed_transaction.transaction.clone());
-        println!("{:<13} {}", "signature:", signed_transaction.signature);
-        println!(
-            "Base64-encoded signed transaction:\n{}",
-            serialize_to_base64
-        );
+        crate::common::print_message("\n\nThe transaction has been successfully signed.");
+        crate::common::print_message("Signed transaction:");
+        crate::common::print_signed_transaction(signed_transaction.clone());
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => println!(
+                "Base64-encoded signed transaction:\n{}",
+                serialize_to_base64
+            ),
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("signed_transaction_base64", serialize_to_base64)
+            }
+        }
         Ok(())
     }
 }
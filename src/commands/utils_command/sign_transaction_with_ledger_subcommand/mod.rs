@@ -36,6 +36,7 @@ impl From<CliSignTransactionWithLedger> for SignTransactionWithLedger {
 
 impl SignTransactionWithLedger {
     pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+        crate::common::ensure_interactive("--unsigned-transaction");
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
             .interact_text()
@@ -44,6 +45,7 @@ impl SignTransactionWithLedger {
     }
 
     pub fn input_seed_phrase_hd_path() -> slip10::BIP32Path {
+        crate::common::ensure_interactive("--seed-phrase-hd-path");
         Input::new()
             .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
             .with_initial_text("44'/397'/0'/0'/1'")
@@ -51,13 +53,27 @@ impl SignTransactionWithLedger {
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        args.push_front(
+            crate::common::TransactionAsBase64 {
+                inner: self.unsigned_transaction.clone(),
+            }
+            .to_cli_arg(),
+        );
+        args.push_front("--unsigned-transaction".to_owned());
+        args.push_front(self.seed_phrase_hd_path.to_string());
+        args.push_front("--seed-phrase-hd-path".to_owned());
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
-        println!("\nGoing to sign transaction:");
+        crate::common::print_message("\nGoing to sign transaction:");
         crate::common::print_transaction(self.unsigned_transaction.clone());
-        println!(
+        crate::common::print_message(&format!(
             "Please confirm transaction signing on Ledger Device (HD Path {})",
             self.seed_phrase_hd_path.to_string()
-        );
+        ));
         let signature = match near_ledger::sign_transaction(
             self.unsigned_transaction
                 .try_to_vec()
@@ -83,19 +99,23 @@ impl SignTransactionWithLedger {
             self.unsigned_transaction,
         );
 
-        println!("\nSigned transaction:\n");
-        crate::common::print_transaction(signed_transaction.transaction.clone());
-        println!("{:<13} {}", "signature:", signed_transaction.signature);
+        crate::common::print_message("\nSigned transaction:\n");
+        crate::common::print_signed_transaction(signed_transaction.clone());
 
         let serialize_to_base64 = near_primitives::serialize::to_base64(
             signed_transaction
                 .try_to_vec()
                 .expect("Signed transaction is not expected to fail on serialization"),
         );
-        println!(
-            "Base64-encoded signed transaction:\n{}",
-            serialize_to_base64
-        );
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => println!(
+                "Base64-encoded signed transaction:\n{}",
+                serialize_to_base64
+            ),
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("signed_transaction_base64", serialize_to_base64)
+            }
+        }
         Ok(())
     }
 }
@@ -24,30 +24,43 @@ impl From<CliViewSerializedTransaction> for ViewSerializedTransaction {
 
 impl ViewSerializedTransaction {
     fn input_transaction() -> String {
+        crate::common::ensure_interactive("<transaction>");
         Input::new()
             .with_prompt("Enter the hash of the transaction")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.transaction.to_string()])
+    }
+
     pub async fn process(self) -> crate::CliResult {
-        let serialize_from_base64 =
-            near_primitives::serialize::from_base64(&self.transaction).unwrap();
+        let serialize_from_base64 = near_primitives::serialize::from_base64(&self.transaction)
+            .map_err(|err| {
+                crate::common::CliError::UserInput(format!(
+                    "Base64 transaction sequence is invalid: {}",
+                    err
+                ))
+            })?;
         match near_primitives::transaction::Transaction::try_from_slice(&serialize_from_base64) {
-            Ok(transaction) => println!("\n{:#?}", &transaction),
+            Ok(transaction) => match crate::common::output_format() {
+                crate::common::OutputFormat::Plaintext => println!("\n{:#?}", &transaction),
+                crate::common::OutputFormat::Json => crate::common::print_transaction(transaction),
+            },
             Err(_) => {
-                match near_primitives::transaction::SignedTransaction::try_from_slice(
-                    &serialize_from_base64,
-                ) {
-                    Ok(signed_transaction) => {
-                        println!("\nSigned transaction:\n");
-                        crate::common::print_transaction(signed_transaction.transaction.clone());
-                        println!("{:<13} {}", "signature:", signed_transaction.signature)
-                    }
-                    Err(err) => {
-                        println!("\nError: Base64 transaction sequence is invalid: {}", err)
-                    }
-                }
+                let signed_transaction =
+                    near_primitives::transaction::SignedTransaction::try_from_slice(
+                        &serialize_from_base64,
+                    )
+                    .map_err(|err| {
+                        crate::common::CliError::UserInput(format!(
+                            "Base64 transaction sequence is invalid: {}",
+                            err
+                        ))
+                    })?;
+                crate::common::print_message("\nSigned transaction:\n");
+                crate::common::print_signed_transaction(signed_transaction)
             }
         };
         Ok(())
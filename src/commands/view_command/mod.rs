@@ -2,11 +2,24 @@ use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod view_account;
+mod view_block;
+mod view_chunk;
 mod view_contract_code;
 mod view_contract_state;
+mod view_contract_state_diff;
+mod view_delegation;
+mod view_epoch;
+mod view_ft_balance;
+mod view_gas_price;
+mod view_network_status;
+mod view_nft_token;
+mod view_nft_tokens_for_owner;
 mod view_nonce;
+mod view_protocol_config;
 mod view_recent_block_hash;
+mod view_stake_proposals;
 mod view_transaction_status;
+mod view_validators;
 
 /// инструмент выбора to view
 #[derive(Debug, Default, clap::Clap)]
@@ -25,17 +38,21 @@ pub struct ViewQueryRequest {
     pub query: QueryRequest,
 }
 
-impl From<CliViewQueryRequest> for ViewQueryRequest {
-    fn from(item: CliViewQueryRequest) -> Self {
+impl ViewQueryRequest {
+    pub fn from(item: CliViewQueryRequest) -> color_eyre::eyre::Result<Self> {
         let query = match item.query {
-            Some(cli_query_request) => QueryRequest::from(cli_query_request),
-            None => QueryRequest::choose_query_request(),
+            Some(cli_query_request) => QueryRequest::from(cli_query_request)?,
+            None => QueryRequest::choose_query_request()?,
         };
-        ViewQueryRequest { query }
+        Ok(ViewQueryRequest { query })
     }
 }
 
 impl ViewQueryRequest {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.query.to_cli_args()
+    }
+
     pub async fn process(self) -> crate::CliResult {
         self.query.process().await
     }
@@ -45,16 +62,42 @@ impl ViewQueryRequest {
 pub enum CliQueryRequest {
     /// View properties for an account
     AccountSummary(self::view_account::operation_mode::CliOperationMode),
+    /// View a block: its header fields and chunks
+    Block(self::view_block::operation_mode::CliOperationMode),
+    /// View a chunk: its transactions and receipts
+    Chunk(self::view_chunk::operation_mode::CliOperationMode),
     /// View a contract code
     ContractCode(self::view_contract_code::operation_mode::CliOperationMode),
     /// View a contract state
     ContractState(self::view_contract_state::operation_mode::CliOperationMode),
+    /// View the changes of a contract state between two blocks
+    ContractStateDiff(self::view_contract_state_diff::operation_mode::CliOperationMode),
+    /// View the staked and unstaked balances of an account with a staking pool
+    Delegation(self::view_delegation::operation_mode::CliOperationMode),
+    /// View the epoch height, start block and seat price
+    Epoch(self::view_epoch::operation_mode::CliOperationMode),
+    /// View the balance of a fungible token (NEP-141)
+    FtBalance(self::view_ft_balance::operation_mode::CliOperationMode),
+    /// View the gas price
+    GasPrice(self::view_gas_price::operation_mode::CliOperationMode),
+    /// View the version, chain ID, latest block and sync state of the server
+    NetworkStatus(self::view_network_status::operation_mode::CliOperationMode),
+    /// View a non-fungible token (NEP-171)
+    NftToken(self::view_nft_token::operation_mode::CliOperationMode),
+    /// View the non-fungible tokens (NEP-171) of an account
+    NftTokensForOwner(self::view_nft_tokens_for_owner::operation_mode::CliOperationMode),
     /// View a transaction status
     Transaction(self::view_transaction_status::operation_mode::CliOperationMode),
     /// View a nonce for a public key
     Nonce(self::view_nonce::operation_mode::CliOperationMode),
+    /// View the storage cost, gas limits and other limits of the protocol
+    ProtocolConfig(self::view_protocol_config::operation_mode::CliOperationMode),
     /// View recent block hash for this network
     RecentBlockHash(self::view_recent_block_hash::operation_mode::CliOperationMode),
+    /// View the stake proposals of the epoch
+    StakeProposals(self::view_stake_proposals::operation_mode::CliOperationMode),
+    /// View the current and next validators of the epoch
+    Validators(self::view_validators::operation_mode::CliOperationMode),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -62,51 +105,151 @@ pub enum CliQueryRequest {
 pub enum QueryRequest {
     #[strum_discriminants(strum(message = "View properties for an account"))]
     AccountSummary(self::view_account::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View a block: its header fields and chunks"))]
+    Block(self::view_block::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View a chunk: its transactions and receipts"))]
+    Chunk(self::view_chunk::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a contract code"))]
     ContractCode(self::view_contract_code::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a contract state"))]
     ContractState(self::view_contract_state::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "View the changes of a contract state between two blocks"
+    ))]
+    ContractStateDiff(self::view_contract_state_diff::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "View the staked and unstaked balances of an account with a staking pool"
+    ))]
+    Delegation(self::view_delegation::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the epoch height, start block and seat price"))]
+    Epoch(self::view_epoch::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the balance of a fungible token (NEP-141)"))]
+    FtBalance(self::view_ft_balance::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the gas price"))]
+    GasPrice(self::view_gas_price::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "View the version, chain ID, latest block and sync state of the server"
+    ))]
+    NetworkStatus(self::view_network_status::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View a non-fungible token (NEP-171)"))]
+    NftToken(self::view_nft_token::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "View the non-fungible tokens (NEP-171) of an account"
+    ))]
+    NftTokensForOwner(self::view_nft_tokens_for_owner::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a transaction status"))]
     Transaction(self::view_transaction_status::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a nonce for a public key"))]
     Nonce(self::view_nonce::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "View the storage cost, gas limits and other limits of the protocol"
+    ))]
+    ProtocolConfig(self::view_protocol_config::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View recent block hash for this network"))]
     RecentBlockHash(self::view_recent_block_hash::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the stake proposals of the epoch"))]
+    StakeProposals(self::view_stake_proposals::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the current and next validators of the epoch"))]
+    Validators(self::view_validators::operation_mode::OperationMode),
 }
 
-impl From<CliQueryRequest> for QueryRequest {
-    fn from(item: CliQueryRequest) -> Self {
+impl QueryRequest {
+    fn from(item: CliQueryRequest) -> color_eyre::eyre::Result<Self> {
         match item {
             CliQueryRequest::AccountSummary(cli_operation_mode) => {
-                QueryRequest::AccountSummary(cli_operation_mode.into())
-            }
-            CliQueryRequest::ContractCode(cli_operation_mode) => {
-                QueryRequest::ContractCode(cli_operation_mode.into())
+                Ok(QueryRequest::AccountSummary(
+                    self::view_account::operation_mode::OperationMode::from(cli_operation_mode)?,
+                ))
             }
-            CliQueryRequest::ContractState(cli_operation_mode) => {
-                QueryRequest::ContractState(cli_operation_mode.into())
+            CliQueryRequest::Block(cli_operation_mode) => Ok(QueryRequest::Block(
+                self::view_block::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::Chunk(cli_operation_mode) => Ok(QueryRequest::Chunk(
+                self::view_chunk::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::ContractCode(cli_operation_mode) => Ok(QueryRequest::ContractCode(
+                self::view_contract_code::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::ContractState(cli_operation_mode) => Ok(QueryRequest::ContractState(
+                self::view_contract_state::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::ContractStateDiff(cli_operation_mode) => {
+                Ok(QueryRequest::ContractStateDiff(
+                    self::view_contract_state_diff::operation_mode::OperationMode::from(
+                        cli_operation_mode,
+                    )?,
+                ))
             }
-            CliQueryRequest::Transaction(cli_operation_mode) => {
-                QueryRequest::Transaction(cli_operation_mode.into())
+            CliQueryRequest::Delegation(cli_operation_mode) => Ok(QueryRequest::Delegation(
+                self::view_delegation::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::Epoch(cli_operation_mode) => Ok(QueryRequest::Epoch(
+                self::view_epoch::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::FtBalance(cli_operation_mode) => Ok(QueryRequest::FtBalance(
+                self::view_ft_balance::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::GasPrice(cli_operation_mode) => Ok(QueryRequest::GasPrice(
+                self::view_gas_price::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::NetworkStatus(cli_operation_mode) => Ok(QueryRequest::NetworkStatus(
+                self::view_network_status::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::NftToken(cli_operation_mode) => Ok(QueryRequest::NftToken(
+                self::view_nft_token::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::NftTokensForOwner(cli_operation_mode) => {
+                Ok(QueryRequest::NftTokensForOwner(
+                    self::view_nft_tokens_for_owner::operation_mode::OperationMode::from(
+                        cli_operation_mode,
+                    )?,
+                ))
             }
-            CliQueryRequest::Nonce(cli_operation_mode) => {
-                QueryRequest::Nonce(cli_operation_mode.into())
+            CliQueryRequest::Transaction(cli_operation_mode) => Ok(QueryRequest::Transaction(
+                self::view_transaction_status::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            )),
+            CliQueryRequest::Nonce(cli_operation_mode) => Ok(QueryRequest::Nonce(
+                self::view_nonce::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::ProtocolConfig(cli_operation_mode) => {
+                Ok(QueryRequest::ProtocolConfig(
+                    self::view_protocol_config::operation_mode::OperationMode::from(
+                        cli_operation_mode,
+                    )?,
+                ))
             }
             CliQueryRequest::RecentBlockHash(cli_operation_mode) => {
-                QueryRequest::RecentBlockHash(cli_operation_mode.into())
+                Ok(QueryRequest::RecentBlockHash(
+                    self::view_recent_block_hash::operation_mode::OperationMode::from(
+                        cli_operation_mode,
+                    )?,
+                ))
             }
+            CliQueryRequest::StakeProposals(cli_operation_mode) => {
+                Ok(QueryRequest::StakeProposals(
+                    self::view_stake_proposals::operation_mode::OperationMode::from(
+                        cli_operation_mode,
+                    )?,
+                ))
+            }
+            CliQueryRequest::Validators(cli_operation_mode) => Ok(QueryRequest::Validators(
+                self::view_validators::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
         }
     }
 }
 
 impl QueryRequest {
-    fn choose_query_request() -> Self {
+    fn choose_query_request() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = QueryRequestDiscriminants::iter().collect::<Vec<_>>();
         let requests = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("query-request subcommand");
         let selected_request = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to view")
             .items(&requests)
@@ -117,31 +260,171 @@ impl QueryRequest {
             QueryRequestDiscriminants::AccountSummary => {
                 CliQueryRequest::AccountSummary(Default::default())
             }
+            QueryRequestDiscriminants::Block => CliQueryRequest::Block(Default::default()),
+            QueryRequestDiscriminants::Chunk => CliQueryRequest::Chunk(Default::default()),
             QueryRequestDiscriminants::ContractCode => {
                 CliQueryRequest::ContractCode(Default::default())
             }
             QueryRequestDiscriminants::ContractState => {
                 CliQueryRequest::ContractState(Default::default())
             }
+            QueryRequestDiscriminants::ContractStateDiff => {
+                CliQueryRequest::ContractStateDiff(Default::default())
+            }
+            QueryRequestDiscriminants::Delegation => {
+                CliQueryRequest::Delegation(Default::default())
+            }
+            QueryRequestDiscriminants::Epoch => CliQueryRequest::Epoch(Default::default()),
+            QueryRequestDiscriminants::FtBalance => CliQueryRequest::FtBalance(Default::default()),
+            QueryRequestDiscriminants::GasPrice => CliQueryRequest::GasPrice(Default::default()),
+            QueryRequestDiscriminants::NetworkStatus => {
+                CliQueryRequest::NetworkStatus(Default::default())
+            }
+            QueryRequestDiscriminants::NftToken => CliQueryRequest::NftToken(Default::default()),
+            QueryRequestDiscriminants::NftTokensForOwner => {
+                CliQueryRequest::NftTokensForOwner(Default::default())
+            }
             QueryRequestDiscriminants::Transaction => {
                 CliQueryRequest::Transaction(Default::default())
             }
             QueryRequestDiscriminants::Nonce => CliQueryRequest::Nonce(Default::default()),
+            QueryRequestDiscriminants::ProtocolConfig => {
+                CliQueryRequest::ProtocolConfig(Default::default())
+            }
             QueryRequestDiscriminants::RecentBlockHash => {
                 CliQueryRequest::RecentBlockHash(Default::default())
             }
+            QueryRequestDiscriminants::StakeProposals => {
+                CliQueryRequest::StakeProposals(Default::default())
+            }
+            QueryRequestDiscriminants::Validators => {
+                CliQueryRequest::Validators(Default::default())
+            }
         };
         Self::from(cli_request)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AccountSummary(account_summary) => {
+                let mut args = account_summary.to_cli_args();
+                args.push_front("account-summary".to_owned());
+                args
+            }
+            Self::Block(block) => {
+                let mut args = block.to_cli_args();
+                args.push_front("block".to_owned());
+                args
+            }
+            Self::Chunk(chunk) => {
+                let mut args = chunk.to_cli_args();
+                args.push_front("chunk".to_owned());
+                args
+            }
+            Self::ContractCode(contract_code) => {
+                let mut args = contract_code.to_cli_args();
+                args.push_front("contract-code".to_owned());
+                args
+            }
+            Self::ContractState(contract_state) => {
+                let mut args = contract_state.to_cli_args();
+                args.push_front("contract-state".to_owned());
+                args
+            }
+            Self::ContractStateDiff(contract_state_diff) => {
+                let mut args = contract_state_diff.to_cli_args();
+                args.push_front("contract-state-diff".to_owned());
+                args
+            }
+            Self::Delegation(delegation) => {
+                let mut args = delegation.to_cli_args();
+                args.push_front("delegation".to_owned());
+                args
+            }
+            Self::Epoch(epoch) => {
+                let mut args = epoch.to_cli_args();
+                args.push_front("epoch".to_owned());
+                args
+            }
+            Self::FtBalance(ft_balance) => {
+                let mut args = ft_balance.to_cli_args();
+                args.push_front("ft-balance".to_owned());
+                args
+            }
+            Self::GasPrice(gas_price) => {
+                let mut args = gas_price.to_cli_args();
+                args.push_front("gas-price".to_owned());
+                args
+            }
+            Self::NetworkStatus(network_status) => {
+                let mut args = network_status.to_cli_args();
+                args.push_front("network-status".to_owned());
+                args
+            }
+            Self::NftToken(nft_token) => {
+                let mut args = nft_token.to_cli_args();
+                args.push_front("nft-token".to_owned());
+                args
+            }
+            Self::NftTokensForOwner(nft_tokens_for_owner) => {
+                let mut args = nft_tokens_for_owner.to_cli_args();
+                args.push_front("nft-tokens-for-owner".to_owned());
+                args
+            }
+            Self::Transaction(transaction) => {
+                let mut args = transaction.to_cli_args();
+                args.push_front("transaction".to_owned());
+                args
+            }
+            Self::Nonce(nonce) => {
+                let mut args = nonce.to_cli_args();
+                args.push_front("nonce".to_owned());
+                args
+            }
+            Self::ProtocolConfig(protocol_config) => {
+                let mut args = protocol_config.to_cli_args();
+                args.push_front("protocol-config".to_owned());
+                args
+            }
+            Self::RecentBlockHash(recent_block_hash) => {
+                let mut args = recent_block_hash.to_cli_args();
+                args.push_front("recent-block-hash".to_owned());
+                args
+            }
+            Self::StakeProposals(stake_proposals) => {
+                let mut args = stake_proposals.to_cli_args();
+                args.push_front("stake-proposals".to_owned());
+                args
+            }
+            Self::Validators(validators) => {
+                let mut args = validators.to_cli_args();
+                args.push_front("validators".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(self) -> crate::CliResult {
         match self {
             QueryRequest::AccountSummary(operation_mode) => operation_mode.process().await,
+            QueryRequest::Block(operation_mode) => operation_mode.process().await,
+            QueryRequest::Chunk(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractCode(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractState(operation_mode) => operation_mode.process().await,
+            QueryRequest::ContractStateDiff(operation_mode) => operation_mode.process().await,
+            QueryRequest::Delegation(operation_mode) => operation_mode.process().await,
+            QueryRequest::Epoch(operation_mode) => operation_mode.process().await,
+            QueryRequest::FtBalance(operation_mode) => operation_mode.process().await,
+            QueryRequest::GasPrice(operation_mode) => operation_mode.process().await,
+            QueryRequest::NetworkStatus(operation_mode) => operation_mode.process().await,
+            QueryRequest::NftToken(operation_mode) => operation_mode.process().await,
+            QueryRequest::NftTokensForOwner(operation_mode) => operation_mode.process().await,
             QueryRequest::Transaction(operation_mode) => operation_mode.process().await,
             QueryRequest::Nonce(operation_mode) => operation_mode.process().await,
+            QueryRequest::ProtocolConfig(operation_mode) => operation_mode.process().await,
             QueryRequest::RecentBlockHash(operation_mode) => operation_mode.process().await,
+            QueryRequest::StakeProposals(operation_mode) => operation_mode.process().await,
+            QueryRequest::Validators(operation_mode) => operation_mode.process().await,
         }
     }
 }
@@ -23,6 +23,7 @@ impl From<CliBlockIdHeight> for BlockIdHeight {
 
 impl BlockIdHeight {
     pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
+        crate::common::ensure_interactive("<block-id-height>");
         Input::new()
             .with_prompt("Type the block ID height for this account")
             .interact_text()
@@ -33,6 +34,10 @@ impl BlockIdHeight {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.block_id_height.to_string()])
+    }
+
     pub async fn process(
         self,
         account_id: String,
@@ -62,7 +67,7 @@ impl BlockIdHeight {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view account: {:?}",
                     err
                 ))
@@ -76,6 +81,13 @@ impl BlockIdHeight {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
 
+        if let crate::common::OutputFormat::Json = crate::common::output_format() {
+            crate::common::set_json_output("account_id", &account_id);
+            crate::common::set_json_output("block_height", query_view_method_response.block_height);
+            crate::common::set_json_output("block_hash", query_view_method_response.block_hash);
+            crate::common::set_json_output("account", account_view);
+            return Ok(());
+        }
         println!(
             "Account details for '{}' at block #{} ({})\n\
             Native account balance: {}\n\
@@ -116,7 +128,7 @@ impl BlockIdHeight {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view key list: {:?}",
                     err
                 ))
@@ -130,6 +142,10 @@ impl BlockIdHeight {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
 
+        if let crate::common::OutputFormat::Json = crate::common::output_format() {
+            crate::common::set_json_output("access_keys", access_key_view.keys);
+            return Ok(());
+        }
         println!("Number of access keys: {}", access_key_view.keys.len());
         for (index, access_key) in access_key_view.keys.iter().enumerate() {
             let permissions_message = match &access_key.access_key.permission {
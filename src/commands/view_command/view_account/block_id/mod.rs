@@ -47,6 +47,7 @@ impl BlockId {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&blocks)
@@ -61,6 +62,24 @@ impl BlockId {
         Self::from(cli_block_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AtFinalBlock => {
+                std::collections::VecDeque::from(vec!["at-final-block".to_owned()])
+            }
+            Self::AtBlockHeight(at_block_height) => {
+                let mut args = at_block_height.to_cli_args();
+                args.push_front("at-block-height".to_owned());
+                args
+            }
+            Self::AtBlockHash(at_block_hash) => {
+                let mut args = at_block_hash.to_cli_args();
+                args.push_front("at-block-hash".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         sender_account_id: String,
@@ -107,7 +126,7 @@ impl BlockId {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view account: {:?}",
                     err
                 ))
@@ -121,6 +140,13 @@ impl BlockId {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
 
+        if let crate::common::OutputFormat::Json = crate::common::output_format() {
+            crate::common::set_json_output("account_id", &account_id);
+            crate::common::set_json_output("block_height", query_view_method_response.block_height);
+            crate::common::set_json_output("block_hash", query_view_method_response.block_hash);
+            crate::common::set_json_output("account", account_view);
+            return Ok(());
+        }
         println!(
             "Account details for '{}' at block #{} ({})\n\
             Native account balance: {}\n\
@@ -159,7 +185,7 @@ impl BlockId {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view key list: {:?}",
                     err
                 ))
@@ -173,6 +199,10 @@ impl BlockId {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
 
+        if let crate::common::OutputFormat::Json = crate::common::output_format() {
+            crate::common::set_json_output("access_keys", access_key_view.keys);
+            return Ok(());
+        }
         println!("Number of access keys: {}", access_key_view.keys.len());
         for (index, access_key) in access_key_view.keys.iter().enumerate() {
             let permissions_message = match &access_key.access_key.permission {
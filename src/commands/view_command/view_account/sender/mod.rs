@@ -27,6 +27,16 @@ impl SendTo {
         Self::from(CliSendTo::Account(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -76,12 +86,19 @@ impl From<CliSender> for Sender {
 impl Sender {
     pub fn input_sender_account_id() -> String {
         println!();
+        crate::common::ensure_interactive("<sender-account-id>");
         Input::new()
             .with_prompt("What Account ID do you need to view?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_block_id.to_cli_args();
+        args.push_front(self.sender_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
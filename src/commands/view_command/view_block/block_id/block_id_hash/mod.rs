@@ -0,0 +1,40 @@
+use dialoguer::Input;
+
+/// Specify the block_id hash to view the block
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliBlockIdHash {
+    block_id_hash: Option<near_primitives::hash::CryptoHash>,
+}
+
+#[derive(Debug)]
+pub struct BlockIdHash {
+    block_id_hash: near_primitives::hash::CryptoHash,
+}
+
+impl From<CliBlockIdHash> for BlockIdHash {
+    fn from(item: CliBlockIdHash) -> Self {
+        let block_id_hash: near_primitives::hash::CryptoHash = match item.block_id_hash {
+            Some(cli_block_id_hash) => cli_block_id_hash,
+            None => BlockIdHash::input_block_id_hash(),
+        };
+        Self { block_id_hash }
+    }
+}
+
+impl BlockIdHash {
+    pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
+        crate::common::ensure_interactive("<block-id-hash>");
+        Input::new()
+            .with_prompt("Type the block ID hash")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn block_id(&self) -> near_primitives::types::BlockId {
+        near_primitives::types::BlockId::Hash(self.block_id_hash)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.block_id_hash.to_string()])
+    }
+}
@@ -0,0 +1,95 @@
+use dialoguer::Input;
+
+/// предустановленный RPC-сервер
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliServer {
+    #[clap(subcommand)]
+    pub block_id: Option<super::super::super::super::block_id::CliBlockId>,
+}
+
+/// данные для custom server
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliCustomServer {
+    #[clap(long)]
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
+    #[clap(subcommand)]
+    block_id: Option<super::super::super::super::block_id::CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct Server {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub block_id: super::super::super::super::block_id::BlockId,
+}
+
+impl CliServer {
+    pub fn into_server(self, connection_config: crate::common::ConnectionConfig) -> Server {
+        let block_id = match self.block_id {
+            Some(cli_block_id) => super::super::super::super::block_id::BlockId::from(cli_block_id),
+            None => super::super::super::super::block_id::BlockId::choose_block_id(),
+        };
+        Server {
+            connection_config,
+            block_id,
+        }
+    }
+}
+
+impl CliCustomServer {
+    pub fn into_server(self) -> Server {
+        let url: crate::common::AvailableRpcServerUrl = match self.url {
+            Some(url) => url,
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
+        };
+        let block_id = match self.block_id {
+            Some(cli_block_id) => super::super::super::super::block_id::BlockId::from(cli_block_id),
+            None => super::super::super::super::block_id::BlockId::choose_block_id(),
+        };
+        Server {
+            connection_config: crate::common::ConnectionConfig::custom(
+                url.inner,
+                self.archival_url,
+                self.wallet_url,
+                self.explorer_url,
+            ),
+            block_id,
+        }
+    }
+}
+
+impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.connection_config.to_cli_args();
+        args.extend(self.block_id.to_cli_args());
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.block_id.process(self.connection_config).await
+    }
+}
@@ -0,0 +1,119 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod block_id_hash;
+mod block_id_height;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliBlockId {
+    /// Specify a block ID final to view the chunk
+    AtFinalBlock,
+    /// Specify a block ID height to view the chunk
+    AtBlockHeight(self::block_id_height::CliBlockIdHeight),
+    /// Specify a block ID hash to view the chunk
+    AtBlockHash(self::block_id_hash::CliBlockIdHash),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum BlockId {
+    #[strum_discriminants(strum(message = "View the chunk at final block"))]
+    AtFinalBlock,
+    #[strum_discriminants(strum(message = "View the chunk at block height"))]
+    AtBlockHeight(self::block_id_height::BlockIdHeight),
+    #[strum_discriminants(strum(message = "View the chunk at block hash"))]
+    AtBlockHash(self::block_id_hash::BlockIdHash),
+}
+
+impl From<CliBlockId> for BlockId {
+    fn from(item: CliBlockId) -> Self {
+        match item {
+            CliBlockId::AtFinalBlock => Self::AtFinalBlock,
+            CliBlockId::AtBlockHeight(cli_block_id_height) => {
+                Self::AtBlockHeight(cli_block_id_height.into())
+            }
+            CliBlockId::AtBlockHash(cli_block_id_hash) => {
+                Self::AtBlockHash(cli_block_id_hash.into())
+            }
+        }
+    }
+}
+
+impl BlockId {
+    pub fn choose_block_id() -> Self {
+        println!();
+        let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
+        let blocks = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id subcommand");
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&blocks)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_block_id = match variants[selection] {
+            BlockIdDiscriminants::AtFinalBlock => CliBlockId::AtFinalBlock,
+            BlockIdDiscriminants::AtBlockHeight => CliBlockId::AtBlockHeight(Default::default()),
+            BlockIdDiscriminants::AtBlockHash => CliBlockId::AtBlockHash(Default::default()),
+        };
+        Self::from(cli_block_id)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AtFinalBlock => {
+                std::collections::VecDeque::from(vec!["at-final-block".to_owned()])
+            }
+            Self::AtBlockHeight(at_block_height) => {
+                let mut args = at_block_height.to_cli_args();
+                args.push_front("at-block-height".to_owned());
+                args
+            }
+            Self::AtBlockHash(at_block_hash) => {
+                let mut args = at_block_hash.to_cli_args();
+                args.push_front("at-block-hash".to_owned());
+                args
+            }
+        }
+    }
+
+    pub fn block_reference(&self) -> near_primitives::types::BlockReference {
+        match self {
+            Self::AtFinalBlock => near_primitives::types::Finality::Final.into(),
+            Self::AtBlockHeight(block_id_height) => {
+                near_primitives::types::BlockReference::BlockId(block_id_height.block_id())
+            }
+            Self::AtBlockHash(block_id_hash) => {
+                near_primitives::types::BlockReference::BlockId(block_id_hash.block_id())
+            }
+        }
+    }
+
+    /// Past blocks are only kept by the archival nodes
+    pub fn rpc_url(&self, network_connection_config: &crate::common::ConnectionConfig) -> url::Url {
+        match self {
+            Self::AtFinalBlock => network_connection_config.rpc_url(),
+            Self::AtBlockHeight(_) | Self::AtBlockHash(_) => {
+                network_connection_config.archival_rpc_url()
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        shard_id: near_primitives::types::ShardId,
+    ) -> crate::CliResult {
+        let rpc_url = self.rpc_url(&network_connection_config);
+        let block = crate::blocks::block(&rpc_url, self.block_reference()).await?;
+        let chunk = crate::blocks::chunk(&rpc_url, &block, shard_id).await?;
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => crate::blocks::print_chunk(&chunk),
+            crate::common::OutputFormat::Json => crate::common::set_json_output("chunk", &chunk),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod block_id;
+pub mod operation_mode;
+mod shard;
@@ -0,0 +1,95 @@
+use dialoguer::Input;
+
+/// предустановленный RPC-сервер
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliServer {
+    #[clap(subcommand)]
+    pub send_to: Option<super::super::super::super::shard::CliSendTo>,
+}
+
+/// данные для custom server
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliCustomServer {
+    #[clap(long)]
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
+    #[clap(subcommand)]
+    send_to: Option<super::super::super::super::shard::CliSendTo>,
+}
+
+#[derive(Debug)]
+pub struct Server {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub send_to: super::super::super::super::shard::SendTo,
+}
+
+impl CliServer {
+    pub fn into_server(self, connection_config: crate::common::ConnectionConfig) -> Server {
+        let send_to = match self.send_to {
+            Some(cli_send_to) => super::super::super::super::shard::SendTo::from(cli_send_to),
+            None => super::super::super::super::shard::SendTo::send_to(),
+        };
+        Server {
+            connection_config,
+            send_to,
+        }
+    }
+}
+
+impl CliCustomServer {
+    pub fn into_server(self) -> Server {
+        let url: crate::common::AvailableRpcServerUrl = match self.url {
+            Some(url) => url,
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
+        };
+        let send_to = match self.send_to {
+            Some(cli_send_to) => super::super::super::super::shard::SendTo::from(cli_send_to),
+            None => super::super::super::super::shard::SendTo::send_to(),
+        };
+        Server {
+            connection_config: crate::common::ConnectionConfig::custom(
+                url.inner,
+                self.archival_url,
+                self.wallet_url,
+                self.explorer_url,
+            ),
+            send_to,
+        }
+    }
+}
+
+impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.connection_config.to_cli_args();
+        args.extend(self.send_to.to_cli_args());
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.send_to.process(self.connection_config).await
+    }
+}
@@ -0,0 +1,108 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a shard
+    Shard(CliShard),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Shard(Shard),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Shard(cli_shard) => {
+                let shard = Shard::from(cli_shard);
+                Self::Shard(shard)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Shard(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Shard(shard) => {
+                let mut args = shard.to_cli_args();
+                args.push_front("shard".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Shard(shard) => shard.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify the shard of the chunk
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliShard {
+    shard_id: Option<near_primitives::types::ShardId>,
+    #[clap(subcommand)]
+    block_id: Option<super::block_id::CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct Shard {
+    shard_id: near_primitives::types::ShardId,
+    block_id: super::block_id::BlockId,
+}
+
+impl From<CliShard> for Shard {
+    fn from(item: CliShard) -> Self {
+        let shard_id: near_primitives::types::ShardId = match item.shard_id {
+            Some(cli_shard_id) => cli_shard_id,
+            None => Shard::input_shard_id(),
+        };
+        let block_id = match item.block_id {
+            Some(cli_block_id) => super::block_id::BlockId::from(cli_block_id),
+            None => super::block_id::BlockId::choose_block_id(),
+        };
+        Self { shard_id, block_id }
+    }
+}
+
+impl Shard {
+    fn input_shard_id() -> near_primitives::types::ShardId {
+        println!();
+        crate::common::ensure_interactive("<shard-id>");
+        Input::new()
+            .with_prompt("What is the ID of the shard?")
+            .default(0)
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.block_id.to_cli_args();
+        args.push_front(self.shard_id.to_string());
+        args
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.block_id
+            .process(network_connection_config, self.shard_id)
+            .await
+    }
+}
@@ -24,6 +24,7 @@ impl From<CliBlockIdHeight> for BlockIdHeight {
 
 impl BlockIdHeight {
     pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
+        crate::common::ensure_interactive("<block-id-height>");
         Input::new()
             .with_prompt("Type the block ID height for this contract")
             .interact_text()
@@ -34,6 +35,16 @@ impl BlockIdHeight {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn block_reference(&self) -> near_primitives::types::BlockReference {
+        near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Height(
+            self.block_id_height,
+        ))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.block_id_height.to_string()])
+    }
+
     pub async fn process(
         self,
         contract_id: String,
@@ -52,7 +63,7 @@ impl BlockIdHeight {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view contract: {:?}",
                     err
                 ))
@@ -77,12 +88,20 @@ impl BlockIdHeight {
                     .map_err(|err| {
                         color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
                     })?;
-                println!("\nThe file {:?} was downloaded successfully", file_path);
+                crate::common::print_message(&format!(
+                    "\nThe file {:?} was downloaded successfully",
+                    file_path
+                ));
+                crate::common::set_json_output("file_path", file_path.display().to_string());
+                crate::wasm::check_downloaded_code(&call_access_view.code, &call_access_view.hash)?;
             }
             None => {
-                println!("\nHash of the contract: {}", &call_access_view.hash)
+                if let crate::common::OutputFormat::Plaintext = crate::common::output_format() {
+                    println!("\nHash of the contract: {}", &call_access_view.hash)
+                }
             }
         }
+        crate::common::set_json_output("code_hash", call_access_view.hash);
         Ok(())
     }
 }
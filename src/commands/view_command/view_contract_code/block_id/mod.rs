@@ -48,6 +48,7 @@ impl BlockId {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&blocks)
@@ -62,6 +63,24 @@ impl BlockId {
         Self::from(cli_block_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AtFinalBlock => {
+                std::collections::VecDeque::from(vec!["at-final-block".to_owned()])
+            }
+            Self::AtBlockHeight(at_block_height) => {
+                let mut args = at_block_height.to_cli_args();
+                args.push_front("at-block-height".to_owned());
+                args
+            }
+            Self::AtBlockHash(at_block_hash) => {
+                let mut args = at_block_hash.to_cli_args();
+                args.push_front("at-block-hash".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         contract_id: String,
@@ -87,6 +106,24 @@ impl BlockId {
         }
     }
 
+    pub fn block_reference(&self) -> near_primitives::types::BlockReference {
+        match self {
+            Self::AtFinalBlock => near_primitives::types::Finality::Final.into(),
+            Self::AtBlockHeight(block_id_height) => block_id_height.block_reference(),
+            Self::AtBlockHash(block_id_hash) => block_id_hash.block_reference(),
+        }
+    }
+
+    /// Past blocks are only kept by the archival nodes
+    pub fn rpc_url(&self, network_connection_config: &crate::common::ConnectionConfig) -> url::Url {
+        match self {
+            Self::AtFinalBlock => network_connection_config.rpc_url(),
+            Self::AtBlockHeight(_) | Self::AtBlockHash(_) => {
+                network_connection_config.archival_rpc_url()
+            }
+        }
+    }
+
     fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
@@ -107,7 +144,7 @@ impl BlockId {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view contract: {:?}",
                     err
                 ))
@@ -132,12 +169,20 @@ impl BlockId {
                     .map_err(|err| {
                         color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
                     })?;
-                println!("\nThe file {:?} was downloaded successfully", file_path);
+                crate::common::print_message(&format!(
+                    "\nThe file {:?} was downloaded successfully",
+                    file_path
+                ));
+                crate::common::set_json_output("file_path", file_path.display().to_string());
+                crate::wasm::check_downloaded_code(&call_access_view.code, &call_access_view.hash)?;
             }
             None => {
-                println!("\nHash of the contract: {}", &call_access_view.hash)
+                if let crate::common::OutputFormat::Plaintext = crate::common::output_format() {
+                    println!("\nHash of the contract: {}", &call_access_view.hash)
+                }
             }
         }
+        crate::common::set_json_output("code_hash", call_access_view.hash);
         Ok(())
     }
 }
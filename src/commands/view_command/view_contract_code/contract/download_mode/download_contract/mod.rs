@@ -40,6 +40,7 @@ impl ContractFile {
 impl ContractFile {
     fn input_file_path(contract_id: &str) -> Option<std::path::PathBuf> {
         println!();
+        crate::common::ensure_interactive("<file-path>");
         let input_file_path: String = Input::new()
             .with_prompt("Where to download the contract file?")
             .with_initial_text(format!("{}.wasm", contract_id))
@@ -48,6 +49,14 @@ impl ContractFile {
         Some(input_file_path.into())
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_block_id.to_cli_args();
+        if let Some(file_path) = &self.file_path {
+            args.push_front(file_path.display().to_string());
+        }
+        args
+    }
+
     pub async fn process(
         self,
         contract_id: String,
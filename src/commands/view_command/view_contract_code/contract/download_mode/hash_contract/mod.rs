@@ -27,6 +27,10 @@ impl ContractHash {
 }
 
 impl ContractHash {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.selected_block_id.to_cli_args()
+    }
+
     pub async fn process(
         self,
         contract_id: String,
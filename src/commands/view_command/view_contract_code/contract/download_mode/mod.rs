@@ -3,6 +3,7 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod download_contract;
 mod hash_contract;
+mod verify_contract;
 
 #[derive(Debug, clap::Clap)]
 pub enum CliDownloadMode {
@@ -10,6 +11,8 @@ pub enum CliDownloadMode {
     Download(self::download_contract::CliContractFile),
     /// View a contract hash
     Hash(self::hash_contract::CliContractHash),
+    /// Verify that a local contract file is the deployed contract
+    Verify(self::verify_contract::CliContractVerify),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -19,6 +22,8 @@ pub enum DownloadMode {
     Download(self::download_contract::ContractFile),
     #[strum_discriminants(strum(message = "View a contract hash"))]
     Hash(self::hash_contract::ContractHash),
+    #[strum_discriminants(strum(message = "Verify a local contract file"))]
+    Verify(self::verify_contract::ContractVerify),
 }
 
 impl DownloadMode {
@@ -30,6 +35,9 @@ impl DownloadMode {
             CliDownloadMode::Hash(cli_contract_hash) => {
                 DownloadMode::Hash(self::hash_contract::ContractHash::from(cli_contract_hash))
             }
+            CliDownloadMode::Verify(cli_contract_verify) => DownloadMode::Verify(
+                self::verify_contract::ContractVerify::from(cli_contract_verify),
+            ),
         }
     }
 }
@@ -42,6 +50,7 @@ impl DownloadMode {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("download-mode subcommand");
         let selected_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("To view contract code you will need to choose next action")
             .items(&modes)
@@ -51,10 +60,31 @@ impl DownloadMode {
         let cli_mode = match variants[selected_mode] {
             DownloadModeDiscriminants::Download => CliDownloadMode::Download(Default::default()),
             DownloadModeDiscriminants::Hash => CliDownloadMode::Hash(Default::default()),
+            DownloadModeDiscriminants::Verify => CliDownloadMode::Verify(Default::default()),
         };
         Self::from(cli_mode, contract_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Download(download) => {
+                let mut args = download.to_cli_args();
+                args.push_front("download".to_owned());
+                args
+            }
+            Self::Hash(hash) => {
+                let mut args = hash.to_cli_args();
+                args.push_front("hash".to_owned());
+                args
+            }
+            Self::Verify(verify) => {
+                let mut args = verify.to_cli_args();
+                args.push_front("verify".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         contract_id: String,
@@ -71,6 +101,11 @@ impl DownloadMode {
                     .process(contract_id, network_connection_config)
                     .await
             }
+            DownloadMode::Verify(contract_verify) => {
+                contract_verify
+                    .process(contract_id, network_connection_config)
+                    .await
+            }
         }
     }
 }
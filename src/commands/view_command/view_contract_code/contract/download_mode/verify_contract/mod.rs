@@ -0,0 +1,123 @@
+use dialoguer::Input;
+
+// verify a local contract file against the deployed contract
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliContractVerify {
+    /// The local contract file, e.g. a reproducible build
+    #[clap(long)]
+    file: Option<std::path::PathBuf>,
+    #[clap(subcommand)]
+    selected_block_id: Option<super::super::super::block_id::CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct ContractVerify {
+    pub file: std::path::PathBuf,
+    pub selected_block_id: super::super::super::block_id::BlockId,
+}
+
+impl ContractVerify {
+    pub fn from(item: CliContractVerify) -> Self {
+        let file = match item.file {
+            Some(cli_file) => cli_file,
+            None => ContractVerify::input_file(),
+        };
+        let selected_block_id: super::super::super::block_id::BlockId = match item.selected_block_id
+        {
+            Some(cli_block_id) => cli_block_id.into(),
+            None => super::super::super::block_id::BlockId::choose_block_id(),
+        };
+        ContractVerify {
+            file,
+            selected_block_id,
+        }
+    }
+}
+
+impl ContractVerify {
+    fn input_file() -> std::path::PathBuf {
+        println!();
+        crate::common::ensure_interactive("--file");
+        Input::<String>::new()
+            .with_prompt("What is a file location of the contract to verify?")
+            .interact_text()
+            .unwrap()
+            .into()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_block_id.to_cli_args();
+        args.push_front(self.file.display().to_string());
+        args.push_front("--file".to_owned());
+        args
+    }
+
+    pub async fn process(
+        self,
+        contract_id: String,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let code = std::fs::read(&self.file).map_err(|err| {
+            crate::common::CliError::UserInput(format!(
+                "Failed to read {}: {}",
+                self.file.display(),
+                err
+            ))
+        })?;
+        let local_code_hash = near_primitives::hash::hash(&code);
+        let code_hash = crate::wasm::account_code_hash(
+            &self.selected_block_id.rpc_url(&network_connection_config),
+            contract_id.clone(),
+            self.selected_block_id.block_reference(),
+        )
+        .await?;
+        let is_verified = code_hash == Some(local_code_hash);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "\nCode hash of {}: {}",
+                    self.file.display(),
+                    crate::wasm::display_code_hash(&local_code_hash)
+                );
+                match &code_hash {
+                    Some(code_hash) => println!(
+                        "Code hash of <{}>: {}",
+                        contract_id,
+                        crate::wasm::display_code_hash(code_hash)
+                    ),
+                    None => println!("<{}> has no contract deployed", contract_id),
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("file_path", self.file.display().to_string());
+                crate::common::set_json_output("local_code_hash", local_code_hash);
+                crate::common::set_json_output("code_hash", code_hash);
+                crate::common::set_json_output("verified", is_verified);
+            }
+        }
+        if !is_verified {
+            return Err(crate::common::CliError::CodeHashMismatch(format!(
+                "FAILED: {} hashes to {}, not to the code hash {} of the contract deployed on <{}>",
+                self.file.display(),
+                crate::wasm::display_code_hash(&local_code_hash),
+                code_hash.map_or_else(
+                    || "(no contract)".to_owned(),
+                    |code_hash| crate::wasm::display_code_hash(&code_hash)
+                ),
+                contract_id
+            ))
+            .into());
+        }
+        crate::common::print_message(&format!(
+            "PASSED: {} is the contract deployed on <{}>",
+            self.file.display(),
+            contract_id
+        ));
+        Ok(())
+    }
+}
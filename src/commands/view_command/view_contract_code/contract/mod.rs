@@ -29,6 +29,16 @@ impl SendTo {
         Self::from(CliSendTo::Contract(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Contract(contract) => {
+                let mut args = contract.to_cli_args();
+                args.push_front("contract".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -80,12 +90,19 @@ impl From<CliContract> for Contract {
 impl Contract {
     pub fn input_contract_id() -> String {
         println!();
+        crate::common::ensure_interactive("<contract-id>");
         Input::new()
             .with_prompt("What contract do you need to view?")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.download_mode.to_cli_args();
+        args.push_front(self.contract_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -19,17 +19,21 @@ pub struct OperationMode {
     pub mode: Mode,
 }
 
-impl From<CliOperationMode> for OperationMode {
-    fn from(item: CliOperationMode) -> Self {
+impl OperationMode {
+    pub fn from(item: CliOperationMode) -> color_eyre::eyre::Result<Self> {
         let mode = match item.mode {
-            Some(cli_mode) => Mode::from(cli_mode),
-            None => Mode::choose_mode(),
+            Some(cli_mode) => Mode::from(cli_mode)?,
+            None => Mode::choose_mode()?,
         };
-        Self { mode }
+        Ok(Self { mode })
     }
 }
 
 impl OperationMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.mode.to_cli_args()
+    }
+
     pub async fn process(self) -> crate::CliResult {
         self.mode.process().await
     }
@@ -48,19 +52,31 @@ pub enum Mode {
     Network(self::online_mode::NetworkArgs),
 }
 
-impl From<CliMode> for Mode {
-    fn from(item: CliMode) -> Self {
+impl Mode {
+    fn from(item: CliMode) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliMode::Network(cli_network_args) => Self::Network(cli_network_args.into()),
+            CliMode::Network(cli_network_args) => Ok(Self::Network(
+                self::online_mode::NetworkArgs::from(cli_network_args)?,
+            )),
         }
     }
 }
 
 impl Mode {
-    pub fn choose_mode() -> Self {
+    pub fn choose_mode() -> color_eyre::eyre::Result<Self> {
         Self::from(CliMode::Network(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Network(network) => {
+                let mut args = network.to_cli_args();
+                args.push_front("network".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(self) -> crate::CliResult {
         match self {
             Self::Network(network_args) => network_args.process().await,
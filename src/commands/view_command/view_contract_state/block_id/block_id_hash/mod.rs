@@ -23,55 +23,35 @@ impl From<CliBlockIdHash> for BlockIdHash {
 
 impl BlockIdHash {
     pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
+        crate::common::ensure_interactive("<block-id-hash>");
         Input::new()
             .with_prompt("Type the block ID hash for this contract")
             .interact_text()
             .unwrap()
     }
 
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.block_id_hash.to_string()])
+    }
+
+    pub fn block_reference(&self) -> near_primitives::types::BlockReference {
+        near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Hash(
+            self.block_id_hash,
+        ))
     }
 
     pub async fn process(
         self,
         sender_account_id: String,
         network_connection_config: crate::common::ConnectionConfig,
+        state_view: super::super::StateView,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::BlockReference::BlockId(
-                    near_primitives::types::BlockId::Hash(self.block_id_hash.clone()),
-                ),
-                request: near_primitives::views::QueryRequest::ViewState {
-                    account_id: sender_account_id,
-                    prefix: near_primitives::types::StoreKey::from(vec![]),
-                },
-            })
+        state_view
+            .process(
+                sender_account_id,
+                &network_connection_config.archival_rpc_url(),
+                self.block_reference(),
+            )
             .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view account: {:?}",
-                    err
-                ))
-            })?;
-        let call_access_view =
-            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
-                query_view_method_response.kind
-            {
-                result
-            } else {
-                return Err(color_eyre::Report::msg(format!("Error call result")));
-            };
-        println!(
-            "\nContract state (values):\n{:#?}\n",
-            &call_access_view.values
-        );
-        println!(
-            "\nContract state (proof):\n{:#?}\n",
-            &call_access_view.proof
-        );
-        Ok(())
     }
 }
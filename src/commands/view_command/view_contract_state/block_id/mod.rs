@@ -1,8 +1,8 @@
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
-mod block_id_hash;
-mod block_id_height;
+pub mod block_id_hash;
+pub mod block_id_height;
 
 #[derive(Debug, clap::Clap)]
 pub enum CliBlockId {
@@ -41,14 +41,19 @@ impl From<CliBlockId> for BlockId {
 
 impl BlockId {
     pub fn choose_block_id() -> Self {
+        Self::choose_block_id_with_prompt("Choose your action")
+    }
+
+    pub fn choose_block_id_with_prompt(prompt: &str) -> Self {
         println!();
         let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
         let blocks = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id subcommand");
         let selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Choose your action")
+            .with_prompt(prompt)
             .items(&blocks)
             .default(0)
             .interact()
@@ -61,71 +66,79 @@ impl BlockId {
         Self::from(cli_block_id)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AtFinalBlock => {
+                std::collections::VecDeque::from(vec!["at-final-block".to_owned()])
+            }
+            Self::AtBlockHeight(at_block_height) => {
+                let mut args = at_block_height.to_cli_args();
+                args.push_front("at-block-height".to_owned());
+                args
+            }
+            Self::AtBlockHash(at_block_hash) => {
+                let mut args = at_block_hash.to_cli_args();
+                args.push_front("at-block-hash".to_owned());
+                args
+            }
+        }
+    }
+
+    pub fn block_reference(&self) -> near_primitives::types::BlockReference {
+        match self {
+            Self::AtFinalBlock => near_primitives::types::Finality::Final.into(),
+            Self::AtBlockHeight(block_id_height) => block_id_height.block_reference(),
+            Self::AtBlockHash(block_id_hash) => block_id_hash.block_reference(),
+        }
+    }
+
+    /// Past blocks are only kept by the archival nodes
+    pub fn rpc_url(&self, network_connection_config: &crate::common::ConnectionConfig) -> url::Url {
+        match self {
+            Self::AtFinalBlock => network_connection_config.rpc_url(),
+            Self::AtBlockHeight(_) | Self::AtBlockHash(_) => {
+                network_connection_config.archival_rpc_url()
+            }
+        }
+    }
+
     pub async fn process(
         self,
         sender_account_id: String,
         network_connection_config: crate::common::ConnectionConfig,
+        state_view: super::StateView,
     ) -> crate::CliResult {
         println!();
         match self {
             Self::AtBlockHeight(block_id_height) => {
                 block_id_height
-                    .process(sender_account_id, network_connection_config)
+                    .process(sender_account_id, network_connection_config, state_view)
                     .await
             }
             Self::AtBlockHash(block_id_hash) => {
                 block_id_hash
-                    .process(sender_account_id, network_connection_config)
+                    .process(sender_account_id, network_connection_config, state_view)
                     .await
             }
             Self::AtFinalBlock => {
-                self.at_final_block(sender_account_id, network_connection_config)
+                self.at_final_block(sender_account_id, network_connection_config, state_view)
                     .await
             }
         }
     }
 
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     async fn at_final_block(
         self,
         sender_account_id: String,
         network_connection_config: crate::common::ConnectionConfig,
+        state_view: super::StateView,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::Finality::Final.into(),
-                request: near_primitives::views::QueryRequest::ViewState {
-                    account_id: sender_account_id,
-                    prefix: near_primitives::types::StoreKey::from(vec![]),
-                },
-            })
+        state_view
+            .process(
+                sender_account_id,
+                &network_connection_config.rpc_url(),
+                near_primitives::types::Finality::Final.into(),
+            )
             .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view account: {:?}",
-                    err
-                ))
-            })?;
-        let call_access_view =
-            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
-                query_view_method_response.kind
-            {
-                result
-            } else {
-                return Err(color_eyre::Report::msg(format!("Error call result")));
-            };
-        println!(
-            "\nContract state (values):\n{:#?}\n",
-            &call_access_view.values
-        );
-        println!(
-            "\nContract state (proof):\n{:#?}\n",
-            &call_access_view.proof
-        );
-        Ok(())
     }
 }
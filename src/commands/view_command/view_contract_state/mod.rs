@@ -1,3 +1,138 @@
-mod block_id;
+pub mod block_id;
 pub mod operation_mode;
 mod sender;
+
+/// What to show of the contract state and where to export it
+#[derive(Debug)]
+pub struct StateView {
+    state_prefix: crate::contract_state::StatePrefix,
+    hide_proof: bool,
+    export: Option<std::path::PathBuf>,
+}
+
+impl StateView {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.state_prefix.to_cli_args();
+        if self.hide_proof {
+            args.push_back("--hide-proof".to_owned());
+        }
+        if let Some(export) = &self.export {
+            args.push_back("--export".to_owned());
+            args.push_back(export.display().to_string());
+        }
+        args
+    }
+
+    pub async fn process(
+        self,
+        account_id: String,
+        rpc_url: &url::Url,
+        block_reference: near_primitives::types::BlockReference,
+    ) -> crate::CliResult {
+        let view_state_result = crate::contract_state::view_state(
+            rpc_url,
+            account_id.clone(),
+            block_reference,
+            &self.state_prefix,
+        )
+        .await?;
+        let raw_state_items = crate::contract_state::state_items(&view_state_result)?;
+        if let Some(export) = &self.export {
+            self.export_state(export, &raw_state_items)?;
+        }
+        let state_items = raw_state_items
+            .iter()
+            .map(|(key, value)| {
+                (
+                    self.state_prefix.display(key),
+                    self.state_prefix.display(value),
+                )
+            })
+            .collect::<Vec<_>>();
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "\nContract state of <{}> ({} keys):",
+                    account_id,
+                    state_items.len()
+                );
+                for (key, value) in &state_items {
+                    println!("  {}: {}", key, value);
+                }
+                if !self.hide_proof {
+                    println!(
+                        "\nContract state (proof):\n{:#?}\n",
+                        &view_state_result.proof
+                    );
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output(
+                    "state",
+                    state_items
+                        .iter()
+                        .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+                        .collect::<Vec<_>>(),
+                );
+                if !self.hide_proof {
+                    crate::common::set_json_output("proof", &view_state_result.proof);
+                }
+                crate::common::set_json_output("values", view_state_result.values);
+            }
+        }
+        Ok(())
+    }
+
+    /// Saves the state as CSV if the file has the .csv extension, and as JSON otherwise. The keys
+    /// and values are the raw bytes in base64, as the RPC returns them, so that nothing is lost
+    /// on the way back.
+    fn export_state(
+        &self,
+        export: &std::path::Path,
+        raw_state_items: &[(Vec<u8>, Vec<u8>)],
+    ) -> crate::CliResult {
+        let is_csv = export
+            .extension()
+            .map_or(false, |extension| extension.eq_ignore_ascii_case("csv"));
+        let state_items = raw_state_items
+            .iter()
+            .map(|(key, value)| {
+                (
+                    near_primitives::serialize::to_base64(key),
+                    near_primitives::serialize::to_base64(value),
+                )
+            })
+            .collect::<Vec<_>>();
+        let content = if is_csv {
+            let mut content = "key,value\n".to_owned();
+            for (key, value) in &state_items {
+                content.push_str(&format!(
+                    "{},{}\n",
+                    crate::contract_state::csv_field(key),
+                    crate::contract_state::csv_field(value)
+                ));
+            }
+            content
+        } else {
+            serde_json::to_string_pretty(
+                &state_items
+                    .iter()
+                    .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+                    .collect::<Vec<_>>(),
+            )?
+        };
+        std::fs::write(export, content).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to export the state to {}: {}",
+                export.display(),
+                err
+            ))
+        })?;
+        crate::common::print_message(&format!(
+            "The contract state is exported to {}",
+            export.display()
+        ));
+        crate::common::set_json_output("export_file", export.display().to_string());
+        Ok(())
+    }
+}
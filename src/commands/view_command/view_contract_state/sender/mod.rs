@@ -27,6 +27,16 @@ impl SendTo {
         Self::from(CliSendTo::Account(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -46,6 +56,14 @@ impl SendTo {
 )]
 pub struct CliSender {
     pub sender_account_id: Option<String>,
+    #[clap(flatten)]
+    state_prefix: crate::contract_state::CliStatePrefix,
+    /// Do not show the proof
+    #[clap(long)]
+    hide_proof: bool,
+    /// Save the state to a file, as CSV if it has the .csv extension and as JSON otherwise
+    #[clap(long)]
+    export: Option<std::path::PathBuf>,
     #[clap(subcommand)]
     selected_block_id: Option<super::block_id::CliBlockId>,
 }
@@ -53,6 +71,7 @@ pub struct CliSender {
 #[derive(Debug)]
 pub struct Sender {
     pub sender_account_id: String,
+    state_view: super::StateView,
     selected_block_id: super::block_id::BlockId,
 }
 
@@ -62,12 +81,18 @@ impl From<CliSender> for Sender {
             Some(cli_sender_account_id) => cli_sender_account_id,
             None => Sender::input_sender_account_id(),
         };
+        let state_view = super::StateView {
+            state_prefix: item.state_prefix.into(),
+            hide_proof: item.hide_proof,
+            export: item.export,
+        };
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
             Some(cli_block_id) => cli_block_id.into(),
             None => super::block_id::BlockId::choose_block_id(),
         };
         Self {
             sender_account_id,
+            state_view,
             selected_block_id,
         }
     }
@@ -76,18 +101,32 @@ impl From<CliSender> for Sender {
 impl Sender {
     pub fn input_sender_account_id() -> String {
         println!();
+        crate::common::ensure_interactive("<sender-account-id>");
         Input::new()
             .with_prompt("Enter your account ID to view your contract status")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.selected_block_id.to_cli_args();
+        for arg in self.state_view.to_cli_args().into_iter().rev() {
+            args.push_front(arg);
+        }
+        args.push_front(self.sender_account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         self.selected_block_id
-            .process(self.sender_account_id, network_connection_config)
+            .process(
+                self.sender_account_id,
+                network_connection_config,
+                self.state_view,
+            )
             .await
     }
 }
@@ -0,0 +1,94 @@
+use super::super::view_contract_state::block_id::{
+    block_id_hash::CliBlockIdHash, block_id_height::CliBlockIdHeight, BlockId, CliBlockId,
+};
+
+/// The block to compare the state from, followed by the block to compare it to. Both are
+/// given as the block IDs of `view contract-state`.
+#[derive(Debug, clap::Clap)]
+pub enum CliFromBlockId {
+    /// Compare the state from the final block
+    AtFinalBlock(CliToBlockId),
+    /// Compare the state from the block with this height
+    AtBlockHeight(CliFromBlockIdHeight),
+    /// Compare the state from the block with this hash
+    AtBlockHash(CliFromBlockIdHash),
+}
+
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliToBlockId {
+    #[clap(subcommand)]
+    to_block_id: Option<CliBlockId>,
+}
+
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliFromBlockIdHeight {
+    #[clap(flatten)]
+    block_id_height: CliBlockIdHeight,
+    #[clap(subcommand)]
+    to_block_id: Option<CliBlockId>,
+}
+
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliFromBlockIdHash {
+    #[clap(flatten)]
+    block_id_hash: CliBlockIdHash,
+    #[clap(subcommand)]
+    to_block_id: Option<CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct Blocks {
+    pub from_block_id: BlockId,
+    pub to_block_id: BlockId,
+}
+
+impl From<Option<CliFromBlockId>> for Blocks {
+    fn from(item: Option<CliFromBlockId>) -> Self {
+        let (from_block_id, cli_to_block_id) = match item {
+            Some(CliFromBlockId::AtFinalBlock(cli_to_block_id)) => {
+                (BlockId::AtFinalBlock, cli_to_block_id.to_block_id)
+            }
+            Some(CliFromBlockId::AtBlockHeight(cli_from_block_id_height)) => (
+                BlockId::AtBlockHeight(cli_from_block_id_height.block_id_height.into()),
+                cli_from_block_id_height.to_block_id,
+            ),
+            Some(CliFromBlockId::AtBlockHash(cli_from_block_id_hash)) => (
+                BlockId::AtBlockHash(cli_from_block_id_hash.block_id_hash.into()),
+                cli_from_block_id_hash.to_block_id,
+            ),
+            None => (
+                BlockId::choose_block_id_with_prompt("Compare the state from the block"),
+                None,
+            ),
+        };
+        let to_block_id = match cli_to_block_id {
+            Some(cli_to_block_id) => cli_to_block_id.into(),
+            None => BlockId::choose_block_id_with_prompt("Compare the state to the block"),
+        };
+        Self {
+            from_block_id,
+            to_block_id,
+        }
+    }
+}
+
+impl Blocks {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.from_block_id.to_cli_args();
+        args.extend(self.to_block_id.to_cli_args());
+        args
+    }
+}
+
+/// e.g. `the block #73066380` or `the final block`
+pub fn block_name(block_id: &BlockId) -> String {
+    match block_id.block_reference() {
+        near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Height(block_height),
+        ) => format!("the block #{}", block_height),
+        near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Hash(
+            block_hash,
+        )) => format!("the block {}", block_hash),
+        _ => "the final block".to_owned(),
+    }
+}
@@ -0,0 +1,184 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an account
+    Account(CliSender),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Sender),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_sender) => {
+                let sender = Sender::from(cli_sender);
+                Self::Account(sender)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(sender) => sender.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify the account and the two blocks to compare its contract state at
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    #[clap(flatten)]
+    state_prefix: crate::contract_state::CliStatePrefix,
+    #[clap(subcommand)]
+    blocks: Option<super::block_id::CliFromBlockId>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    state_prefix: crate::contract_state::StatePrefix,
+    blocks: super::block_id::Blocks,
+}
+
+impl From<CliSender> for Sender {
+    fn from(item: CliSender) -> Self {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let state_prefix = item.state_prefix.into();
+        let blocks = super::block_id::Blocks::from(item.blocks);
+        Self {
+            sender_account_id,
+            state_prefix,
+            blocks,
+        }
+    }
+}
+
+impl Sender {
+    pub fn input_sender_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<sender-account-id>");
+        Input::new()
+            .with_prompt("Enter the account ID to compare its contract state")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.blocks.to_cli_args();
+        for arg in self.state_prefix.to_cli_args().into_iter().rev() {
+            args.push_front(arg);
+        }
+        args.push_front(self.sender_account_id.to_string());
+        args
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let mut states = vec![];
+        for block_id in &[&self.blocks.from_block_id, &self.blocks.to_block_id] {
+            let view_state_result = crate::contract_state::view_state(
+                &block_id.rpc_url(&network_connection_config),
+                self.sender_account_id.clone(),
+                block_id.block_reference(),
+                &self.state_prefix,
+            )
+            .await?;
+            states.push(crate::contract_state::state_items(&view_state_result)?);
+        }
+        let new_state = states.pop().unwrap();
+        let old_state = states.pop().unwrap();
+        let state_diff = crate::contract_state::StateDiff::new(old_state, new_state);
+        let display = |bytes: &Vec<u8>| self.state_prefix.display(bytes);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "\nContract state of <{}> from {} to {}:",
+                    self.sender_account_id,
+                    super::block_id::block_name(&self.blocks.from_block_id),
+                    super::block_id::block_name(&self.blocks.to_block_id)
+                );
+                for (key, value) in &state_diff.added {
+                    println!("  + {}: {}", display(key), display(value));
+                }
+                for (key, value) in &state_diff.removed {
+                    println!("  - {}: {}", display(key), display(value));
+                }
+                for (key, old_value, new_value) in &state_diff.changed {
+                    println!(
+                        "  ~ {}: {} -> {}",
+                        display(key),
+                        display(old_value),
+                        display(new_value)
+                    );
+                }
+                println!(
+                    "{} added, {} removed, {} changed",
+                    state_diff.added.len(),
+                    state_diff.removed.len(),
+                    state_diff.changed.len()
+                );
+            }
+            crate::common::OutputFormat::Json => {
+                let entries = |entries: &Vec<(Vec<u8>, Vec<u8>)>| {
+                    entries
+                        .iter()
+                        .map(|(key, value)| {
+                            serde_json::json!({ "key": display(key), "value": display(value) })
+                        })
+                        .collect::<Vec<_>>()
+                };
+                crate::common::set_json_output("added", entries(&state_diff.added));
+                crate::common::set_json_output("removed", entries(&state_diff.removed));
+                crate::common::set_json_output(
+                    "changed",
+                    state_diff
+                        .changed
+                        .iter()
+                        .map(|(key, old_value, new_value)| {
+                            serde_json::json!({
+                                "key": display(key),
+                                "old_value": display(old_value),
+                                "new_value": display(new_value),
+                            })
+                        })
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an account
+    Account(CliSender),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Sender),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_sender) => {
+                let sender = Sender::from(cli_sender);
+                Self::Account(sender)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(sender) => sender.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify the delegator and the staking pool to view the delegation of
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    /// The staking pool contract
+    pool_account_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    pool_account_id: String,
+}
+
+impl From<CliSender> for Sender {
+    fn from(item: CliSender) -> Self {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let pool_account_id: String = match item.pool_account_id {
+            Some(pool_account_id) => pool_account_id,
+            None => Sender::input_pool_account_id(),
+        };
+        Self {
+            sender_account_id,
+            pool_account_id,
+        }
+    }
+}
+
+impl Sender {
+    pub fn input_sender_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<sender-account-id>");
+        Input::new()
+            .with_prompt("Enter the account ID to view its delegation")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_pool_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<pool-account-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the staking pool?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![
+            self.sender_account_id.to_string(),
+            self.pool_account_id.to_string(),
+        ])
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let delegation = crate::staking_pool::delegation(
+            &network_connection_config.rpc_url(),
+            &self.pool_account_id,
+            &self.sender_account_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "\nDelegation of <{}> to <{}>:",
+                    self.sender_account_id, self.pool_account_id
+                );
+                println!(
+                    "  Staked balance:   {}",
+                    crate::common::NearBalance::from_yoctonear(delegation.staked_balance)
+                );
+                println!(
+                    "  Unstaked balance: {} ({})",
+                    crate::common::NearBalance::from_yoctonear(delegation.unstaked_balance),
+                    if delegation.is_unstaked_balance_available {
+                        "available for withdrawal"
+                    } else {
+                        "not available for withdrawal yet"
+                    }
+                );
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("account_id", &self.sender_account_id);
+                crate::common::set_json_output("pool_account_id", &self.pool_account_id);
+                crate::common::set_json_output(
+                    "staked_balance",
+                    delegation.staked_balance.to_string(),
+                );
+                crate::common::set_json_output(
+                    "unstaked_balance",
+                    delegation.unstaked_balance.to_string(),
+                );
+                crate::common::set_json_output(
+                    "is_unstaked_balance_available",
+                    delegation.is_unstaked_balance_available,
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an account
+    Account(CliSender),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Sender),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_sender) => {
+                let sender = Sender::from(cli_sender);
+                Self::Account(sender)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(sender) => sender.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify the account and the token contract to view the balance of
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    /// The NEP-141 token contract
+    token_contract_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    token_contract_id: String,
+}
+
+impl From<CliSender> for Sender {
+    fn from(item: CliSender) -> Self {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let token_contract_id: String = match item.token_contract_id {
+            Some(token_contract_id) => token_contract_id,
+            None => Sender::input_token_contract_id(),
+        };
+        Self {
+            sender_account_id,
+            token_contract_id,
+        }
+    }
+}
+
+impl Sender {
+    pub fn input_sender_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<sender-account-id>");
+        Input::new()
+            .with_prompt("Enter the account ID to view its token balance")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_token_contract_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<token-contract-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the token contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![
+            self.sender_account_id.to_string(),
+            self.token_contract_id.to_string(),
+        ])
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let rpc_url = network_connection_config.rpc_url();
+        let metadata =
+            crate::fungible_token::ft_metadata(&rpc_url, &self.token_contract_id).await?;
+        let balance = crate::fungible_token::ft_balance_of(
+            &rpc_url,
+            &self.token_contract_id,
+            &self.sender_account_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        let amount = crate::fungible_token::format_amount(balance, metadata.decimals);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => println!(
+                "\n<{}> has {} {} ({}) on <{}>",
+                self.sender_account_id,
+                amount,
+                metadata.symbol,
+                metadata.name,
+                self.token_contract_id
+            ),
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("account_id", &self.sender_account_id);
+                crate::common::set_json_output("token_contract_id", &self.token_contract_id);
+                crate::common::set_json_output("balance", balance.to_string());
+                crate::common::set_json_output("amount", amount);
+                crate::common::set_json_output("symbol", &metadata.symbol);
+                crate::common::set_json_output("decimals", metadata.decimals);
+            }
+        }
+        Ok(())
+    }
+}
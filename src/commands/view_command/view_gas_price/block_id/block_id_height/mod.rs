@@ -0,0 +1,40 @@
+use dialoguer::Input;
+
+/// Specify the block_id height to view the gas price
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliBlockIdHeight {
+    block_id_height: Option<near_primitives::types::BlockHeight>,
+}
+
+#[derive(Debug)]
+pub struct BlockIdHeight {
+    block_id_height: near_primitives::types::BlockHeight,
+}
+
+impl From<CliBlockIdHeight> for BlockIdHeight {
+    fn from(item: CliBlockIdHeight) -> Self {
+        let block_id_height: near_primitives::types::BlockHeight = match item.block_id_height {
+            Some(cli_block_id_height) => cli_block_id_height,
+            None => BlockIdHeight::input_block_id_height(),
+        };
+        Self { block_id_height }
+    }
+}
+
+impl BlockIdHeight {
+    pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
+        crate::common::ensure_interactive("<block-id-height>");
+        Input::new()
+            .with_prompt("Type the block ID height")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn block_id(&self) -> near_primitives::types::BlockId {
+        near_primitives::types::BlockId::Height(self.block_id_height)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.block_id_height.to_string()])
+    }
+}
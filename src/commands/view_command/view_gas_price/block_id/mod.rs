@@ -0,0 +1,127 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod block_id_hash;
+mod block_id_height;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliBlockId {
+    /// Specify a block ID final to view the gas price
+    AtFinalBlock,
+    /// Specify a block ID height to view the gas price
+    AtBlockHeight(self::block_id_height::CliBlockIdHeight),
+    /// Specify a block ID hash to view the gas price
+    AtBlockHash(self::block_id_hash::CliBlockIdHash),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum BlockId {
+    #[strum_discriminants(strum(message = "View the gas price at final block"))]
+    AtFinalBlock,
+    #[strum_discriminants(strum(message = "View the gas price at block height"))]
+    AtBlockHeight(self::block_id_height::BlockIdHeight),
+    #[strum_discriminants(strum(message = "View the gas price at block hash"))]
+    AtBlockHash(self::block_id_hash::BlockIdHash),
+}
+
+impl From<CliBlockId> for BlockId {
+    fn from(item: CliBlockId) -> Self {
+        match item {
+            CliBlockId::AtFinalBlock => Self::AtFinalBlock,
+            CliBlockId::AtBlockHeight(cli_block_id_height) => {
+                Self::AtBlockHeight(cli_block_id_height.into())
+            }
+            CliBlockId::AtBlockHash(cli_block_id_hash) => {
+                Self::AtBlockHash(cli_block_id_hash.into())
+            }
+        }
+    }
+}
+
+impl BlockId {
+    pub fn choose_block_id() -> Self {
+        println!();
+        let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
+        let blocks = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id subcommand");
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&blocks)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_block_id = match variants[selection] {
+            BlockIdDiscriminants::AtFinalBlock => CliBlockId::AtFinalBlock,
+            BlockIdDiscriminants::AtBlockHeight => CliBlockId::AtBlockHeight(Default::default()),
+            BlockIdDiscriminants::AtBlockHash => CliBlockId::AtBlockHash(Default::default()),
+        };
+        Self::from(cli_block_id)
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AtFinalBlock => {
+                std::collections::VecDeque::from(vec!["at-final-block".to_owned()])
+            }
+            Self::AtBlockHeight(at_block_height) => {
+                let mut args = at_block_height.to_cli_args();
+                args.push_front("at-block-height".to_owned());
+                args
+            }
+            Self::AtBlockHash(at_block_hash) => {
+                let mut args = at_block_hash.to_cli_args();
+                args.push_front("at-block-hash".to_owned());
+                args
+            }
+        }
+    }
+
+    /// `None` is the latest block for the `gas_price` RPC
+    pub fn block_id(&self) -> Option<near_primitives::types::BlockId> {
+        match self {
+            Self::AtFinalBlock => None,
+            Self::AtBlockHeight(block_id_height) => Some(block_id_height.block_id()),
+            Self::AtBlockHash(block_id_hash) => Some(block_id_hash.block_id()),
+        }
+    }
+
+    /// Past blocks are only kept by the archival nodes
+    pub fn rpc_url(&self, network_connection_config: &crate::common::ConnectionConfig) -> url::Url {
+        match self {
+            Self::AtFinalBlock => network_connection_config.rpc_url(),
+            Self::AtBlockHeight(_) | Self::AtBlockHash(_) => {
+                network_connection_config.archival_rpc_url()
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let gas_price =
+            near_jsonrpc_client::new_client(self.rpc_url(&network_connection_config).as_str())
+                .gas_price(self.block_id())
+                .await
+                .map_err(|err| {
+                    crate::common::CliError::Rpc(format!(
+                        "Failed to fetch the gas price: {:?}",
+                        err
+                    ))
+                })?
+                .gas_price;
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!("\nGas price: {} yoctoNEAR per gas unit", gas_price)
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("gas_price", gas_price.to_string())
+            }
+        }
+        Ok(())
+    }
+}
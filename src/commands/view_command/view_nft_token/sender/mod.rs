@@ -0,0 +1,142 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an NFT contract
+    Contract(CliSender),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Contract(Sender),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Contract(cli_sender) => {
+                let sender = Sender::from(cli_sender);
+                Self::Contract(sender)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Contract(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Contract(contract) => {
+                let mut args = contract.to_cli_args();
+                args.push_front("contract".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Contract(sender) => sender.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify the NFT contract and the token to view
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSender {
+    /// The NEP-171 NFT contract
+    pub nft_contract_id: Option<String>,
+    token_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub nft_contract_id: String,
+    token_id: String,
+}
+
+impl From<CliSender> for Sender {
+    fn from(item: CliSender) -> Self {
+        let nft_contract_id: String = match item.nft_contract_id {
+            Some(nft_contract_id) => nft_contract_id,
+            None => Sender::input_nft_contract_id(),
+        };
+        let token_id: String = match item.token_id {
+            Some(token_id) => token_id,
+            None => Sender::input_token_id(),
+        };
+        Self {
+            nft_contract_id,
+            token_id,
+        }
+    }
+}
+
+impl Sender {
+    fn input_nft_contract_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<nft-contract-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the NFT contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_token_id() -> String {
+        crate::common::ensure_interactive("<token-id>");
+        Input::new()
+            .with_prompt("What is the ID of the token?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![
+            self.nft_contract_id.to_string(),
+            self.token_id.to_string(),
+        ])
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let token = match crate::non_fungible_token::nft_token(
+            &network_connection_config.rpc_url(),
+            &self.nft_contract_id,
+            &self.token_id,
+        )
+        .await?
+        {
+            Some(token) => token,
+            None => {
+                return Err(crate::common::CliError::UserInput(format!(
+                    "The token <{}> does not exist on the contract <{}>",
+                    self.token_id, self.nft_contract_id
+                ))
+                .into())
+            }
+        };
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                crate::non_fungible_token::print_token(&token)
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("nft_contract_id", &self.nft_contract_id);
+                crate::common::set_json_output("token", &token);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an account
+    Account(CliOwner),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Owner),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_owner) => {
+                let owner = Owner::from(cli_owner);
+                Self::Account(owner)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(owner) => owner.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify the owner and the NFT contract to view the tokens of
+#[derive(Debug, Default, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliOwner {
+    pub owner_account_id: Option<String>,
+    /// The NEP-171 NFT contract
+    nft_contract_id: Option<String>,
+    /// The index of the first token of the page (0 by default)
+    #[clap(long)]
+    from_index: Option<u128>,
+    /// The number of tokens of the page (10 by default)
+    #[clap(long)]
+    limit: Option<u64>,
+    /// View all the tokens, page by page
+    #[clap(long)]
+    all: bool,
+}
+
+#[derive(Debug)]
+pub struct Owner {
+    pub owner_account_id: String,
+    nft_contract_id: String,
+    from_index: Option<u128>,
+    limit: Option<u64>,
+    all: bool,
+}
+
+impl From<CliOwner> for Owner {
+    fn from(item: CliOwner) -> Self {
+        let owner_account_id: String = match item.owner_account_id {
+            Some(cli_owner_account_id) => cli_owner_account_id,
+            None => Owner::input_owner_account_id(),
+        };
+        let nft_contract_id: String = match item.nft_contract_id {
+            Some(nft_contract_id) => nft_contract_id,
+            None => Owner::input_nft_contract_id(),
+        };
+        Self {
+            owner_account_id,
+            nft_contract_id,
+            from_index: item.from_index,
+            limit: item.limit,
+            all: item.all,
+        }
+    }
+}
+
+impl Owner {
+    pub fn input_owner_account_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<owner-account-id>");
+        Input::new()
+            .with_prompt("Enter the account ID of the owner to view its tokens")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_nft_contract_id() -> String {
+        println!();
+        crate::common::ensure_interactive("<nft-contract-id>");
+        Input::new()
+            .with_prompt("What is the account ID of the NFT contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::from(vec![
+            self.owner_account_id.to_string(),
+            self.nft_contract_id.to_string(),
+        ]);
+        if let Some(from_index) = &self.from_index {
+            args.push_back("--from-index".to_owned());
+            args.push_back(from_index.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            args.push_back("--limit".to_owned());
+            args.push_back(limit.to_string());
+        }
+        if self.all {
+            args.push_back("--all".to_owned());
+        }
+        args
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let rpc_url = network_connection_config.rpc_url();
+        let limit = self.limit.unwrap_or(10);
+        if limit == 0 {
+            return Err(crate::common::CliError::UserInput(
+                "--limit must be greater than 0".to_owned(),
+            )
+            .into());
+        }
+        let mut from_index = self.from_index.unwrap_or(0);
+        let mut tokens = vec![];
+        // A page shorter than the limit is the last one
+        let next_from_index = loop {
+            let page = crate::non_fungible_token::nft_tokens_for_owner(
+                &rpc_url,
+                &self.nft_contract_id,
+                &self.owner_account_id,
+                from_index,
+                limit,
+            )
+            .await?;
+            let is_last_page = (page.len() as u64) < limit;
+            from_index += page.len() as u128;
+            tokens.extend(page);
+            if is_last_page {
+                break None;
+            }
+            if !self.all {
+                break Some(from_index);
+            }
+        };
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                if tokens.is_empty() {
+                    println!(
+                        "\n<{}> has no tokens on <{}>",
+                        self.owner_account_id, self.nft_contract_id
+                    );
+                } else {
+                    println!(
+                        "\nTokens of <{}> on <{}>:",
+                        self.owner_account_id, self.nft_contract_id
+                    );
+                }
+                for token in &tokens {
+                    crate::non_fungible_token::print_token(token);
+                }
+                if let Some(next_from_index) = next_from_index {
+                    println!(
+                        "\nThere may be more tokens, view the next page with --from-index {} or all of them with --all",
+                        next_from_index
+                    );
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("owner_account_id", &self.owner_account_id);
+                crate::common::set_json_output("nft_contract_id", &self.nft_contract_id);
+                crate::common::set_json_output("tokens", &tokens);
+                crate::common::set_json_output(
+                    "next_from_index",
+                    next_from_index.map(|next_from_index| next_from_index.to_string()),
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -27,6 +27,16 @@ impl SendTo {
         Self::from(CliSendTo::Account(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(account) => {
+                let mut args = account.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -76,12 +86,19 @@ impl From<CliAccount> for Account {
 impl Account {
     fn input_account_id() -> String {
         println!();
+        crate::common::ensure_interactive("<account-id>");
         Input::new()
             .with_prompt("Enter your account ID")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.public_key.to_cli_args();
+        args.push_front(self.account_id.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -26,6 +26,16 @@ impl AccessKey {
         Self::from(CliAccessKey::PublicKey(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::PublicKey(public_key) => {
+                let mut args = public_key.to_cli_args();
+                args.push_front("public-key".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         account_id: String,
@@ -64,6 +74,7 @@ impl From<CliAccessKeyType> for AccessKeyType {
 
 impl AccessKeyType {
     pub fn input_public_key() -> near_crypto::PublicKey {
+        crate::common::ensure_interactive("<public-key>");
         Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
@@ -74,6 +85,10 @@ impl AccessKeyType {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.public_key.to_string()])
+    }
+
     pub async fn process(
         self,
         account_id: String,
@@ -91,7 +106,7 @@ impl AccessKeyType {
             })
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch public key information for nonce: {:?}",
                     err
                 ))
@@ -105,10 +120,16 @@ impl AccessKeyType {
             } else {
                 return Err(color_eyre::Report::msg(format!("Error current_nonce")));
             };
-        println!(
-            "\ncurrent nonce: {}  for a public key: {}",
-            current_nonce, public_key
-        );
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => println!(
+                "\ncurrent nonce: {}  for a public key: {}",
+                current_nonce, public_key
+            ),
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("public_key", public_key);
+                crate::common::set_json_output("nonce", current_nonce);
+            }
+        }
         Ok(())
     }
 }
@@ -19,6 +19,15 @@ pub struct CliServer {}
 pub struct CliCustomServer {
     #[clap(long)]
     pub url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Archival RPC endpoint (defaults to the RPC endpoint)
+    #[clap(long)]
+    pub archival_url: Option<url::Url>,
+    /// Wallet endpoint used to log in
+    #[clap(long)]
+    pub wallet_url: Option<url::Url>,
+    /// Explorer endpoint used to link the sent transactions
+    #[clap(long)]
+    pub explorer_url: Option<url::Url>,
 }
 
 #[derive(Debug)]
@@ -36,32 +45,49 @@ impl CliCustomServer {
     pub fn into_server(self) -> Server {
         let url: crate::common::AvailableRpcServerUrl = match self.url {
             Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the RPC endpoint?")
-                .interact_text()
-                .unwrap(),
+            None => {
+                crate::common::ensure_interactive("--url");
+                Input::new()
+                    .with_prompt("What is the RPC endpoint?")
+                    .interact_text()
+                    .unwrap()
+            }
         };
         Server {
-            connection_config: crate::common::ConnectionConfig::Custom { url: url.inner },
+            connection_config: crate::common::ConnectionConfig::custom(
+                url.inner,
+                self.archival_url,
+                self.wallet_url,
+                self.explorer_url,
+            ),
         }
     }
 }
 
 impl Server {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.connection_config.to_cli_args()
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let status = near_jsonrpc_client::new_client(self.connection_config.rpc_url().as_str())
             .status()
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch public key information for nonce: {:?}",
                     err
                 ))
             })?;
-        println!(
-            "recent block hash: {:?}",
-            status.sync_info.latest_block_hash
-        );
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => println!(
+                "recent block hash: {:?}",
+                status.sync_info.latest_block_hash
+            ),
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("block_hash", status.sync_info.latest_block_hash)
+            }
+        }
         Ok(())
     }
 }
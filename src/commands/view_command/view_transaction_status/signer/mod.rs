@@ -27,6 +27,16 @@ impl SendFrom {
         Self::from(CliSendFrom::Signer(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Signer(signer) => {
+                let mut args = signer.to_cli_args();
+                args.push_front("signer".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -66,6 +76,7 @@ impl From<CliSender> for Sender {
 impl Sender {
     pub fn input_sender_account_id() -> String {
         println!();
+        crate::common::ensure_interactive("<account-id>");
         Input::new()
             .with_prompt("Specify the account that signed the transaction")
             .interact_text()
@@ -76,6 +87,10 @@ impl Sender {
         near_jsonrpc_client::new_client(&selected_server_url)
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::from(vec![self.account_id.to_string()])
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -87,12 +102,33 @@ impl Sender {
             .tx(transaction_hash, account_id)
             .await
             .map_err(|err| {
-                color_eyre::Report::msg(format!(
+                crate::common::CliError::Rpc(format!(
                     "Failed to fetch query for view transaction: {:?}",
                     err
                 ))
             })?;
-        println!("Transactiion status: {:#?}", query_view_transaction_status);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                let status = match &query_view_transaction_status.status {
+                    near_primitives::views::FinalExecutionStatus::NotStarted => {
+                        "not started".to_string()
+                    }
+                    near_primitives::views::FinalExecutionStatus::Started => "started".to_string(),
+                    near_primitives::views::FinalExecutionStatus::Failure(_) => {
+                        "failed".to_string()
+                    }
+                    near_primitives::views::FinalExecutionStatus::SuccessValue(value) => format!(
+                        "succeeded with {}",
+                        crate::common::decode_success_value(value)
+                    ),
+                };
+                println!("Transaction status: {}\n", status);
+                crate::common::print_receipt_tree(&query_view_transaction_status);
+            }
+            crate::common::OutputFormat::Json => {
+                crate::common::set_json_output("transaction_outcome", query_view_transaction_status)
+            }
+        }
         Ok(())
     }
 }
@@ -26,6 +26,16 @@ impl Transaction {
         Self::from(CliTransaction::TransactionHash(Default::default()))
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::TransactionHash(transaction_hash) => {
+                let mut args = transaction_hash.to_cli_args();
+                args.push_front("transaction-hash".to_owned());
+                args
+            }
+        }
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
@@ -77,12 +87,19 @@ impl From<CliTransactionType> for TransactionType {
 impl TransactionType {
     fn input_transaction_hash() -> String {
         println!();
+        crate::common::ensure_interactive("<transaction-hash>");
         Input::new()
             .with_prompt("Enter the hash of the transaction you need to view")
             .interact_text()
             .unwrap()
     }
 
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.send_from.to_cli_args();
+        args.push_front(self.transaction_hash.to_string());
+        args
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
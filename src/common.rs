@@ -1,8 +1,214 @@
 use std::convert::TryInto;
-use std::io::Write;
 
 use near_primitives::borsh::BorshDeserialize;
 
+static INTERACTIVE_MODE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
+
+pub fn set_interactive_mode(is_interactive: bool) {
+    INTERACTIVE_MODE.store(is_interactive, std::sync::atomic::Ordering::SeqCst);
+}
+
+pub fn is_interactive_mode() -> bool {
+    INTERACTIVE_MODE.load(std::sync::atomic::Ordering::SeqCst)
+}
+
+static WAS_PROMPTED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Whether the user has been asked for at least one argument during this run
+pub fn was_prompted() -> bool {
+    WAS_PROMPTED.load(std::sync::atomic::Ordering::SeqCst)
+}
+
+/// Panic payload used to abort the command instead of prompting for a missing argument.
+/// It is converted into [`MissingArgumentError`] in the main function.
+#[derive(Debug, Clone)]
+pub struct MissingArgument {
+    pub argument: &'static str,
+}
+
+/// Every prompt calls this first, so in the non-interactive mode a missing argument
+/// stops the command instead of blocking on stdin.
+pub fn ensure_interactive(argument: &'static str) {
+    if !is_interactive_mode() {
+        std::panic::panic_any(MissingArgument { argument });
+    }
+    WAS_PROMPTED.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Joins the arguments into a single command line, quoting the ones a shell would split or expand
+pub fn to_shell_command(args: impl IntoIterator<Item = String>) -> String {
+    args.into_iter()
+        .map(|arg| {
+            if !arg.is_empty()
+                && arg
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_.,:/=@+%".contains(c))
+            {
+                arg
+            } else {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Printed in the console command instead of a private key or a seed phrase, so that the secret
+/// does not end up in the terminal scrollback, CI logs or the shell history
+pub const SECRET_PLACEHOLDER: &str = "<SECRET>";
+
+#[derive(Debug, Clone)]
+pub struct MissingArgumentError {
+    pub argument: &'static str,
+    pub subcommand_path: String,
+}
+
+impl std::fmt::Display for MissingArgumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The required argument {} was not provided for `{}` (interactive mode is disabled)",
+            self.argument, self.subcommand_path
+        )
+    }
+}
+
+impl std::error::Error for MissingArgumentError {}
+
+/// The failures a script may want to tell apart. Each kind has its own exit code:
+///
+/// | Exit code | Error |
+/// |-----------|-------|
+/// | 1 | any other error |
+/// | 2 | `UserInput`, including a missing argument in the non-interactive mode |
+/// | 3 | `Rpc`: the RPC server is unreachable or failed to answer |
+/// | 4 | `InvalidTransaction`: the chain rejected the transaction |
+/// | 5 | `ActionFailed`: the transaction was executed, but one of its actions failed |
+/// | 6 | `Keychain` |
+/// | 7 | `DryRunFailed`: `--dry-run` found that the transaction would fail |
+/// | 8 | `CodeHashMismatch`: a contract code does not hash to the expected code hash |
+#[derive(Debug, Clone, strum_macros::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum CliError {
+    Rpc(String),
+    InvalidTransaction(near_primitives::errors::InvalidTxError),
+    ActionFailed(near_primitives::errors::ActionError),
+    /// An access key is missing in the keychain, or it cannot be read or saved
+    Keychain(String),
+    UserInput(String),
+    DryRunFailed(String),
+    CodeHashMismatch(String),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::UserInput(_) => 2,
+            Self::Rpc(_) => 3,
+            Self::InvalidTransaction(_) => 4,
+            Self::ActionFailed(_) => 5,
+            Self::Keychain(_) => 6,
+            Self::DryRunFailed(_) => 7,
+            Self::CodeHashMismatch(_) => 8,
+        }
+    }
+
+    pub fn tx_execution_error(&self) -> Option<near_primitives::errors::TxExecutionError> {
+        match self {
+            Self::InvalidTransaction(invalid_tx_error) => Some(
+                near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error.clone()),
+            ),
+            Self::ActionFailed(action_error) => Some(
+                near_primitives::errors::TxExecutionError::ActionError(action_error.clone()),
+            ),
+            Self::Rpc(_)
+            | Self::Keychain(_)
+            | Self::UserInput(_)
+            | Self::DryRunFailed(_)
+            | Self::CodeHashMismatch(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rpc(message)
+            | Self::Keychain(message)
+            | Self::UserInput(message)
+            | Self::DryRunFailed(message)
+            | Self::CodeHashMismatch(message) => write!(f, "{}", message),
+            Self::InvalidTransaction(_) | Self::ActionFailed(_) => write!(
+                f,
+                "{}",
+                transaction_error_message(
+                    self.tx_execution_error()
+                        .expect("Transaction errors always have TxExecutionError")
+                )
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<near_primitives::errors::TxExecutionError> for CliError {
+    fn from(tx_execution_error: near_primitives::errors::TxExecutionError) -> Self {
+        match tx_execution_error {
+            near_primitives::errors::TxExecutionError::ActionError(action_error) => {
+                Self::ActionFailed(action_error)
+            }
+            near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error) => {
+                Self::InvalidTransaction(invalid_tx_error)
+            }
+        }
+    }
+}
+
+impl From<near_jsonrpc_primitives::errors::RpcError> for CliError {
+    /// A transaction rejected by the chain is reported by the RPC as an error
+    fn from(rpc_error: near_jsonrpc_primitives::errors::RpcError) -> Self {
+        if let Some(data) = &rpc_error.data {
+            if let Ok(near_jsonrpc_primitives::errors::ServerError::TxExecutionError(
+                tx_execution_error,
+            )) = serde_json::from_value(data.clone())
+            {
+                return Self::from(tx_execution_error);
+            }
+        }
+        Self::Rpc(format!("Error transaction: {:?}", rpc_error))
+    }
+}
+
+/// Whether a query failed because the account or the access key does not exist at the block
+pub fn is_unknown_account_or_access_key(
+    rpc_error: &near_jsonrpc_primitives::errors::RpcError,
+) -> bool {
+    match &rpc_error.error_struct {
+        Some(near_jsonrpc_primitives::errors::RpcErrorKind::HandlerError(handler_error)) => {
+            matches!(
+                serde_json::from_value(handler_error.clone()),
+                Ok(near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. })
+                    | Ok(
+                        near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey { .. }
+                    )
+            )
+        }
+        _ => false,
+    }
+}
+
+/// The exit code for an error returned by a command (see [`CliError`])
+pub fn exit_code(report: &color_eyre::Report) -> i32 {
+    if let Some(cli_error) = report.downcast_ref::<CliError>() {
+        cli_error.exit_code()
+    } else if report.downcast_ref::<MissingArgumentError>().is_some() {
+        2
+    } else {
+        1
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -18,6 +224,74 @@ pub enum OutputFormat {
     Json,
 }
 
+static JSON_OUTPUT_FORMAT: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+pub fn set_output_format(output_format: OutputFormat) {
+    JSON_OUTPUT_FORMAT.store(
+        matches!(output_format, OutputFormat::Json),
+        std::sync::atomic::Ordering::SeqCst,
+    );
+}
+
+pub fn output_format() -> OutputFormat {
+    if JSON_OUTPUT_FORMAT.load(std::sync::atomic::Ordering::SeqCst) {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Plaintext
+    }
+}
+
+thread_local! {
+    /// The fields of the document printed by `--format json` once the command is finished
+    static JSON_OUTPUT: std::cell::RefCell<serde_json::Map<String, serde_json::Value>> =
+        std::cell::RefCell::new(serde_json::Map::new());
+}
+
+/// Sets a field of the JSON document (it is only printed with `--format json`)
+pub fn set_json_output(key: &str, value: impl serde::Serialize) {
+    let value = serde_json::to_value(value).expect("JSON output is not expected to fail");
+    JSON_OUTPUT.with(|json_output| json_output.borrow_mut().insert(key.to_owned(), value));
+}
+
+/// Prints the single JSON document of the command:
+/// `{"status": "success", ...}` or `{"status": "error", "error": {"message": ..., "exit_code": ...}, ...}`
+pub fn print_json_output(result: &crate::CliResult) {
+    if let Err(report) = result {
+        let mut error = serde_json::json!({
+            "message": format!("{}", report),
+            "exit_code": exit_code(report),
+        });
+        if let Some(cli_error) = report.downcast_ref::<CliError>() {
+            error["kind"] = serde_json::json!(<&str>::from(cli_error));
+            if let Some(tx_execution_error) = cli_error.tx_execution_error() {
+                error["tx_execution_error"] = serde_json::json!(tx_execution_error);
+            }
+        }
+        set_json_output("error", error);
+    }
+    let mut document =
+        JSON_OUTPUT.with(|json_output| std::mem::take(&mut *json_output.borrow_mut()));
+    let status = match result {
+        Ok(()) => "success",
+        Err(_) => "error",
+    };
+    document.insert("status".to_owned(), serde_json::json!(status));
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&document).expect("JSON output is not expected to fail")
+    );
+}
+
+/// A progress message for humans; with `--format json` it goes to stderr so that stdout
+/// carries nothing but the JSON document
+pub fn print_message(message: &str) {
+    match output_format() {
+        OutputFormat::Plaintext => println!("{}", message),
+        OutputFormat::Json => eprintln!("{}", message),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionAsBase64 {
     pub inner: near_primitives::transaction::Transaction,
@@ -36,6 +310,16 @@ impl std::str::FromStr for TransactionAsBase64 {
     }
 }
 
+impl TransactionAsBase64 {
+    /// The value in the form accepted on the command line
+    pub fn to_cli_arg(&self) -> String {
+        near_primitives::serialize::to_base64(
+            near_primitives::borsh::BorshSerialize::try_to_vec(&self.inner)
+                .expect("Transaction is not expected to fail on serialization"),
+        )
+    }
+}
+
 impl std::fmt::Display for TransactionAsBase64 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Transaction {}", self.inner.get_hash_and_size().0)
@@ -69,6 +353,8 @@ impl std::fmt::Display for BlockHashAsBase58 {
 #[derive(Debug, Clone, PartialEq)]
 pub struct AvailableRpcServerUrl {
     pub inner: url::Url,
+    /// The status of the server, fetched to check that it is available
+    pub status: crate::network::NetworkStatus,
 }
 
 impl std::str::FromStr for AvailableRpcServerUrl {
@@ -76,14 +362,17 @@ impl std::str::FromStr for AvailableRpcServerUrl {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let url: url::Url =
             url::Url::parse(s).map_err(|err| format!("URL is not parsed: {}", err))?;
-        actix::System::new()
+        let status = actix::System::new()
             .block_on(async {
                 near_jsonrpc_client::new_client(&url.as_str())
                     .status()
                     .await
             })
             .map_err(|err| format!("AvailableRpcServerUrl: {:?}", err))?;
-        Ok(Self { inner: url })
+        Ok(Self {
+            inner: url,
+            status: status.into(),
+        })
     }
 }
 
@@ -108,6 +397,21 @@ impl NearBalance {
     pub fn to_yoctonear(&self) -> u128 {
         self.yoctonear_amount
     }
+
+    /// The exact amount in the form accepted on the command line (`Display` rounds it)
+    pub fn to_cli_arg(&self) -> String {
+        let fractional_part = self.yoctonear_amount % ONE_NEAR;
+        if fractional_part == 0 {
+            format!("{}NEAR", self.yoctonear_amount / ONE_NEAR)
+        } else {
+            let fractional_part = format!("{:0>24}", fractional_part);
+            format!(
+                "{}.{}NEAR",
+                self.yoctonear_amount / ONE_NEAR,
+                fractional_part.trim_end_matches('0')
+            )
+        }
+    }
 }
 
 impl std::fmt::Display for NearBalance {
@@ -224,6 +528,21 @@ impl std::str::FromStr for NearGas {
 }
 
 impl NearGas {
+    /// The exact amount in the form accepted on the command line (`Display` rounds it)
+    pub fn to_cli_arg(&self) -> String {
+        let fractional_part = self.inner % ONE_TERA_GAS;
+        if fractional_part == 0 {
+            format!("{}TGas", self.inner / ONE_TERA_GAS)
+        } else {
+            let fractional_part = format!("{:0>12}", fractional_part);
+            format!(
+                "{}.{}TGas",
+                self.inner / ONE_TERA_GAS,
+                fractional_part.trim_end_matches('0')
+            )
+        }
+    }
+
     fn into_tera_gas(num: &str) -> Result<u64, String> {
         let res_split: Vec<&str> = num.split('.').collect();
         match res_split.len() {
@@ -262,16 +581,41 @@ pub enum ConnectionConfig {
     Testnet,
     Mainnet,
     Betanet,
-    Custom { url: url::Url },
+    /// A network given by its URLs on the command line
+    Custom(crate::config::NetworkProfile),
+    /// A network defined in the config file
+    Network(crate::config::NetworkProfile),
 }
 
 impl ConnectionConfig {
+    /// Keys of a custom network are kept apart from other networks in a directory named after its RPC host
+    pub fn custom(
+        rpc_url: url::Url,
+        archival_rpc_url: Option<url::Url>,
+        wallet_url: Option<url::Url>,
+        explorer_url: Option<url::Url>,
+    ) -> Self {
+        let name = match (rpc_url.host_str(), rpc_url.port()) {
+            (Some(host), Some(port)) => format!("{}_{}", host, port),
+            (Some(host), None) => host.to_owned(),
+            (None, _) => "custom".to_owned(),
+        };
+        Self::Custom(crate::config::NetworkProfile {
+            keychain_dir: format!("{}{}/", crate::consts::DIR_NAME_CUSTOM, name),
+            name,
+            rpc_url,
+            archival_rpc_url,
+            wallet_url,
+            explorer_url,
+        })
+    }
+
     pub fn rpc_url(&self) -> url::Url {
         match self {
             Self::Testnet => crate::consts::TESTNET_API_SERVER_URL.parse().unwrap(),
             Self::Mainnet => crate::consts::MAINNET_API_SERVER_URL.parse().unwrap(),
             Self::Betanet => crate::consts::BETANET_API_SERVER_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Custom(network) | Self::Network(network) => network.rpc_url.clone(),
         }
     }
 
@@ -286,25 +630,31 @@ impl ConnectionConfig {
             Self::Betanet => crate::consts::BETANET_ARCHIVAL_API_SERVER_URL
                 .parse()
                 .unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Custom(network) | Self::Network(network) => network
+                .archival_rpc_url
+                .clone()
+                .unwrap_or_else(|| network.rpc_url.clone()),
         }
     }
 
-    pub fn wallet_url(&self) -> url::Url {
+    pub fn wallet_url(&self) -> Option<url::Url> {
         match self {
-            Self::Testnet => crate::consts::TESTNET_WALLET_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_WALLET_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_WALLET_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Testnet => Some(crate::consts::TESTNET_WALLET_URL.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_WALLET_URL.parse().unwrap()),
+            Self::Betanet => Some(crate::consts::BETANET_WALLET_URL.parse().unwrap()),
+            Self::Custom(network) | Self::Network(network) => network.wallet_url.clone(),
         }
     }
 
-    pub fn transaction_explorer(&self) -> url::Url {
+    pub fn transaction_explorer(&self) -> Option<url::Url> {
         match self {
-            Self::Testnet => crate::consts::TESTNET_TRANSACTION_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_TRANSACTION_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_TRANSACTION_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Testnet => Some(crate::consts::TESTNET_TRANSACTION_URL.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_TRANSACTION_URL.parse().unwrap()),
+            Self::Betanet => Some(crate::consts::BETANET_TRANSACTION_URL.parse().unwrap()),
+            Self::Custom(network) | Self::Network(network) => network
+                .explorer_url
+                .as_ref()
+                .and_then(|explorer_url| explorer_url.join("transactions/").ok()),
         }
     }
 
@@ -313,9 +663,30 @@ impl ConnectionConfig {
             Self::Testnet => crate::consts::DIR_NAME_TESTNET,
             Self::Mainnet => crate::consts::DIR_NAME_MAINNET,
             Self::Betanet => crate::consts::DIR_NAME_BETANET,
-            Self::Custom { url: _ } => crate::consts::DIR_NAME_CUSTOM,
+            Self::Custom(network) | Self::Network(network) => &network.keychain_dir,
         }
     }
+
+    /// The `custom` server flags; the other networks are selected by their subcommand name
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Self::Custom(network) = self {
+            args.push_back("--url".to_owned());
+            args.push_back(network.rpc_url.to_string());
+            let optional_urls = [
+                ("--archival-url", &network.archival_rpc_url),
+                ("--wallet-url", &network.wallet_url),
+                ("--explorer-url", &network.explorer_url),
+            ];
+            for (flag, url) in optional_urls.iter() {
+                if let Some(url) = url {
+                    args.push_back(flag.to_string());
+                    args.push_back(url.to_string());
+                }
+            }
+        }
+        args
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -342,18 +713,39 @@ pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
             let master_seed_phrase = mnemonic.word_iter().collect::<Vec<&str>>().join(" ");
             (master_seed_phrase, mnemonic.to_seed(""))
         };
+    key_pair_properties_from_seed(
+        master_seed_phrase,
+        &master_seed,
+        generate_keypair.seed_phrase_hd_path,
+    )
+}
 
-    let derived_private_key = slip10::derive_key_from_path(
+/// The key pair derived from the master seed phrase along `seed_phrase_hd_path`
+pub fn key_pair_properties_from_seed_phrase(
+    master_seed_phrase: &str,
+    seed_phrase_hd_path: slip10::BIP32Path,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let master_seed = bip39::Mnemonic::parse(master_seed_phrase)?.to_seed("");
+    key_pair_properties_from_seed(
+        master_seed_phrase.to_owned(),
         &master_seed,
-        slip10::Curve::Ed25519,
-        &generate_keypair.seed_phrase_hd_path,
+        seed_phrase_hd_path,
     )
-    .map_err(|err| {
-        color_eyre::Report::msg(format!(
-            "Failed to derive a key from the master key: {}",
-            err
-        ))
-    })?;
+}
+
+fn key_pair_properties_from_seed(
+    master_seed_phrase: String,
+    master_seed: &[u8],
+    seed_phrase_hd_path: slip10::BIP32Path,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let derived_private_key =
+        slip10::derive_key_from_path(master_seed, slip10::Curve::Ed25519, &seed_phrase_hd_path)
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to derive a key from the master key: {}",
+                    err
+                ))
+            })?;
 
     let secret_keypair = {
         let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key)?;
@@ -371,7 +763,7 @@ pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
         bs58::encode(secret_keypair.to_bytes()).into_string()
     );
     let key_pair_properties: KeyPairProperties = KeyPairProperties {
-        seed_phrase_hd_path: generate_keypair.seed_phrase_hd_path,
+        seed_phrase_hd_path,
         master_seed_phrase,
         implicit_account_id,
         public_key_str,
@@ -381,6 +773,24 @@ pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
 }
 
 pub fn print_transaction(transaction: near_primitives::transaction::Transaction) {
+    if let OutputFormat::Json = output_format() {
+        set_json_output(
+            "transaction",
+            serde_json::json!({
+                "signer_id": transaction.signer_id,
+                "public_key": transaction.public_key,
+                "nonce": transaction.nonce,
+                "receiver_id": transaction.receiver_id,
+                "block_hash": transaction.block_hash,
+                "actions": transaction
+                    .actions
+                    .into_iter()
+                    .map(near_primitives::views::ActionView::from)
+                    .collect::<Vec<_>>(),
+            }),
+        );
+        return;
+    }
     println!("{:<13} {}", "signer_id:", &transaction.signer_id);
     println!("{:<13} {}", "public_key:", &transaction.public_key);
     println!("{:<13} {}", "nonce:", &transaction.nonce);
@@ -481,9 +891,24 @@ pub fn print_transaction(transaction: near_primitives::transaction::Transaction)
     }
 }
 
+pub fn print_signed_transaction(
+    signed_transaction: near_primitives::transaction::SignedTransaction,
+) {
+    print_transaction(signed_transaction.transaction);
+    match output_format() {
+        OutputFormat::Plaintext => {
+            println!("{:<13} {}", "signature:", signed_transaction.signature)
+        }
+        OutputFormat::Json => set_json_output("signature", signed_transaction.signature),
+    }
+}
+
 async fn print_value_successful_transaction(
     transaction_info: near_primitives::views::FinalExecutionOutcomeView,
 ) {
+    if let OutputFormat::Json = output_format() {
+        return;
+    }
     println!("Successful transaction");
     for action in transaction_info.transaction.actions {
         match action {
@@ -550,72 +975,191 @@ async fn print_value_successful_transaction(
             }
         }
     }
+    print_receipt_tree(&transaction_info);
 }
 
-pub async fn print_transaction_error(
-    tx_execution_error: near_primitives::errors::TxExecutionError,
+/// Prints the receipts spawned by the transaction as a tree with their executors, the gas and
+/// tokens burnt, the status and the logs, followed by the totals
+pub fn print_receipt_tree(transaction_info: &near_primitives::views::FinalExecutionOutcomeView) {
+    let transaction_outcome = &transaction_info.transaction_outcome;
+    println!(
+        "Transaction {} signed by <{}>",
+        transaction_outcome.id, transaction_info.transaction.signer_id
+    );
+    print_outcome_details(&transaction_outcome.outcome, "  ");
+    for (index, receipt_id) in transaction_outcome.outcome.receipt_ids.iter().enumerate() {
+        let is_last = index + 1 == transaction_outcome.outcome.receipt_ids.len();
+        print_receipt_subtree(transaction_info, receipt_id, "", is_last);
+    }
+    let (total_gas_burnt, total_tokens_burnt) = total_burnt(transaction_info);
+    println!(
+        "Total gas burnt: {}\nTotal fee burnt: {}\n",
+        NearGas {
+            inner: total_gas_burnt
+        },
+        NearBalance::from_yoctonear(total_tokens_burnt)
+    );
+}
+
+fn print_receipt_subtree(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    receipt_id: &near_primitives::hash::CryptoHash,
+    prefix: &str,
+    is_last: bool,
 ) {
-    println!("Failed transaction");
+    let (branch, child_prefix) = if is_last {
+        ("└─ ", format!("{}   ", prefix))
+    } else {
+        ("├─ ", format!("{}│  ", prefix))
+    };
+    let receipt_outcome = match transaction_info
+        .receipts_outcome
+        .iter()
+        .find(|receipt_outcome| &receipt_outcome.id == receipt_id)
+    {
+        Some(receipt_outcome) => receipt_outcome,
+        None => {
+            println!(
+                "{}{}Receipt {} (no outcome yet)",
+                prefix, branch, receipt_id
+            );
+            return;
+        }
+    };
+    println!(
+        "{}{}Receipt {} executed by <{}>",
+        prefix, branch, receipt_id, receipt_outcome.outcome.executor_id
+    );
+    print_outcome_details(&receipt_outcome.outcome, &format!("{}   ", child_prefix));
+    for (index, child_receipt_id) in receipt_outcome.outcome.receipt_ids.iter().enumerate() {
+        let is_last = index + 1 == receipt_outcome.outcome.receipt_ids.len();
+        print_receipt_subtree(transaction_info, child_receipt_id, &child_prefix, is_last);
+    }
+}
+
+fn print_outcome_details(outcome: &near_primitives::views::ExecutionOutcomeView, prefix: &str) {
+    let status = match &outcome.status {
+        near_primitives::views::ExecutionStatusView::Unknown => "Unknown".to_string(),
+        near_primitives::views::ExecutionStatusView::Failure(tx_execution_error) => format!(
+            "Failure: {}",
+            transaction_error_message(tx_execution_error.clone())
+        ),
+        near_primitives::views::ExecutionStatusView::SuccessValue(value) => {
+            format!("Success: {}", decode_success_value(value))
+        }
+        near_primitives::views::ExecutionStatusView::SuccessReceiptId(receipt_id) => {
+            format!("Success: the result comes from the receipt {}", receipt_id)
+        }
+    };
+    println!("{}Status: {}", prefix, status);
+    println!(
+        "{}Gas burnt: {}, tokens burnt: {}",
+        prefix,
+        NearGas {
+            inner: outcome.gas_burnt
+        },
+        NearBalance::from_yoctonear(outcome.tokens_burnt)
+    );
+    for log in &outcome.logs {
+        println!("{}Log: {}", prefix, log);
+    }
+}
+
+/// The base64 value returned by a function call as JSON or as a UTF-8 string if possible
+pub fn decode_success_value(value: &str) -> String {
+    let bytes = match near_primitives::serialize::from_base64(value) {
+        Ok(bytes) => bytes,
+        Err(_) => return format!("base64:{}", value),
+    };
+    if bytes.is_empty() {
+        return "(no value)".to_string();
+    }
+    if let Ok(json_value) = serde_json::from_slice::<serde_json::Value>(&bytes) {
+        return json_value.to_string();
+    }
+    match String::from_utf8(bytes) {
+        Ok(string) => format!("{:?}", string),
+        Err(_) => format!("base64:{}", value),
+    }
+}
+
+/// The gas and the tokens burnt by the transaction and all its receipts
+pub fn total_burnt(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+) -> (near_primitives::types::Gas, near_primitives::types::Balance) {
+    std::iter::once(&transaction_info.transaction_outcome)
+        .chain(transaction_info.receipts_outcome.iter())
+        .fold((0, 0), |(gas_burnt, tokens_burnt), outcome| {
+            (
+                gas_burnt + outcome.outcome.gas_burnt,
+                tokens_burnt + outcome.outcome.tokens_burnt,
+            )
+        })
+}
+
+fn transaction_error_message(
+    tx_execution_error: near_primitives::errors::TxExecutionError,
+) -> String {
     match tx_execution_error {
         near_primitives::errors::TxExecutionError::ActionError(action_error) => {
             match action_error.kind {
                 near_primitives::errors::ActionErrorKind::AccountAlreadyExists{account_id} => {
-                    println!("Error: Create Account action tries to create an account with account ID <{}> which is already exists in the storage.", account_id)
+                    format!("Create Account action tries to create an account with account ID <{}> which is already exists in the storage.", account_id)
                 },
                 near_primitives::errors::ActionErrorKind::AccountDoesNotExist{account_id} => {
-                    println!("Error: TX receiver ID <{}> doesn't exist (but action is not \"Create Account\").", account_id)
+                    format!("TX receiver ID <{}> doesn't exist (but action is not \"Create Account\").", account_id)
                 },
                 near_primitives::errors::ActionErrorKind::CreateAccountOnlyByRegistrar{account_id:_, registrar_account_id:_, predecessor_id:_} => {
-                    println!("Error: A top-level account ID can only be created by registrar.")
+                    format!("A top-level account ID can only be created by registrar.")
                 },
                 near_primitives::errors::ActionErrorKind::CreateAccountNotAllowed{account_id, predecessor_id} => {
-                    println!("Error: A newly created account <{}> must be under a namespace of the creator account <{}>.", account_id, predecessor_id)
+                    format!("A newly created account <{}> must be under a namespace of the creator account <{}>.", account_id, predecessor_id)
                 },
                 near_primitives::errors::ActionErrorKind::ActorNoPermission{account_id:_, actor_id:_} => {
-                    println!("Error: Administrative actions can be proceed only if sender=receiver or the first TX action is a \"Create Account\" action.")
+                    format!("Administrative actions can be proceed only if sender=receiver or the first TX action is a \"Create Account\" action.")
                 },
                 near_primitives::errors::ActionErrorKind::DeleteKeyDoesNotExist{account_id, public_key} => {
-                    println!("Error: Account <{}>  tries to remove an access key <{}> that doesn't exist.", account_id, public_key)
+                    format!("Account <{}>  tries to remove an access key <{}> that doesn't exist.", account_id, public_key)
                 },
                 near_primitives::errors::ActionErrorKind::AddKeyAlreadyExists{account_id, public_key} => {
-                    println!("Error: Public key <{}> is already used for an existing account ID <{}>.", public_key, account_id)
+                    format!("Public key <{}> is already used for an existing account ID <{}>.", public_key, account_id)
                 },
                 near_primitives::errors::ActionErrorKind::DeleteAccountStaking{account_id} => {
-                    println!("Error: Account <{}> is staking and can not be deleted", account_id)
+                    format!("Account <{}> is staking and can not be deleted", account_id)
                 },
                 near_primitives::errors::ActionErrorKind::LackBalanceForState{account_id, amount} => {
-                    println!("Error: Receipt action can't be completed, because the remaining balance will not be enough to cover storage.\nAn account which needs balance: <{}>\nBalance required to complete an action: <{}>",
+                    format!("Receipt action can't be completed, because the remaining balance will not be enough to cover storage.\nAn account which needs balance: <{}>\nBalance required to complete an action: <{}>",
                         account_id,
                         crate::common::NearBalance::from_yoctonear(amount)
                     )
                 },
                 near_primitives::errors::ActionErrorKind::TriesToUnstake{account_id} => {
-                    println!("Error: Account <{}> is not yet staked, but tries to unstake.", account_id)
+                    format!("Account <{}> is not yet staked, but tries to unstake.", account_id)
                 },
                 near_primitives::errors::ActionErrorKind::TriesToStake{account_id, stake, locked:_, balance} => {
-                    println!("Error: Account <{}> doesn't have enough balance ({}) to increase the stake ({}).",
+                    format!("Account <{}> doesn't have enough balance ({}) to increase the stake ({}).",
                     account_id,
                     crate::common::NearBalance::from_yoctonear(balance),
                     crate::common::NearBalance::from_yoctonear(stake)
                     )
                 },
                 near_primitives::errors::ActionErrorKind::InsufficientStake{account_id:_, stake, minimum_stake} => {
-                    println!("Error: Insufficient stake {}.\nThe minimum rate must be {}.",
+                    format!("Insufficient stake {}.\nThe minimum rate must be {}.",
                         crate::common::NearBalance::from_yoctonear(stake),
                         crate::common::NearBalance::from_yoctonear(minimum_stake)
                     )
                 },
                 near_primitives::errors::ActionErrorKind::FunctionCallError(function_call_error_ser) => {
-                    println!("Error: An error occurred during a `FunctionCall` Action, parameter is debug message.\n{:?}", function_call_error_ser)
+                    format!("An error occurred during a `FunctionCall` Action, parameter is debug message.\n{:?}", function_call_error_ser)
                 },
                 near_primitives::errors::ActionErrorKind::NewReceiptValidationError(receipt_validation_error) => {
-                    println!("Error: Error occurs when a new `ActionReceipt` created by the `FunctionCall` action fails.\n{:?}", receipt_validation_error)
+                    format!("Error occurs when a new `ActionReceipt` created by the `FunctionCall` action fails.\n{:?}", receipt_validation_error)
                 },
                 near_primitives::errors::ActionErrorKind::OnlyImplicitAccountCreationAllowed{account_id:_} => {
-                    println!("Error: Error occurs when a `CreateAccount` action is called on hex-characters account of length 64.\nSee implicit account creation NEP: https://github.com/nearprotocol/NEPs/pull/71")
+                    format!("Error occurs when a `CreateAccount` action is called on hex-characters account of length 64.\nSee implicit account creation NEP: https://github.com/nearprotocol/NEPs/pull/71")
                 },
                 near_primitives::errors::ActionErrorKind::DeleteAccountWithLargeState{account_id} => {
-                    println!("Error: Delete account <{}> whose state is large is temporarily banned.", account_id)
+                    format!("Delete account <{}> whose state is large is temporarily banned.", account_id)
                 },
             }
         },
@@ -624,19 +1168,19 @@ pub async fn print_transaction_error(
                 near_primitives::errors::InvalidTxError::InvalidAccessKeyError(invalid_access_key_error) => {
                     match invalid_access_key_error {
                         near_primitives::errors::InvalidAccessKeyError::AccessKeyNotFound{account_id, public_key} => {
-                            println!("Error: Public key {} doesn't exist for the account <{}>.", public_key, account_id)
+                            format!("Public key {} doesn't exist for the account <{}>.", public_key, account_id)
                         },
                         near_primitives::errors::InvalidAccessKeyError::ReceiverMismatch{tx_receiver, ak_receiver} => {
-                            println!("Error: Transaction for <{}> doesn't match the access key for <{}>.", tx_receiver, ak_receiver)
+                            format!("Transaction for <{}> doesn't match the access key for <{}>.", tx_receiver, ak_receiver)
                         },
                         near_primitives::errors::InvalidAccessKeyError::MethodNameMismatch{method_name} => {
-                            println!("Error: Transaction method name <{}> isn't allowed by the access key.", method_name)
+                            format!("Transaction method name <{}> isn't allowed by the access key.", method_name)
                         },
                         near_primitives::errors::InvalidAccessKeyError::RequiresFullAccess => {
-                            println!("Error: Transaction requires a full permission access key.")
+                            format!("Transaction requires a full permission access key.")
                         },
                         near_primitives::errors::InvalidAccessKeyError::NotEnoughAllowance{account_id, public_key, allowance, cost} => {
-                            println!("Error: Access Key <{}> for account <{}> does not have enough allowance ({}) to cover transaction cost ({}).",
+                            format!("Access Key <{}> for account <{}> does not have enough allowance ({}) to cover transaction cost ({}).",
                                 public_key,
                                 account_id,
                                 crate::common::NearBalance::from_yoctonear(allowance),
@@ -644,90 +1188,90 @@ pub async fn print_transaction_error(
                             )
                         },
                         near_primitives::errors::InvalidAccessKeyError::DepositWithFunctionCall => {
-                            println!("Error: Having a deposit with a function call action is not allowed with a function call access key.")
+                            format!("Having a deposit with a function call action is not allowed with a function call access key.")
                         }
                     }
                 },
                 near_primitives::errors::InvalidTxError::InvalidSignerId { signer_id } => {
-                    println!("Error: TX signer ID <{}> is not in a valid format or not satisfy requirements see \"near_runtime_utils::utils::is_valid_account_id\".", signer_id)
+                    format!("TX signer ID <{}> is not in a valid format or not satisfy requirements see \"near_runtime_utils::utils::is_valid_account_id\".", signer_id)
                 },
                 near_primitives::errors::InvalidTxError::SignerDoesNotExist { signer_id } => {
-                    println!("Error: TX signer ID <{}> is not found in a storage.", signer_id)
+                    format!("TX signer ID <{}> is not found in a storage.", signer_id)
                 },
                 near_primitives::errors::InvalidTxError::InvalidNonce { tx_nonce, ak_nonce } => {
-                    println!("Error: Transaction nonce ({}) must be account[access_key].nonce ({}) + 1.", tx_nonce, ak_nonce)
+                    format!("Transaction nonce ({}) must be account[access_key].nonce ({}) + 1.", tx_nonce, ak_nonce)
                 },
                 near_primitives::errors::InvalidTxError::NonceTooLarge { tx_nonce, upper_bound } => {
-                    println!("Error: Transaction nonce ({}) is larger than the upper bound ({}) given by the block height.", tx_nonce, upper_bound)
+                    format!("Transaction nonce ({}) is larger than the upper bound ({}) given by the block height.", tx_nonce, upper_bound)
                 },
                 near_primitives::errors::InvalidTxError::InvalidReceiverId { receiver_id } => {
-                    println!("Error: TX receiver ID ({}) is not in a valid format or not satisfy requirements see \"near_runtime_utils::is_valid_account_id\".", receiver_id)
+                    format!("TX receiver ID ({}) is not in a valid format or not satisfy requirements see \"near_runtime_utils::is_valid_account_id\".", receiver_id)
                 },
                 near_primitives::errors::InvalidTxError::InvalidSignature => {
-                    println!("Error: TX signature is not valid")
+                    format!("TX signature is not valid")
                 },
                 near_primitives::errors::InvalidTxError::NotEnoughBalance {signer_id, balance, cost} => {
-                    println!("Error: Account <{}> does not have enough balance ({}) to cover TX cost ({}).",
+                    format!("Account <{}> does not have enough balance ({}) to cover TX cost ({}).",
                         signer_id,
                         crate::common::NearBalance::from_yoctonear(balance),
                         crate::common::NearBalance::from_yoctonear(cost)
                     )
                 },
                 near_primitives::errors::InvalidTxError::LackBalanceForState {signer_id, amount} => {
-                    println!("Error: Signer account <{}> doesn't have enough balance ({}) after transaction.",
+                    format!("Signer account <{}> doesn't have enough balance ({}) after transaction.",
                         signer_id,
                         crate::common::NearBalance::from_yoctonear(amount)
                     )
                 },
                 near_primitives::errors::InvalidTxError::CostOverflow => {
-                    println!("Error: An integer overflow occurred during transaction cost estimation.")
+                    format!("An integer overflow occurred during transaction cost estimation.")
                 },
                 near_primitives::errors::InvalidTxError::InvalidChain => {
-                    println!("Error: Transaction parent block hash doesn't belong to the current chain.")
+                    format!("Transaction parent block hash doesn't belong to the current chain.")
                 },
                 near_primitives::errors::InvalidTxError::Expired => {
-                    println!("Error: Transaction has expired.")
+                    format!("Transaction has expired.")
                 },
                 near_primitives::errors::InvalidTxError::ActionsValidation(actions_validation_error) => {
                     match actions_validation_error {
                         near_primitives::errors::ActionsValidationError::DeleteActionMustBeFinal => {
-                            println!("Error: The delete action must be a final action in transaction.")
+                            format!("The delete action must be a final action in transaction.")
                         },
                         near_primitives::errors::ActionsValidationError::TotalPrepaidGasExceeded {total_prepaid_gas, limit} => {
-                            println!("Error: The total prepaid gas ({}) for all given actions exceeded the limit ({}).",
+                            format!("The total prepaid gas ({}) for all given actions exceeded the limit ({}).",
                             total_prepaid_gas,
                             limit
                             )
                         },
                         near_primitives::errors::ActionsValidationError::TotalNumberOfActionsExceeded {total_number_of_actions, limit} => {
-                            println!("Error: The number of actions ({}) exceeded the given limit ({}).", total_number_of_actions, limit)
+                            format!("The number of actions ({}) exceeded the given limit ({}).", total_number_of_actions, limit)
                         },
                         near_primitives::errors::ActionsValidationError::AddKeyMethodNamesNumberOfBytesExceeded {total_number_of_bytes, limit} => {
-                            println!("Error: The total number of bytes ({}) of the method names exceeded the limit ({}) in a Add Key action.", total_number_of_bytes, limit)
+                            format!("The total number of bytes ({}) of the method names exceeded the limit ({}) in a Add Key action.", total_number_of_bytes, limit)
                         },
                         near_primitives::errors::ActionsValidationError::AddKeyMethodNameLengthExceeded {length, limit} => {
-                            println!("Error: The length ({}) of some method name exceeded the limit ({}) in a Add Key action.", length, limit)
+                            format!("The length ({}) of some method name exceeded the limit ({}) in a Add Key action.", length, limit)
                         },
                         near_primitives::errors::ActionsValidationError::IntegerOverflow => {
-                            println!("Error: Integer overflow during a compute.")
+                            format!("Integer overflow during a compute.")
                         },
                         near_primitives::errors::ActionsValidationError::InvalidAccountId {account_id} => {
-                            println!("Error: Invalid account ID <{}>.", account_id)
+                            format!("Invalid account ID <{}>.", account_id)
                         },
                         near_primitives::errors::ActionsValidationError::ContractSizeExceeded {size, limit} => {
-                            println!("Error: The size ({}) of the contract code exceeded the limit ({}) in a DeployContract action.", size, limit)
+                            format!("The size ({}) of the contract code exceeded the limit ({}) in a DeployContract action.", size, limit)
                         },
                         near_primitives::errors::ActionsValidationError::FunctionCallMethodNameLengthExceeded {length, limit} => {
-                            println!("Error: The length ({}) of the method name exceeded the limit ({}) in a Function Call action.", length, limit)
+                            format!("The length ({}) of the method name exceeded the limit ({}) in a Function Call action.", length, limit)
                         },
                         near_primitives::errors::ActionsValidationError::FunctionCallArgumentsLengthExceeded {length, limit} => {
-                            println!("Error: The length ({}) of the arguments exceeded the limit ({}) in a Function Call action.", length, limit)
+                            format!("The length ({}) of the arguments exceeded the limit ({}) in a Function Call action.", length, limit)
                         },
                         near_primitives::errors::ActionsValidationError::UnsuitableStakingKey {public_key} => {
-                            println!("Error: An attempt to stake with a public key <{}> that is not convertible to ristretto.", public_key)
+                            format!("An attempt to stake with a public key <{}> that is not convertible to ristretto.", public_key)
                         },
                         near_primitives::errors::ActionsValidationError::FunctionCallZeroAttachedGas => {
-                            println!("Error: The attached amount of gas in a FunctionCall action has to be a positive number.")
+                            format!("The attached amount of gas in a FunctionCall action has to be a positive number.")
                         }
                     }
                 },
@@ -739,31 +1283,64 @@ pub async fn print_transaction_error(
 pub async fn print_transaction_status(
     transaction_info: near_primitives::views::FinalExecutionOutcomeView,
     network_connection_config: Option<crate::common::ConnectionConfig>,
-) {
-    match transaction_info.status {
+) -> crate::CliResult {
+    let result = match transaction_info.status.clone() {
+        // `--wait-until included` does not wait for the execution
         near_primitives::views::FinalExecutionStatus::NotStarted
-        | near_primitives::views::FinalExecutionStatus::Started => unreachable!(),
+        | near_primitives::views::FinalExecutionStatus::Started => {
+            if let OutputFormat::Plaintext = output_format() {
+                println!("Transaction included, not yet executed");
+            }
+            Ok(())
+        }
         near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
-            print_transaction_error(tx_execution_error).await
+            if let OutputFormat::Plaintext = output_format() {
+                println!("Failed transaction");
+                print_receipt_tree(&transaction_info);
+            }
+            Err(CliError::from(tx_execution_error).into())
         }
         near_primitives::views::FinalExecutionStatus::SuccessValue(_) => {
-            print_value_successful_transaction(transaction_info.clone()).await
+            print_value_successful_transaction(transaction_info.clone()).await;
+            Ok(())
         }
     };
-    let transaction_explorer: url::Url = match network_connection_config {
+    let transaction_explorer: Option<url::Url> = match network_connection_config {
         Some(connection_config) => connection_config.transaction_explorer(),
         None => unreachable!("Error"),
     };
-    println!("Transaction ID: {id}.\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
-        id=transaction_info.transaction_outcome.id,
-        path=transaction_explorer
-    );
+    if let OutputFormat::Json = output_format() {
+        set_json_output("transaction_id", transaction_info.transaction_outcome.id);
+        let (total_gas_burnt, total_tokens_burnt) = total_burnt(&transaction_info);
+        set_json_output("total_gas_burnt", total_gas_burnt);
+        set_json_output("total_tokens_burnt", total_tokens_burnt.to_string());
+        if let Some(transaction_explorer) = transaction_explorer {
+            set_json_output(
+                "explorer_url",
+                format!(
+                    "{}{}",
+                    transaction_explorer, transaction_info.transaction_outcome.id
+                ),
+            );
+        }
+        set_json_output("transaction_outcome", transaction_info);
+        return result;
+    }
+    match transaction_explorer {
+        Some(transaction_explorer) => println!("Transaction ID: {id}.\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
+            id=transaction_info.transaction_outcome.id,
+            path=transaction_explorer
+        ),
+        None => println!("Transaction ID: {}.\n", transaction_info.transaction_outcome.id),
+    }
+    result
 }
 
 pub async fn save_access_key_to_keychain(
     network_connection_config: Option<crate::common::ConnectionConfig>,
     key_pair_properties: crate::common::KeyPairProperties,
     account_id: &str,
+    passphrase_fd: Option<i32>,
 ) -> crate::CliResult {
     let buf = format!(
         "{}",
@@ -775,54 +1352,117 @@ pub async fn save_access_key_to_keychain(
             "private_key": key_pair_properties.secret_keypair_str,
         })
     );
+    save_key_file_to_keychain(
+        network_connection_config,
+        &buf,
+        &key_pair_properties.public_key_str,
+        account_id,
+        passphrase_fd,
+    )
+}
+
+/// Saves the plaintext key file `buf` for both the key (`<account_id>/<public_key>.json`)
+/// and, unless there is one already, the account (`<account_id>.json`).
+/// `passphrase_fd` gives the passphrase of an encrypted keychain.
+pub fn save_key_file_to_keychain(
+    network_connection_config: Option<crate::common::ConnectionConfig>,
+    buf: &str,
+    public_key_str: &str,
+    account_id: &str,
+    passphrase_fd: Option<i32>,
+) -> crate::CliResult {
     let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
     let dir_name = match &network_connection_config {
         Some(connection_config) => connection_config.dir_name(),
         None => crate::consts::DIR_NAME_KEY_CHAIN,
     };
-    let file_with_key_name: std::path::PathBuf = format!(
-        "{}.json",
-        key_pair_properties.public_key_str.replace(":", "_")
-    )
-    .into();
+    let file_with_key_name: std::path::PathBuf =
+        format!("{}.json", public_key_str.replace(":", "_")).into();
     let mut path_with_key_name = std::path::PathBuf::from(&home_dir);
     path_with_key_name.push(dir_name);
     path_with_key_name.push(account_id);
     std::fs::create_dir_all(&path_with_key_name)?;
     path_with_key_name.push(file_with_key_name);
-    std::fs::File::create(&path_with_key_name)
-        .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-        .write(buf.as_bytes())
-        .map_err(|err| color_eyre::Report::msg(format!("Failed to write to file: {:?}", err)))?;
-    println!(
+    crate::keychain::write_key_file(&path_with_key_name, buf, passphrase_fd)?;
+    print_message(&format!(
         "The data for the access key is saved in a file {}",
         &path_with_key_name.display()
-    );
+    ));
+    set_json_output("account_id", account_id);
+    set_json_output("public_key", public_key_str);
+    set_json_output("file_path", path_with_key_name.display().to_string());
 
     let file_with_account_name: std::path::PathBuf = format!("{}.json", account_id).into();
     let mut path_with_account_name = std::path::PathBuf::from(&home_dir);
     path_with_account_name.push(dir_name);
     path_with_account_name.push(file_with_account_name);
     if path_with_account_name.exists() {
-        println!(
+        print_message(&format!(
             "The file: {} already exists! Therefore it was not overwritten.",
             &path_with_account_name.display()
-        );
+        ));
     } else {
-        std::fs::File::create(&path_with_account_name)
-            .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-            .write(buf.as_bytes())
-            .map_err(|err| {
-                color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
-            })?;
-        println!(
+        crate::keychain::write_key_file(&path_with_account_name, buf, passphrase_fd)?;
+        print_message(&format!(
             "The data for the access key is saved in a file {}",
             &path_with_account_name.display()
-        );
+        ));
     };
     Ok(())
 }
 
+/// Calls a view method of the contract with JSON args and parses its JSON result
+pub async fn call_view_function<T: serde::de::DeserializeOwned>(
+    rpc_url: &url::Url,
+    contract_account_id: &str,
+    method_name: &str,
+    args: serde_json::Value,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<T> {
+    let query_view_method_response = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: contract_account_id.to_owned(),
+                method_name: method_name.to_owned(),
+                args: near_primitives::types::FunctionArgs::from(args.to_string().into_bytes()),
+            },
+        })
+        .await
+        .map_err(|err| {
+            CliError::Rpc(format!(
+                "Failed to call the view method {} of <{}>: {:?}",
+                method_name, contract_account_id, err
+            ))
+        })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(call_result) =
+        query_view_method_response.kind
+    {
+        serde_json::from_slice(&call_result.result).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "The view method {} of <{}> returned an unexpected result: {}",
+                method_name, contract_account_id, err
+            ))
+        })
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+/// The protocol config at the block as returned by `EXPERIMENTAL_protocol_config`
+pub async fn protocol_config(
+    rpc_url: &url::Url,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let protocol_config = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .EXPERIMENTAL_protocol_config(
+            near_jsonrpc_primitives::types::config::RpcProtocolConfigRequest { block_reference },
+        )
+        .await
+        .map_err(|err| CliError::Rpc(format!("Failed to fetch the protocol config: {:?}", err)))?;
+    Ok(serde_json::to_value(protocol_config)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1089,4 +1729,103 @@ mod tests {
             Err("Near Gas: invalid digit found in string".to_string())
         );
     }
+
+    #[test]
+    fn near_balance_to_cli_arg_round_trip() {
+        for yoctonear_amount in &[0, 1, 10u128.pow(24), 10055000000000000000000000, 123456789] {
+            let near_balance = NearBalance::from_yoctonear(*yoctonear_amount);
+            assert_eq!(
+                NearBalance::from_str(&near_balance.to_cli_arg()).unwrap(),
+                near_balance
+            );
+        }
+        assert_eq!(
+            NearBalance::from_yoctonear(10055000000000000000000000).to_cli_arg(),
+            "10.055NEAR"
+        );
+    }
+    #[test]
+    fn near_gas_to_cli_arg_round_trip() {
+        for gas in &[0, 1, 10000000000000, 10055000000000, 123456789] {
+            let near_gas = NearGas { inner: *gas };
+            assert_eq!(NearGas::from_str(&near_gas.to_cli_arg()).unwrap(), near_gas);
+        }
+        assert_eq!(
+            NearGas {
+                inner: 30000000000000
+            }
+            .to_cli_arg(),
+            "30TGas"
+        );
+    }
+    #[test]
+    fn to_shell_command_quotes_special_arguments() {
+        assert_eq!(
+            to_shell_command(vec![
+                "near-cli".to_string(),
+                "10.055NEAR".to_string(),
+                "{\"owner_id\": \"bob.testnet\"}".to_string(),
+                "it's".to_string(),
+                "".to_string(),
+            ]),
+            "near-cli 10.055NEAR '{\"owner_id\": \"bob.testnet\"}' 'it'\\''s' ''"
+        );
+    }
+    #[test]
+    fn exit_code_depends_on_error_kind() {
+        assert_eq!(exit_code(&color_eyre::Report::msg("Error")), 1);
+        assert_eq!(
+            exit_code(
+                &MissingArgumentError {
+                    argument: "<account-id>",
+                    subcommand_path: "near-cli view account-summary".to_owned(),
+                }
+                .into()
+            ),
+            2
+        );
+        assert_eq!(exit_code(&CliError::UserInput("".to_owned()).into()), 2);
+        assert_eq!(
+            exit_code(
+                &crate::config::Config::default()
+                    .network("localnet")
+                    .unwrap_err()
+            ),
+            2
+        );
+        assert_eq!(exit_code(&CliError::Rpc("".to_owned()).into()), 3);
+        assert_eq!(
+            exit_code(
+                &CliError::from(near_primitives::errors::TxExecutionError::InvalidTxError(
+                    near_primitives::errors::InvalidTxError::Expired
+                ))
+                .into()
+            ),
+            4
+        );
+        assert_eq!(exit_code(&CliError::Keychain("".to_owned()).into()), 6);
+        assert_eq!(exit_code(&CliError::DryRunFailed("".to_owned()).into()), 7);
+        assert_eq!(
+            exit_code(&CliError::CodeHashMismatch("".to_owned()).into()),
+            8
+        );
+    }
+
+    #[test]
+    fn success_value_is_decoded_when_possible() {
+        assert_eq!(decode_success_value(""), "(no value)");
+        assert_eq!(
+            decode_success_value(&near_primitives::serialize::to_base64(b"{\"total\": 5}")),
+            "{\"total\":5}"
+        );
+        assert_eq!(
+            decode_success_value(&near_primitives::serialize::to_base64(b"hello")),
+            "\"hello\""
+        );
+        let non_utf8 = near_primitives::serialize::to_base64(&[0xffu8, 0xfe]);
+        assert_eq!(
+            decode_success_value(&non_utf8),
+            format!("base64:{}", non_utf8)
+        );
+    }
 }
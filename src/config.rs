@@ -0,0 +1,98 @@
+/// Settings stored in `~/.config/near-cli/config.toml`, e.g.:
+///
+/// ```toml
+/// [networks.localnet]
+/// rpc_url = "http://127.0.0.1:3030"
+/// wallet_url = "http://127.0.0.1:4000"
+/// explorer_url = "http://127.0.0.1:3019"
+/// keychain_dir = ".near-credentials/localnet/"
+///
+/// [keychain]
+/// encrypt = true
+/// ```
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub networks: std::collections::BTreeMap<String, NetworkProfile>,
+    #[serde(default)]
+    pub keychain: KeychainConfig,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct KeychainConfig {
+    /// Encrypt new key files with a passphrase (see `crate::keychain`)
+    #[serde(default)]
+    pub encrypt: bool,
+}
+
+/// A named network. Only `rpc_url` is required: the archival RPC falls back to it,
+/// and the keychain lives in `~/.near-credentials/<network name>/`.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct NetworkProfile {
+    #[serde(skip)]
+    pub name: String,
+    pub rpc_url: url::Url,
+    pub archival_rpc_url: Option<url::Url>,
+    pub wallet_url: Option<url::Url>,
+    pub explorer_url: Option<url::Url>,
+    /// Relative to the home directory unless it is an absolute path
+    #[serde(default)]
+    pub keychain_dir: String,
+}
+
+impl Config {
+    pub fn path() -> std::path::PathBuf {
+        let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+        path.push(crate::consts::CONFIG_FILE_PATH);
+        path
+    }
+
+    /// Reads the config file; a missing file is the same as an empty config
+    pub fn load() -> color_eyre::eyre::Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = std::fs::read_to_string(&path).map_err(|err| {
+            crate::common::CliError::UserInput(format!(
+                "Failed to read {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        let config = Self::from_toml(&data).map_err(|err| {
+            crate::common::CliError::UserInput(format!(
+                "Failed to parse {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        Ok(config)
+    }
+
+    fn from_toml(data: &str) -> Result<Self, toml::de::Error> {
+        let mut config: Self = toml::from_str(data)?;
+        for (name, network) in config.networks.iter_mut() {
+            network.name = name.clone();
+            if network.keychain_dir.is_empty() {
+                network.keychain_dir = format!(".near-credentials/{}/", name);
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn network_names(&self) -> Vec<String> {
+        self.networks.keys().cloned().collect()
+    }
+
+    pub fn network(&self, name: &str) -> color_eyre::eyre::Result<NetworkProfile> {
+        let network = self.networks.get(name).cloned().ok_or_else(|| {
+            crate::common::CliError::UserInput(format!(
+                "Network <{}> is not defined in {}",
+                name,
+                Self::path().display()
+            ))
+        })?;
+        Ok(network)
+    }
+}
@@ -18,4 +18,6 @@ pub const DIR_NAME_KEY_CHAIN: &str = ".near-credentials/default/";
 pub const DIR_NAME_TESTNET: &str = ".near-credentials/testnet/";
 pub const DIR_NAME_MAINNET: &str = ".near-credentials/mainnet/";
 pub const DIR_NAME_BETANET: &str = ".near-credentials/betanet/";
-pub const DIR_NAME_CUSTOM: &str = ".near-credentials/default/";
+pub const DIR_NAME_CUSTOM: &str = ".near-credentials/custom/";
+
+pub const CONFIG_FILE_PATH: &str = ".config/near-cli/config.toml";
@@ -0,0 +1,250 @@
+//! Reading the key-value storage of a contract: the key prefix to filter by and the way the
+//! keys and values are shown.
+
+/// How the bytes of a prefix are given as text
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    strum_macros::IntoStaticStr,
+    strum_macros::EnumString,
+    strum_macros::EnumVariantNames,
+    smart_default::SmartDefault,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Encoding {
+    #[default]
+    Utf8,
+    Base64,
+    Hex,
+}
+
+impl Encoding {
+    pub fn decode(self, text: &str) -> color_eyre::eyre::Result<Vec<u8>> {
+        let bytes = match self {
+            Self::Utf8 => Ok(text.as_bytes().to_vec()),
+            Self::Base64 => near_primitives::serialize::from_base64(text)
+                .map_err(|err| format!("Invalid base64 <{}>: {}", text, err)),
+            Self::Hex => hex::decode(text.trim_start_matches("0x"))
+                .map_err(|err| format!("Invalid hex <{}>: {}", text, err)),
+        };
+        bytes.map_err(|err| crate::common::CliError::UserInput(err).into())
+    }
+}
+
+/// How the bytes that are not printable UTF-8 are written as text
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    strum_macros::IntoStaticStr,
+    strum_macros::EnumString,
+    strum_macros::EnumVariantNames,
+    smart_default::SmartDefault,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum BinaryEncoding {
+    #[default]
+    Base64,
+    Hex,
+}
+
+impl BinaryEncoding {
+    /// Printable UTF-8 in double quotes, otherwise the bytes in this encoding, so that a text
+    /// starting with `base64:` or `0x` is not taken for encoded bytes
+    pub fn display(self, bytes: &[u8]) -> String {
+        match std::str::from_utf8(bytes) {
+            Ok(text) if !text.chars().any(char::is_control) => {
+                serde_json::Value::String(text.to_owned()).to_string()
+            }
+            _ => match self {
+                Self::Base64 => format!("base64:{}", near_primitives::serialize::to_base64(bytes)),
+                Self::Hex => format!("0x{}", hex::encode(bytes)),
+            },
+        }
+    }
+}
+
+/// Filter the contract state by a key prefix
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliStatePrefix {
+    /// Only the keys starting with this prefix
+    #[clap(long)]
+    prefix: Option<String>,
+    /// How the prefix is given: utf8, base64 or hex
+    #[clap(long, default_value = "utf8")]
+    prefix_encoding: Encoding,
+    /// How the keys and values that are not printable UTF-8 are shown: base64 or hex
+    #[clap(long, default_value = "base64")]
+    binary_encoding: BinaryEncoding,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatePrefix {
+    prefix: Option<String>,
+    prefix_encoding: Encoding,
+    binary_encoding: BinaryEncoding,
+}
+
+impl From<CliStatePrefix> for StatePrefix {
+    fn from(item: CliStatePrefix) -> Self {
+        Self {
+            prefix: item.prefix,
+            prefix_encoding: item.prefix_encoding,
+            binary_encoding: item.binary_encoding,
+        }
+    }
+}
+
+impl StatePrefix {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(prefix) = &self.prefix {
+            args.push_back("--prefix".to_owned());
+            args.push_back(prefix.clone());
+            if self.prefix_encoding != Encoding::Utf8 {
+                args.push_back("--prefix-encoding".to_owned());
+                args.push_back(<&str>::from(self.prefix_encoding).to_owned());
+            }
+        }
+        if self.binary_encoding != BinaryEncoding::default() {
+            args.push_back("--binary-encoding".to_owned());
+            args.push_back(<&str>::from(self.binary_encoding).to_owned());
+        }
+        args
+    }
+
+    pub fn store_key(&self) -> color_eyre::eyre::Result<near_primitives::types::StoreKey> {
+        let prefix = match &self.prefix {
+            Some(prefix) => self.prefix_encoding.decode(prefix)?,
+            None => vec![],
+        };
+        Ok(near_primitives::types::StoreKey::from(prefix))
+    }
+
+    pub fn display(&self, bytes: &[u8]) -> String {
+        self.binary_encoding.display(bytes)
+    }
+}
+
+/// The keys and values of the state, decoded from base64
+pub fn state_items(
+    view_state_result: &near_primitives::views::ViewStateResult,
+) -> color_eyre::eyre::Result<Vec<(Vec<u8>, Vec<u8>)>> {
+    view_state_result
+        .values
+        .iter()
+        .map(|state_item| {
+            let key = near_primitives::serialize::from_base64(&state_item.key);
+            let value = near_primitives::serialize::from_base64(&state_item.value);
+            match (key, value) {
+                (Ok(key), Ok(value)) => Ok((key, value)),
+                _ => Err(color_eyre::Report::msg(format!(
+                    "The RPC returned an invalid state item: {:?}",
+                    state_item
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Queries the state of the account at the block
+pub async fn view_state(
+    rpc_url: &url::Url,
+    account_id: String,
+    block_reference: near_primitives::types::BlockReference,
+    state_prefix: &StatePrefix,
+) -> color_eyre::eyre::Result<near_primitives::views::ViewStateResult> {
+    let query_view_state_response = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewState {
+                account_id,
+                prefix: state_prefix.store_key()?,
+            },
+        })
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!(
+                "Failed to fetch query for view contract state: {:?}",
+                err
+            ))
+        })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
+        query_view_state_response.kind
+    {
+        Ok(result)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+/// The keys added, removed and changed (with the old and the new value) between two states
+#[derive(Debug, Default, PartialEq)]
+pub struct StateDiff {
+    pub added: Vec<(Vec<u8>, Vec<u8>)>,
+    pub removed: Vec<(Vec<u8>, Vec<u8>)>,
+    pub changed: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)>,
+}
+
+impl StateDiff {
+    pub fn new(old_state: Vec<(Vec<u8>, Vec<u8>)>, new_state: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+        let mut old_state = old_state
+            .into_iter()
+            .collect::<std::collections::BTreeMap<_, _>>();
+        let mut state_diff = Self::default();
+        for (key, new_value) in new_state
+            .into_iter()
+            .collect::<std::collections::BTreeMap<_, _>>()
+        {
+            match old_state.remove(&key) {
+                None => state_diff.added.push((key, new_value)),
+                Some(old_value) if old_value != new_value => {
+                    state_diff.changed.push((key, old_value, new_value))
+                }
+                Some(_) => {}
+            }
+        }
+        state_diff.removed = old_state.into_iter().collect();
+        state_diff
+    }
+}
+
+/// A CSV field, quoted so that commas, quotes and line breaks survive
+pub fn csv_field(field: &str) -> String {
+    format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn printable_bytes_are_shown_as_text() {
+        assert_eq!(BinaryEncoding::Base64.display(b"STATE"), "\"STATE\"");
+        assert_eq!(BinaryEncoding::Base64.display(b"0x00"), "\"0x00\"");
+        assert_eq!(BinaryEncoding::Base64.display(&[0, 1, 2]), "base64:AAEC");
+        assert_eq!(BinaryEncoding::Hex.display(&[0, 1, 2]), "0x000102");
+        assert_eq!(Encoding::Hex.decode("0x000102").unwrap(), vec![0, 1, 2]);
+        assert_eq!(csv_field("a \"b\", c"), "\"a \"\"b\"\", c\"");
+    }
+
+    #[test]
+    fn state_diff_finds_added_removed_and_changed_keys() {
+        let entry = |key: &str, value: &str| (key.as_bytes().to_vec(), value.as_bytes().to_vec());
+        let state_diff = StateDiff::new(
+            vec![entry("a", "1"), entry("b", "2"), entry("c", "3")],
+            vec![entry("d", "4"), entry("c", "3"), entry("b", "5")],
+        );
+        assert_eq!(
+            state_diff,
+            StateDiff {
+                added: vec![entry("d", "4")],
+                removed: vec![entry("a", "1")],
+                changed: vec![(b"b".to_vec(), b"2".to_vec(), b"5".to_vec())],
+            }
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! The arguments of a contract function call: inline JSON, a JSON file, base64 or hex encoded
+//! raw bytes, or stdin.
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+/// The flags to take the function arguments from somewhere else than the inline JSON
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliArgsSource {
+    /// Read the arguments as JSON from a file
+    #[clap(long, conflicts_with_all = &["args-base64", "args-hex", "args-stdin"])]
+    args_file: Option<std::path::PathBuf>,
+    /// The arguments as base64 encoded raw bytes
+    #[clap(long, conflicts_with_all = &["args-hex", "args-stdin"])]
+    args_base64: Option<String>,
+    /// The arguments as hex encoded raw bytes
+    #[clap(long, conflicts_with = "args-stdin")]
+    args_hex: Option<String>,
+    /// Read the arguments as raw bytes from stdin
+    #[clap(long)]
+    args_stdin: bool,
+}
+
+/// The argument ids (the kebab-case field names) of the flags of [`CliArgsSource`], which
+/// conflict with the inline JSON
+pub const ARGS_SOURCE_FLAGS: &[&str] = &["args-file", "args-base64", "args-hex", "args-stdin"];
+
+#[derive(Debug, Clone)]
+pub enum FunctionArgs {
+    Json(String),
+    JsonFile(std::path::PathBuf),
+    Base64(String),
+    Hex(String),
+    Stdin,
+}
+
+impl FunctionArgs {
+    /// `argument` is the name of the inline JSON argument, used when it cannot be asked for
+    pub fn from(inline_args: Option<String>, item: CliArgsSource, argument: &'static str) -> Self {
+        if let Some(json) = inline_args {
+            Self::Json(json)
+        } else if let Some(path) = item.args_file {
+            Self::JsonFile(path)
+        } else if let Some(base64) = item.args_base64 {
+            Self::Base64(base64)
+        } else if let Some(hex) = item.args_hex {
+            Self::Hex(hex)
+        } else if item.args_stdin {
+            Self::Stdin
+        } else {
+            Self::input_args(argument)
+        }
+    }
+
+    fn input_args(argument: &'static str) -> Self {
+        println!();
+        crate::common::ensure_interactive(argument);
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How would you like to provide args for function?")
+            .items(&[
+                "JSON",
+                "A JSON file",
+                "Base64 encoded bytes",
+                "Hex encoded bytes",
+                "Read from stdin",
+            ])
+            .default(0)
+            .interact()
+            .unwrap();
+        match selection {
+            0 => Self::Json(
+                Input::new()
+                    .with_prompt("Enter args for function")
+                    .with_initial_text("{}")
+                    .validate_with(|input: &String| -> Result<(), String> {
+                        json_to_bytes(input).map(|_| ())
+                    })
+                    .interact_text()
+                    .unwrap(),
+            ),
+            1 => Self::JsonFile(
+                Input::<String>::new()
+                    .with_prompt("Enter the path to the JSON file with args")
+                    .interact_text()
+                    .unwrap()
+                    .into(),
+            ),
+            2 => Self::Base64(
+                Input::new()
+                    .with_prompt("Enter base64 encoded args")
+                    .interact_text()
+                    .unwrap(),
+            ),
+            3 => Self::Hex(
+                Input::new()
+                    .with_prompt("Enter hex encoded args")
+                    .interact_text()
+                    .unwrap(),
+            ),
+            _ => Self::Stdin,
+        }
+    }
+
+    /// The inline JSON is just the value, the other sources are flags
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let (flag, value) = match self {
+            Self::Json(json) => return std::collections::VecDeque::from(vec![json.clone()]),
+            Self::JsonFile(path) => ("--args-file", path.display().to_string()),
+            Self::Base64(base64) => ("--args-base64", base64.clone()),
+            Self::Hex(hex) => ("--args-hex", hex.clone()),
+            Self::Stdin => {
+                return std::collections::VecDeque::from(vec!["--args-stdin".to_owned()])
+            }
+        };
+        std::collections::VecDeque::from(vec![flag.to_owned(), value])
+    }
+
+    /// The bytes to send, JSON is validated first
+    pub fn to_bytes(&self) -> color_eyre::eyre::Result<Vec<u8>> {
+        let bytes = match self {
+            Self::Json(json) => json_to_bytes(json),
+            Self::JsonFile(path) => std::fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read args from {}: {}", path.display(), err))
+                .and_then(|json| json_to_bytes(&json)),
+            Self::Base64(base64) => near_primitives::serialize::from_base64(base64)
+                .map_err(|err| format!("Invalid base64 args: {}", err)),
+            Self::Hex(hex) => hex::decode(hex.trim_start_matches("0x"))
+                .map_err(|err| format!("Invalid hex args: {}", err)),
+            Self::Stdin => {
+                let mut bytes = vec![];
+                std::io::Read::read_to_end(&mut std::io::stdin(), &mut bytes)
+                    .map(|_| bytes)
+                    .map_err(|err| format!("Failed to read args from stdin: {}", err))
+            }
+        };
+        bytes.map_err(|err| crate::common::CliError::UserInput(err).into())
+    }
+}
+
+fn json_to_bytes(json: &str) -> Result<Vec<u8>, String> {
+    serde_json::from_str::<serde_json::Value>(json)
+        .map_err(|err| format!("The args are not valid JSON: {}", err))?;
+    Ok(json.trim().as_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Clap;
+
+    /// The inline JSON and the args source as the commands take them
+    #[derive(Debug, clap::Clap)]
+    struct CliCallFunction {
+        #[clap(conflicts_with_all = ARGS_SOURCE_FLAGS)]
+        args: Option<String>,
+        #[clap(flatten)]
+        args_source: CliArgsSource,
+    }
+
+    #[test]
+    fn args_sources_conflict() {
+        let call_function = CliCallFunction::try_parse_from(&["call", "{}"]).unwrap();
+        assert_eq!(call_function.args, Some("{}".to_owned()));
+        let call_function = CliCallFunction::try_parse_from(&["call", "--args-hex", "00"]).unwrap();
+        assert_eq!(call_function.args_source.args_hex, Some("00".to_owned()));
+        assert!(CliCallFunction::try_parse_from(&[
+            "call",
+            "--args-file",
+            "args.json",
+            "--args-hex",
+            "00"
+        ])
+        .is_err());
+        assert!(CliCallFunction::try_parse_from(&[
+            "call",
+            "--args-base64",
+            "AA==",
+            "--args-stdin"
+        ])
+        .is_err());
+        assert!(CliCallFunction::try_parse_from(&["call", "{}", "--args-stdin"]).is_err());
+    }
+
+    #[test]
+    fn args_are_decoded_from_every_encoding() {
+        assert_eq!(
+            FunctionArgs::Json(r#"{"account_id": "volodymyr.testnet"}"#.to_owned())
+                .to_bytes()
+                .unwrap(),
+            br#"{"account_id": "volodymyr.testnet"}"#.to_vec()
+        );
+        assert!(FunctionArgs::Json("{account_id}".to_owned())
+            .to_bytes()
+            .is_err());
+        assert_eq!(
+            FunctionArgs::Base64("AQID".to_owned()).to_bytes().unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            FunctionArgs::Hex("0x010203".to_owned()).to_bytes().unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(FunctionArgs::Hex("0x0g".to_owned()).to_bytes().is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! NEP-141 fungible tokens: the view methods of a token contract and the amounts in the
+//! decimals of its metadata.
+
+/// `ft_transfer` and `ft_transfer_call` require exactly one yoctoNEAR to be attached
+pub const ONE_YOCTO_NEAR: u128 = 1;
+/// The gas attached to `storage_deposit`
+pub const STORAGE_DEPOSIT_GAS: u64 = 30_000_000_000_000;
+/// The default gas of `ft_transfer`
+pub const FT_TRANSFER_GAS: u64 = 30_000_000_000_000;
+/// The default gas of `ft_transfer_call`, which also calls the receiver and resolves the transfer
+pub const FT_TRANSFER_CALL_GAS: u64 = 100_000_000_000_000;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FtMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StorageBalanceBounds {
+    pub min: String,
+}
+
+/// An amount of tokens as entered, e.g. `1.5`; it is scaled by the decimals of the token
+/// once its metadata is known
+#[derive(Debug, Clone, PartialEq)]
+pub struct FtAmount {
+    inner: String,
+}
+
+impl std::str::FromStr for FtAmount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let amount = s.trim();
+        let mut parts = amount.splitn(2, '.');
+        let integer = parts.next().unwrap_or_default();
+        let fraction = parts.next().unwrap_or_default();
+        if integer.is_empty() && fraction.is_empty()
+            || !integer.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(format!(
+                "<{}> is not an amount of tokens (example: 10 or 0.5)",
+                s
+            ));
+        }
+        Ok(Self {
+            inner: amount.to_owned(),
+        })
+    }
+}
+
+impl std::fmt::Display for FtAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl FtAmount {
+    pub fn to_cli_arg(&self) -> String {
+        self.inner.clone()
+    }
+
+    /// The amount in the smallest units of a token with these decimals
+    pub fn to_base_units(&self, decimals: u8) -> Result<u128, String> {
+        let mut parts = self.inner.splitn(2, '.');
+        let integer = parts.next().unwrap_or_default();
+        let fraction = parts.next().unwrap_or_default().trim_end_matches('0');
+        if fraction.len() > usize::from(decimals) {
+            return Err(format!(
+                "The token has {} decimals, {} has more",
+                decimals, self.inner
+            ));
+        }
+        format!(
+            "{}{}{}",
+            integer,
+            fraction,
+            "0".repeat(usize::from(decimals) - fraction.len())
+        )
+        .parse::<u128>()
+        .or_else(|_| {
+            if integer.is_empty() && fraction.is_empty() {
+                Ok(0)
+            } else {
+                Err(format!("{} is too large an amount", self.inner))
+            }
+        })
+    }
+}
+
+/// The amount in the smallest units shown with the decimals of the token, e.g. `1.5`
+pub fn format_amount(amount: u128, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_owned()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// Parses the NEP-141 `U128` string
+pub fn parse_u128(value: &str) -> color_eyre::eyre::Result<u128> {
+    value.parse().map_err(|_| {
+        color_eyre::Report::msg(format!(
+            "The token contract returned an invalid amount <{}>",
+            value
+        ))
+    })
+}
+
+pub async fn ft_metadata(
+    rpc_url: &url::Url,
+    token_contract_id: &str,
+) -> color_eyre::eyre::Result<FtMetadata> {
+    crate::common::call_view_function(
+        rpc_url,
+        token_contract_id,
+        "ft_metadata",
+        serde_json::json!({}),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await
+}
+
+/// The balance in the smallest units of the token
+pub async fn ft_balance_of(
+    rpc_url: &url::Url,
+    token_contract_id: &str,
+    account_id: &str,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<u128> {
+    let balance: String = crate::common::call_view_function(
+        rpc_url,
+        token_contract_id,
+        "ft_balance_of",
+        serde_json::json!({ "account_id": account_id }),
+        block_reference,
+    )
+    .await?;
+    parse_u128(&balance)
+}
+
+/// Whether the account is registered with the token contract: `storage_balance_of` is not null
+pub async fn is_registered(
+    rpc_url: &url::Url,
+    token_contract_id: &str,
+    account_id: &str,
+) -> color_eyre::eyre::Result<bool> {
+    let storage_balance: Option<serde_json::Value> = crate::common::call_view_function(
+        rpc_url,
+        token_contract_id,
+        "storage_balance_of",
+        serde_json::json!({ "account_id": account_id }),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await?;
+    Ok(storage_balance.is_some())
+}
+
+pub async fn storage_balance_bounds(
+    rpc_url: &url::Url,
+    token_contract_id: &str,
+) -> color_eyre::eyre::Result<StorageBalanceBounds> {
+    crate::common::call_view_function(
+        rpc_url,
+        token_contract_id,
+        "storage_balance_bounds",
+        serde_json::json!({}),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_scaled_by_the_token_decimals() {
+        let amount = |amount: &str| amount.parse::<FtAmount>().unwrap();
+        assert_eq!(amount("1.5").to_base_units(6), Ok(1_500_000));
+        assert_eq!(amount("0.000001").to_base_units(6), Ok(1));
+        assert_eq!(amount("10").to_base_units(0), Ok(10));
+        assert_eq!(amount(".5").to_base_units(1), Ok(5));
+        assert!(amount("0.0000001").to_base_units(6).is_err());
+        assert!("1.5.0".parse::<FtAmount>().is_err());
+        assert!("1e6".parse::<FtAmount>().is_err());
+        assert_eq!(format_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_amount(1, 6), "0.000001");
+        assert_eq!(format_amount(10, 0), "10");
+        assert_eq!(format_amount(0, 18), "0");
+    }
+}
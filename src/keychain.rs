@@ -0,0 +1,457 @@
+//! Access key files in the keychain (`~/.near-credentials`).
+//!
+//! A key file is either plaintext JSON with `master_seed_phrase`, `seed_phrase_hd_path`,
+//! `account_id`, `public_key` and `private_key`, or its encrypted form, which keeps only
+//! `account_id` and `public_key` readable:
+//!
+//! ```json
+//! {
+//!   "account_id": "volodymyr.testnet",
+//!   "public_key": "ed25519:...",
+//!   "crypto": {
+//!     "cipher": "chacha20poly1305",
+//!     "kdf": "argon2id",
+//!     "kdf_params": { "memory_kib": 65536, "iterations": 3, "parallelism": 1 },
+//!     "salt": "<base64>",
+//!     "nonce": "<base64>",
+//!     "ciphertext": "<base64 of the encrypted plaintext JSON>"
+//!   }
+//! }
+//! ```
+//!
+//! New key files are encrypted when `encrypt = true` is set in the `[keychain]` section
+//! of the config file; `near-cli utils encrypt-keychain` encrypts the existing ones.
+use std::io::Write;
+
+use chacha20poly1305::aead::{Aead, NewAead};
+
+/// The environment variable to read the keychain passphrase from instead of prompting
+pub const PASSPHRASE_ENV_VAR: &str = "NEAR_CLI_KEYCHAIN_PASSPHRASE";
+
+const CIPHER: &str = "chacha20poly1305";
+const KDF: &str = "argon2id";
+
+thread_local! {
+    /// The passphrase is asked for once per run, even if several key files are read or written
+    static PASSPHRASE: std::cell::RefCell<Option<String>> = std::cell::RefCell::new(None);
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct EncryptedKeyFile {
+    account_id: String,
+    public_key: near_crypto::PublicKey,
+    crypto: KeyFileCrypto,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct KeyFileCrypto {
+    cipher: String,
+    kdf: String,
+    kdf_params: KdfParams,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct KdfParams {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+/// The non-secret part of a plaintext key file
+#[derive(Debug, serde::Deserialize)]
+struct KeyFileHeader {
+    account_id: String,
+    public_key: near_crypto::PublicKey,
+}
+
+pub fn is_encrypted(data: &str) -> bool {
+    serde_json::from_str::<EncryptedKeyFile>(data).is_ok()
+}
+
+/// Whether new key files are to be encrypted (`[keychain] encrypt = true` in the config file)
+pub fn is_encryption_enabled() -> color_eyre::eyre::Result<bool> {
+    Ok(crate::config::Config::load()?.keychain.encrypt)
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    kdf_params: &KdfParams,
+) -> color_eyre::eyre::Result<chacha20poly1305::Key> {
+    let config = argon2::Config {
+        variant: argon2::Variant::Argon2id,
+        version: argon2::Version::Version13,
+        mem_cost: kdf_params.memory_kib,
+        time_cost: kdf_params.iterations,
+        lanes: kdf_params.parallelism,
+        hash_length: 32,
+        ..argon2::Config::default()
+    };
+    let key = argon2::hash_raw(passphrase.as_bytes(), salt, &config)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to derive a key: {}", err)))?;
+    Ok(*chacha20poly1305::Key::from_slice(&key))
+}
+
+/// Encrypts the plaintext key file `data`
+pub fn encrypt(data: &str, passphrase: &str) -> color_eyre::eyre::Result<String> {
+    let header: KeyFileHeader = serde_json::from_str(data).map_err(|err| {
+        crate::common::CliError::Keychain(format!("The key file is not valid: {}", err))
+    })?;
+    let mut salt = [0u8; 16];
+    let mut nonce = [0u8; 12];
+    rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut salt);
+    rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut nonce);
+    let kdf_params = KdfParams::default();
+    let key = derive_key(passphrase, &salt, &kdf_params)?;
+    let ciphertext = chacha20poly1305::ChaCha20Poly1305::new(&key)
+        .encrypt(chacha20poly1305::Nonce::from_slice(&nonce), data.as_bytes())
+        .map_err(|_| crate::common::CliError::Keychain("Failed to encrypt".to_string()))?;
+    let encrypted_key_file = EncryptedKeyFile {
+        account_id: header.account_id,
+        public_key: header.public_key,
+        crypto: KeyFileCrypto {
+            cipher: CIPHER.to_string(),
+            kdf: KDF.to_string(),
+            kdf_params,
+            salt: base64::encode(&salt),
+            nonce: base64::encode(&nonce),
+            ciphertext: base64::encode(&ciphertext),
+        },
+    };
+    Ok(serde_json::to_string(&encrypted_key_file)?)
+}
+
+/// Decrypts an encrypted key file back into the plaintext JSON
+pub fn decrypt(data: &str, passphrase: &str) -> color_eyre::eyre::Result<String> {
+    let encrypted_key_file: EncryptedKeyFile = serde_json::from_str(data).map_err(|err| {
+        crate::common::CliError::Keychain(format!("The encrypted key file is not valid: {}", err))
+    })?;
+    let crypto = encrypted_key_file.crypto;
+    if crypto.cipher != CIPHER || crypto.kdf != KDF {
+        return Err(crate::common::CliError::Keychain(format!(
+            "Unsupported key file encryption: {} with {}",
+            crypto.cipher, crypto.kdf
+        ))
+        .into());
+    }
+    let decode = |field: &str, value: &str| {
+        base64::decode(value).map_err(|err| {
+            crate::common::CliError::Keychain(format!(
+                "The encrypted key file has an invalid {}: {}",
+                field, err
+            ))
+        })
+    };
+    let salt = decode("salt", &crypto.salt)?;
+    let nonce = decode("nonce", &crypto.nonce)?;
+    let ciphertext = decode("ciphertext", &crypto.ciphertext)?;
+    if nonce.len() != 12 {
+        return Err(crate::common::CliError::Keychain(
+            "The encrypted key file has an invalid nonce".to_string(),
+        )
+        .into());
+    }
+    let key = derive_key(passphrase, &salt, &crypto.kdf_params)?;
+    let data = chacha20poly1305::ChaCha20Poly1305::new(&key)
+        .decrypt(
+            chacha20poly1305::Nonce::from_slice(&nonce),
+            ciphertext.as_ref(),
+        )
+        .map_err(|_| {
+            crate::common::CliError::Keychain(
+                "Failed to decrypt the key file: the passphrase is wrong".to_string(),
+            )
+        })?;
+    Ok(String::from_utf8(data)?)
+}
+
+/// The keychain passphrase: from `passphrase_fd`, the environment variable or a prompt,
+/// in this order. A new passphrase is asked for twice.
+pub fn passphrase(
+    passphrase_fd: Option<i32>,
+    is_new_passphrase: bool,
+) -> color_eyre::eyre::Result<String> {
+    if let Some(passphrase) = PASSPHRASE.with(|cached| cached.borrow().clone()) {
+        return Ok(passphrase);
+    }
+    let passphrase = match passphrase_fd {
+        Some(fd) => read_passphrase_fd(fd)?,
+        None => match std::env::var(PASSPHRASE_ENV_VAR) {
+            Ok(passphrase) => passphrase,
+            Err(_) => {
+                crate::common::ensure_interactive("--passphrase-fd");
+                let mut prompt = dialoguer::Password::new();
+                prompt.with_prompt("Enter the keychain passphrase");
+                if is_new_passphrase {
+                    prompt
+                        .with_confirmation("Repeat the passphrase", "The passphrases do not match");
+                }
+                prompt.interact()?
+            }
+        },
+    };
+    if passphrase.is_empty() {
+        return Err(crate::common::CliError::UserInput(
+            "The keychain passphrase must not be empty".to_string(),
+        )
+        .into());
+    }
+    PASSPHRASE.with(|cached| *cached.borrow_mut() = Some(passphrase.clone()));
+    Ok(passphrase)
+}
+
+#[cfg(unix)]
+fn read_passphrase_fd(fd: i32) -> color_eyre::eyre::Result<String> {
+    use std::io::Read;
+    use std::os::unix::io::FromRawFd;
+
+    // The descriptor is handed over by the caller (e.g. `--passphrase-fd 3 3<passphrase.txt`),
+    // which stays its owner: it is not closed here
+    let mut file = std::mem::ManuallyDrop::new(unsafe { std::fs::File::from_raw_fd(fd) });
+    let mut passphrase = String::new();
+    file.read_to_string(&mut passphrase).map_err(|err| {
+        crate::common::CliError::UserInput(format!(
+            "Failed to read the passphrase from the file descriptor {}: {}",
+            fd, err
+        ))
+    })?;
+    Ok(passphrase.trim_end_matches(&['\r', '\n'][..]).to_string())
+}
+
+#[cfg(not(unix))]
+fn read_passphrase_fd(_fd: i32) -> color_eyre::eyre::Result<String> {
+    Err(crate::common::CliError::UserInput(format!(
+        "--passphrase-fd is not supported on this platform, use {} instead",
+        PASSPHRASE_ENV_VAR
+    ))
+    .into())
+}
+
+/// Reads a key file and returns its plaintext JSON, decrypting it if needed
+pub fn read_key_file(
+    path: &std::path::Path,
+    passphrase_fd: Option<i32>,
+) -> color_eyre::eyre::Result<String> {
+    let data = std::fs::read_to_string(path).map_err(|err| {
+        crate::common::CliError::Keychain(format!("Access key file not found! Error: {}", err))
+    })?;
+    if is_encrypted(&data) {
+        decrypt(&data, &passphrase(passphrase_fd, false)?)
+    } else {
+        Ok(data)
+    }
+}
+
+/// Writes the plaintext JSON `data` to a key file, encrypted if the keychain encryption is enabled
+pub fn write_key_file(
+    path: &std::path::Path,
+    data: &str,
+    passphrase_fd: Option<i32>,
+) -> color_eyre::eyre::Result<()> {
+    let data = if is_encryption_enabled()? {
+        encrypt(data, &passphrase(passphrase_fd, true)?)?
+    } else {
+        data.to_string()
+    };
+    write_private_file(path, data.as_bytes()).map_err(|err| {
+        crate::common::CliError::Keychain(format!(
+            "Failed to write to file {}: {}",
+            path.display(),
+            err
+        ))
+    })?;
+    Ok(())
+}
+
+/// Writes a file only its owner can read. The data goes to a temporary file next to it, which
+/// then replaces the file, so a crash never leaves a truncated key behind.
+pub fn write_private_file(path: &std::path::Path, data: &[u8]) -> std::io::Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = std::path::PathBuf::from(temp_path);
+    // A temporary file left by a crash may have other permissions, the mode only applies
+    // to a new file
+    match std::fs::remove_file(&temp_path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&temp_path)?;
+    if let Err(err) = file.write_all(data).and_then(|_| file.sync_all()) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(err);
+    }
+    std::fs::rename(&temp_path, path)
+}
+
+/// The keychain directories: `~/.near-credentials` and those of the config networks
+/// that live elsewhere
+pub fn keychain_dirs() -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    let root_dir = home_dir.join(".near-credentials");
+    let mut dirs = vec![root_dir.clone()];
+    for network in crate::config::Config::load()?.networks.values() {
+        let dir = home_dir.join(&network.keychain_dir);
+        if !dir.starts_with(&root_dir) && !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    Ok(dirs)
+}
+
+/// The keychain directory of the network, or the one for the offline mode
+pub fn keychain_dir(
+    network_connection_config: &Option<crate::common::ConnectionConfig>,
+) -> std::path::PathBuf {
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    match network_connection_config {
+        Some(connection_config) => home_dir.join(connection_config.dir_name()),
+        None => home_dir.join(crate::consts::DIR_NAME_KEY_CHAIN),
+    }
+}
+
+/// The keys of the account in the keychain of the network. Keys of custom networks used to be
+/// saved in the directory of the offline mode, which is still read if the account has no keys
+/// in the directory of the custom network.
+pub fn account_keys(
+    network_connection_config: &Option<crate::common::ConnectionConfig>,
+    account_id: &str,
+) -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+    let keys_in = |dir: &std::path::Path| -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+        Ok(keys(dir)?
+            .into_iter()
+            .filter(|key| key.account_id == account_id)
+            .collect())
+    };
+    let dir = keychain_dir(network_connection_config);
+    let keychain_keys = keys_in(&dir)?;
+    if !keychain_keys.is_empty() {
+        return Ok(keychain_keys);
+    }
+    if let Some(crate::common::ConnectionConfig::Custom(_)) = network_connection_config {
+        let legacy_dir = keychain_dir(&None);
+        let legacy_keys = keys_in(&legacy_dir)?;
+        if !legacy_keys.is_empty() {
+            crate::common::print_message(&format!(
+                "Warning: the keys of <{}> are read from {}, where the keys of custom networks were saved before. Move them to {}.",
+                account_id,
+                legacy_dir.display(),
+                dir.display()
+            ));
+        }
+        return Ok(legacy_keys);
+    }
+    Ok(keychain_keys)
+}
+
+/// An access key in the keychain. The same key is usually saved twice: in
+/// `<account_id>/<public_key>.json` and in `<account_id>.json`.
+#[derive(Debug, Clone)]
+pub struct KeychainKey {
+    pub account_id: String,
+    pub public_key: near_crypto::PublicKey,
+    pub file_paths: Vec<std::path::PathBuf>,
+    pub is_encrypted: bool,
+}
+
+/// The access keys saved under `dir`, ordered by account; other JSON files are ignored
+pub fn keys(dir: &std::path::Path) -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+    let mut keys: Vec<KeychainKey> = vec![];
+    for path in key_files(dir)? {
+        let data = std::fs::read_to_string(&path)?;
+        let header: KeyFileHeader = match serde_json::from_str(&data) {
+            Ok(header) => header,
+            Err(_) => continue,
+        };
+        match keys
+            .iter_mut()
+            .find(|key| key.account_id == header.account_id && key.public_key == header.public_key)
+        {
+            Some(key) => key.file_paths.push(path),
+            None => keys.push(KeychainKey {
+                account_id: header.account_id,
+                public_key: header.public_key,
+                file_paths: vec![path],
+                is_encrypted: is_encrypted(&data),
+            }),
+        }
+    }
+    keys.sort_by_key(|key| (key.account_id.clone(), key.public_key.to_string()));
+    Ok(keys)
+}
+
+/// All the `*.json` files under `dir`, recursively; a missing directory has none
+pub fn key_files(dir: &std::path::Path) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let mut files = vec![];
+    if !dir.is_dir() {
+        return Ok(files);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(key_files(&path)?);
+        } else if path
+            .extension()
+            .map_or(false, |extension| extension == "json")
+        {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_FILE: &str = r#"{"account_id":"volodymyr.testnet","public_key":"ed25519:7FmDRADa1v4BcLiiR9MPPdmWQp3Um1iPdAYATvBY1YzS","private_key":"ed25519:5NLt2jQPSXDg8K7Qm6bb5g9CMHCrtKgsMKVY9R6dtbd79LmTRwJ4ZeddjDkS1fyZbgEFTrRU5iSgSzTWYqB8XhKf"}"#;
+
+    #[test]
+    fn encrypted_key_file_round_trip() {
+        let encrypted = encrypt(KEY_FILE, "correct horse").unwrap();
+        assert!(is_encrypted(&encrypted));
+        assert!(!is_encrypted(KEY_FILE));
+        assert!(!encrypted.contains("private_key"));
+        assert!(encrypted.contains("volodymyr.testnet"));
+        assert_eq!(decrypt(&encrypted, "correct horse").unwrap(), KEY_FILE);
+        assert!(decrypt(&encrypted, "wrong horse").is_err());
+    }
+
+    #[test]
+    fn key_files_are_replaced_and_private() {
+        let dir = std::env::temp_dir().join(format!("near-cli-keychain-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("volodymyr.testnet.json");
+        std::fs::write(&path, "a longer key file that is replaced").unwrap();
+        write_private_file(&path, KEY_FILE.as_bytes()).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), KEY_FILE);
+        assert!(!dir.join("volodymyr.testnet.json.tmp").exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
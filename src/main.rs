@@ -1,8 +1,20 @@
-use clap::Clap;
+use clap::{Clap, FromArgMatches, IntoApp};
 
+mod blocks;
 mod commands;
 mod common;
+mod config;
 mod consts;
+mod contract_state;
+mod function_args;
+mod fungible_token;
+mod keychain;
+mod network;
+mod non_fungible_token;
+mod staking_pool;
+mod submission;
+mod validators;
+mod wasm;
 
 type CliResult = color_eyre::eyre::Result<()>;
 
@@ -18,6 +30,21 @@ type CliResult = color_eyre::eyre::Result<()>;
     // setting(clap::AppSettings::NextLineHelp)
 )]
 struct CliArgs {
+    /// Fail instead of prompting for missing arguments (this is the default when stdin is not a terminal)
+    #[clap(long, global = true)]
+    no_interactive: bool,
+    /// Output format: plaintext or json (a single JSON document on stdout, also for failures)
+    #[clap(long, global = true, default_value = "plaintext")]
+    format: self::common::OutputFormat,
+    /// How far to follow a sent transaction: included, executed (with all its receipts) or final
+    #[clap(long, global = true, default_value = "executed")]
+    wait_until: self::submission::WaitUntil,
+    /// Sign a transaction rejected for an expired block hash or a used nonce again and resend it
+    #[clap(long, global = true)]
+    resign: bool,
+    /// Check the transaction against the chain and show its estimated cost instead of sending it
+    #[clap(long, global = true)]
+    dry_run: bool,
     #[clap(subcommand)]
     top_level_command: Option<self::commands::CliTopLevelCommand>,
 }
@@ -27,24 +54,58 @@ struct Args {
     top_level_command: self::commands::TopLevelCommand,
 }
 
-impl From<CliArgs> for Args {
-    fn from(cli_args: CliArgs) -> Self {
+impl Args {
+    fn from(cli_args: CliArgs) -> color_eyre::eyre::Result<Self> {
         let top_level_command = match cli_args.top_level_command {
-            Some(cli_subcommand) => self::commands::TopLevelCommand::from(cli_subcommand),
-            None => self::commands::TopLevelCommand::choose_command(),
+            Some(cli_subcommand) => self::commands::TopLevelCommand::from(cli_subcommand)?,
+            None => self::commands::TopLevelCommand::choose_command()?,
         };
-        Self { top_level_command }
+        Ok(Self { top_level_command })
     }
 }
 
 impl Args {
+    /// The arguments that reproduce this command without any prompts
+    fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.top_level_command.to_cli_args();
+        let submission_settings = self::submission::submission_settings();
+        if submission_settings.dry_run {
+            args.push_front("--dry-run".to_owned());
+        }
+        if submission_settings.resign {
+            args.push_front("--resign".to_owned());
+        }
+        if submission_settings.wait_until != self::submission::WaitUntil::default() {
+            args.push_front(<&str>::from(submission_settings.wait_until).to_owned());
+            args.push_front("--wait-until".to_owned());
+        }
+        if let self::common::OutputFormat::Json = self::common::output_format() {
+            args.push_front("json".to_owned());
+            args.push_front("--format".to_owned());
+        }
+        args.push_front("near-cli".to_owned());
+        args
+    }
+
     async fn process(self) -> CliResult {
         self.top_level_command.process().await
     }
 }
 
+/// The chain of subcommands provided on the command line, e.g. `near-cli transfer near network`
+fn subcommand_path(matches: &clap::ArgMatches) -> String {
+    let mut path = vec!["near-cli"];
+    let mut matches = matches;
+    while let Some((subcommand_name, subcommand_matches)) = matches.subcommand() {
+        path.push(subcommand_name);
+        matches = subcommand_matches;
+    }
+    path.join(" ")
+}
+
 fn main() -> CliResult {
-    let cli = CliArgs::parse();
+    let matches = CliArgs::into_app().get_matches();
+    let cli = CliArgs::from_arg_matches(&matches);
 
     if let Some(self::commands::CliTopLevelCommand::GenerateShellCompletions(subcommand)) =
         cli.top_level_command
@@ -53,9 +114,62 @@ fn main() -> CliResult {
         return Ok(());
     }
 
-    let args = Args::from(cli);
+    self::common::set_interactive_mode(!cli.no_interactive && atty::is(atty::Stream::Stdin));
+    self::common::set_output_format(cli.format.clone());
+    self::submission::set_submission_settings(self::submission::SubmissionSettings {
+        wait_until: cli.wait_until,
+        resign: cli.resign,
+        dry_run: cli.dry_run,
+    });
 
     color_eyre::install()?;
 
-    actix::System::new().block_on(args.process())
+    let default_panic_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        if !panic_info.payload().is::<self::common::MissingArgument>() {
+            default_panic_hook(panic_info)
+        }
+    }));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let args = Args::from(cli)?;
+        let cli_command = self::common::to_shell_command(args.to_cli_args());
+        let result = actix::System::new().block_on(args.process());
+        // A failed command is not worth re-running as it is
+        if self::common::was_prompted() && result.is_ok() {
+            self::common::print_message(&format!(
+                "\nHere is your console command if you need to script it or re-run:\n{}",
+                cli_command
+            ));
+            if cli_command.contains(self::common::SECRET_PLACEHOLDER) {
+                self::common::print_message(&format!(
+                    "The secret keys and seed phrases are replaced with {}, put them back to re-run it",
+                    self::common::SECRET_PLACEHOLDER
+                ));
+            }
+        }
+        result
+    }))
+    .unwrap_or_else(|panic_payload| {
+        match panic_payload.downcast::<self::common::MissingArgument>() {
+            Ok(missing_argument) => Err(self::common::MissingArgumentError {
+                argument: missing_argument.argument,
+                subcommand_path: subcommand_path(&matches),
+            }
+            .into()),
+            Err(panic_payload) => std::panic::resume_unwind(panic_payload),
+        }
+    });
+
+    if let self::common::OutputFormat::Json = self::common::output_format() {
+        self::common::print_json_output(&result);
+    }
+    if let Err(report) = result {
+        if let self::common::OutputFormat::Plaintext = self::common::output_format() {
+            eprintln!("Error: {:?}", report);
+        }
+        // Scripts rely on the exit code to tell the kind of the failure (see `common::CliError`)
+        std::process::exit(self::common::exit_code(&report));
+    }
+    Ok(())
 }
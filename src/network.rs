@@ -0,0 +1,176 @@
+//! What a server runs: its `status` (version, chain ID, latest block, sync state) and the main
+//! limits of its `EXPERIMENTAL_protocol_config`.
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct NetworkStatus {
+    pub version: String,
+    pub build: String,
+    pub chain_id: String,
+    pub protocol_version: u32,
+    pub latest_protocol_version: u32,
+    pub latest_block_height: near_primitives::types::BlockHeight,
+    pub latest_block_hash: String,
+    pub latest_block_time: String,
+    pub syncing: bool,
+}
+
+impl From<near_primitives::views::StatusResponse> for NetworkStatus {
+    fn from(status: near_primitives::views::StatusResponse) -> Self {
+        Self {
+            version: status.version.version,
+            build: status.version.build,
+            chain_id: status.chain_id,
+            protocol_version: status.protocol_version,
+            latest_protocol_version: status.latest_protocol_version,
+            latest_block_height: status.sync_info.latest_block_height,
+            latest_block_hash: status.sync_info.latest_block_hash.to_string(),
+            latest_block_time: status.sync_info.latest_block_time.to_string(),
+            syncing: status.sync_info.syncing,
+        }
+    }
+}
+
+impl NetworkStatus {
+    pub fn print(&self) {
+        println!("\nChain ID: {}", self.chain_id);
+        println!("Node version: {} (build {})", self.version, self.build);
+        println!(
+            "Protocol version: {} (the latest supported by the node is {})",
+            self.protocol_version, self.latest_protocol_version
+        );
+        println!(
+            "Latest block: #{} ({}) at {}",
+            self.latest_block_height, self.latest_block_hash, self.latest_block_time
+        );
+        if self.syncing {
+            println!("The node is syncing, the latest block is behind the network");
+        } else {
+            println!("The node is synced");
+        }
+    }
+}
+
+pub async fn network_status(rpc_url: &url::Url) -> color_eyre::eyre::Result<NetworkStatus> {
+    let status = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .status()
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!("Failed to fetch the network status: {:?}", err))
+        })?;
+    Ok(NetworkStatus::from(status))
+}
+
+/// The label, the JSON pointer in the protocol config and the unit of the fields shown
+const PROTOCOL_CONFIG_FIELDS: &[(&str, &str, &str)] = &[
+    ("Protocol version", "/protocol_version", ""),
+    ("Chain ID", "/chain_id", ""),
+    ("Epoch length", "/epoch_length", " blocks"),
+    ("Block producer seats", "/num_block_producer_seats", ""),
+    (
+        "Storage cost per byte",
+        "/runtime_config/storage_amount_per_byte",
+        " yoctoNEAR",
+    ),
+    ("Chunk gas limit", "/gas_limit", " gas"),
+    ("Min gas price", "/min_gas_price", " yoctoNEAR"),
+    ("Max gas price", "/max_gas_price", " yoctoNEAR"),
+    (
+        "Max prepaid gas of a function call",
+        "/runtime_config/wasm_config/limit_config/max_total_prepaid_gas",
+        " gas",
+    ),
+    (
+        "Max gas burnt by a function call",
+        "/runtime_config/wasm_config/limit_config/max_gas_burnt",
+        " gas",
+    ),
+    (
+        "Max gas burnt by a view call",
+        "/runtime_config/wasm_config/limit_config/max_gas_burnt_view",
+        " gas",
+    ),
+    (
+        "Max contract size",
+        "/runtime_config/wasm_config/limit_config/max_contract_size",
+        " bytes",
+    ),
+    (
+        "Max transaction size",
+        "/runtime_config/wasm_config/limit_config/max_transaction_size",
+        " bytes",
+    ),
+    (
+        "Max actions per receipt",
+        "/runtime_config/wasm_config/limit_config/max_actions_per_receipt",
+        "",
+    ),
+    (
+        "Max arguments length",
+        "/runtime_config/wasm_config/limit_config/max_arguments_length",
+        " bytes",
+    ),
+    (
+        "Max method name length",
+        "/runtime_config/wasm_config/limit_config/max_length_method_name",
+        " bytes",
+    ),
+    (
+        "Max storage key length",
+        "/runtime_config/wasm_config/limit_config/max_length_storage_key",
+        " bytes",
+    ),
+    (
+        "Max storage value length",
+        "/runtime_config/wasm_config/limit_config/max_length_storage_value",
+        " bytes",
+    ),
+    (
+        "Max logs per function call",
+        "/runtime_config/wasm_config/limit_config/max_number_logs",
+        "",
+    ),
+];
+
+/// The fields of the protocol config shown in plaintext, the ones missing in the config of
+/// the node are left out
+pub fn protocol_config_summary(protocol_config: &serde_json::Value) -> Vec<(&'static str, String)> {
+    PROTOCOL_CONFIG_FIELDS
+        .iter()
+        .filter_map(|(label, pointer, unit)| {
+            let value = match protocol_config.pointer(pointer)? {
+                serde_json::Value::String(value) => value.clone(),
+                value => value.to_string(),
+            };
+            Some((*label, format!("{}{}", value, unit)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protocol_config_summary_has_the_fields_of_the_config() {
+        let protocol_config = serde_json::json!({
+            "protocol_version": 45,
+            "chain_id": "testnet",
+            "runtime_config": {
+                "storage_amount_per_byte": "10000000000000000000",
+                "wasm_config": {"limit_config": {"max_contract_size": 4194304}}
+            }
+        });
+        assert_eq!(
+            protocol_config_summary(&protocol_config),
+            vec![
+                ("Protocol version", "45".to_owned()),
+                ("Chain ID", "testnet".to_owned()),
+                (
+                    "Storage cost per byte",
+                    "10000000000000000000 yoctoNEAR".to_owned()
+                ),
+                ("Max contract size", "4194304 bytes".to_owned()),
+            ]
+        );
+    }
+}
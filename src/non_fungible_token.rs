@@ -0,0 +1,155 @@
+//! NEP-171 non-fungible tokens: the view methods of an NFT contract and how its tokens and
+//! their NEP-177 metadata are shown.
+
+/// `nft_transfer` and `nft_transfer_call` require exactly one yoctoNEAR to be attached
+pub const ONE_YOCTO_NEAR: u128 = 1;
+/// The default gas of `nft_transfer`
+pub const NFT_TRANSFER_GAS: u64 = 30_000_000_000_000;
+/// The default gas of `nft_transfer_call`, which also calls the receiver and resolves the transfer
+pub const NFT_TRANSFER_CALL_GAS: u64 = 100_000_000_000_000;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Token {
+    pub token_id: String,
+    pub owner_id: String,
+    #[serde(default)]
+    pub metadata: Option<TokenMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved_account_ids: Option<std::collections::BTreeMap<String, u64>>,
+}
+
+/// The fields that are not set are left out of the JSON output
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TokenMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copies: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_hash: Option<String>,
+}
+
+impl TokenMetadata {
+    /// The fields that are set, labelled, in the order of the standard
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let copies = self.copies.map(|copies| copies.to_string());
+        vec![
+            ("Title", &self.title),
+            ("Description", &self.description),
+            ("Media", &self.media),
+            ("Media hash", &self.media_hash),
+            ("Copies", &copies),
+            ("Issued at", &self.issued_at),
+            ("Expires at", &self.expires_at),
+            ("Starts at", &self.starts_at),
+            ("Updated at", &self.updated_at),
+            ("Extra", &self.extra),
+            ("Reference", &self.reference),
+            ("Reference hash", &self.reference_hash),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| value.clone().map(|value| (label, value)))
+        .collect()
+    }
+}
+
+pub fn print_token(token: &Token) {
+    println!("\nToken <{}>", token.token_id);
+    println!("  Owner: {}", token.owner_id);
+    if let Some(metadata) = &token.metadata {
+        for (label, value) in metadata.lines() {
+            println!("  {}: {}", label, value);
+        }
+    }
+    if let Some(approved_account_ids) = &token.approved_account_ids {
+        for (account_id, approval_id) in approved_account_ids {
+            println!("  Approved: {} (approval ID {})", account_id, approval_id);
+        }
+    }
+}
+
+pub async fn nft_token(
+    rpc_url: &url::Url,
+    nft_contract_id: &str,
+    token_id: &str,
+) -> color_eyre::eyre::Result<Option<Token>> {
+    crate::common::call_view_function(
+        rpc_url,
+        nft_contract_id,
+        "nft_token",
+        serde_json::json!({ "token_id": token_id }),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await
+}
+
+/// One page of the tokens of the owner, starting at the index `from_index`
+pub async fn nft_tokens_for_owner(
+    rpc_url: &url::Url,
+    nft_contract_id: &str,
+    account_id: &str,
+    from_index: u128,
+    limit: u64,
+) -> color_eyre::eyre::Result<Vec<Token>> {
+    crate::common::call_view_function(
+        rpc_url,
+        nft_contract_id,
+        "nft_tokens_for_owner",
+        serde_json::json!({
+            "account_id": account_id,
+            "from_index": from_index.to_string(),
+            "limit": limit,
+        }),
+        near_primitives::types::Finality::Final.into(),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_metadata_is_shown_in_the_order_of_the_standard() {
+        let token: Token = serde_json::from_str(
+            r#"{
+                "token_id": "1",
+                "owner_id": "volodymyr.testnet",
+                "metadata": {"copies": 1, "title": "Sunrise", "media": null, "extra": "{}"}
+            }"#,
+        )
+        .unwrap();
+        let metadata = token.metadata.unwrap();
+        assert_eq!(
+            metadata.lines(),
+            vec![
+                ("Title", "Sunrise".to_owned()),
+                ("Copies", "1".to_owned()),
+                ("Extra", "{}".to_owned()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::json!({"title": "Sunrise", "copies": 1, "extra": "{}"})
+        );
+        assert_eq!(token.approved_account_ids, None);
+    }
+}
@@ -0,0 +1,146 @@
+//! Delegation to staking-pool contracts: the change methods of a pool and the view methods
+//! that show the balances of a delegator.
+
+/// The gas of the pool calls, the standard one of the wallets: every call also pings the pool,
+/// which may restake it
+pub const STAKING_POOL_GAS: u64 = 125_000_000_000_000;
+
+/// A change method of the staking pool; the amounts are in yoctoNEAR
+#[derive(Debug, Clone, PartialEq)]
+pub enum PoolCall {
+    DepositAndStake(u128),
+    Unstake(u128),
+    UnstakeAll,
+    Withdraw(u128),
+    WithdrawAll,
+}
+
+impl PoolCall {
+    pub fn method_name(&self) -> &'static str {
+        match self {
+            Self::DepositAndStake(_) => "deposit_and_stake",
+            Self::Unstake(_) => "unstake",
+            Self::UnstakeAll => "unstake_all",
+            Self::Withdraw(_) => "withdraw",
+            Self::WithdrawAll => "withdraw_all",
+        }
+    }
+
+    /// `deposit_and_stake` stakes the attached deposit, the other methods take the amount as
+    /// an argument
+    pub fn action(&self) -> near_primitives::transaction::Action {
+        let (args, deposit) = match self {
+            Self::DepositAndStake(amount) => (serde_json::json!({}), *amount),
+            Self::Unstake(amount) | Self::Withdraw(amount) => {
+                (serde_json::json!({ "amount": amount.to_string() }), 0)
+            }
+            Self::UnstakeAll | Self::WithdrawAll => (serde_json::json!({}), 0),
+        };
+        near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: self.method_name().to_owned(),
+                args: args.to_string().into_bytes(),
+                gas: STAKING_POOL_GAS,
+                deposit,
+            },
+        )
+    }
+}
+
+/// The balances of a delegator in yoctoNEAR
+#[derive(Debug, Clone)]
+pub struct Delegation {
+    pub staked_balance: u128,
+    pub unstaked_balance: u128,
+    /// The unstaked balance can be withdrawn: the epochs of the unstaking have passed
+    pub is_unstaked_balance_available: bool,
+}
+
+async fn balance(
+    rpc_url: &url::Url,
+    pool_account_id: &str,
+    method_name: &str,
+    account_id: &str,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<u128> {
+    let balance: String = crate::common::call_view_function(
+        rpc_url,
+        pool_account_id,
+        method_name,
+        serde_json::json!({ "account_id": account_id }),
+        block_reference,
+    )
+    .await?;
+    balance.parse().map_err(|_| {
+        color_eyre::Report::msg(format!(
+            "The staking pool returned an invalid balance <{}>",
+            balance
+        ))
+    })
+}
+
+pub async fn delegation(
+    rpc_url: &url::Url,
+    pool_account_id: &str,
+    account_id: &str,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Delegation> {
+    let staked_balance = balance(
+        rpc_url,
+        pool_account_id,
+        "get_account_staked_balance",
+        account_id,
+        block_reference.clone(),
+    )
+    .await?;
+    let unstaked_balance = balance(
+        rpc_url,
+        pool_account_id,
+        "get_account_unstaked_balance",
+        account_id,
+        block_reference.clone(),
+    )
+    .await?;
+    let is_unstaked_balance_available = crate::common::call_view_function(
+        rpc_url,
+        pool_account_id,
+        "is_account_unstaked_balance_available",
+        serde_json::json!({ "account_id": account_id }),
+        block_reference,
+    )
+    .await?;
+    Ok(Delegation {
+        staked_balance,
+        unstaked_balance,
+        is_unstaked_balance_available,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_call(pool_call: PoolCall) -> near_primitives::transaction::FunctionCallAction {
+        match pool_call.action() {
+            near_primitives::transaction::Action::FunctionCall(function_call) => function_call,
+            action => panic!("Unexpected action {:?}", action),
+        }
+    }
+
+    #[test]
+    fn only_deposit_and_stake_attaches_a_deposit() {
+        let deposit_and_stake = function_call(PoolCall::DepositAndStake(10));
+        assert_eq!(deposit_and_stake.method_name, "deposit_and_stake");
+        assert_eq!(deposit_and_stake.args, b"{}");
+        assert_eq!(deposit_and_stake.deposit, 10);
+        let unstake = function_call(PoolCall::Unstake(10));
+        assert_eq!(unstake.method_name, "unstake");
+        assert_eq!(unstake.args, br#"{"amount":"10"}"#);
+        assert_eq!(unstake.deposit, 0);
+        let withdraw_all = function_call(PoolCall::WithdrawAll);
+        assert_eq!(withdraw_all.method_name, "withdraw_all");
+        assert_eq!(withdraw_all.args, b"{}");
+        assert_eq!(withdraw_all.deposit, 0);
+        assert_eq!(withdraw_all.gas, STAKING_POOL_GAS);
+    }
+}
@@ -0,0 +1,862 @@
+//! Sending signed transactions.
+//!
+//! A transaction is validated with `EXPERIMENTAL_check_tx`, sent with `broadcast_tx_async`,
+//! and then its `tx` status is polled with exponential backoff until it reaches the level
+//! given with `--wait-until`. A transaction rejected for an expired block hash or a used
+//! nonce is signed again with a fresh nonce and block hash if `--resign` is given and the
+//! secret key is at hand, unless the used nonce is taken by the transaction itself. With `--dry-run` nothing is sent: the transaction is only checked
+//! against the current state of the chain and its estimated cost is reported.
+use near_primitives::borsh::BorshSerialize;
+
+/// How far a sent transaction is followed before the command returns
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    strum_macros::IntoStaticStr,
+    strum_macros::EnumString,
+    strum_macros::EnumVariantNames,
+    smart_default::SmartDefault,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum WaitUntil {
+    /// The transaction is in a block
+    Included,
+    /// The transaction and all its receipts are executed
+    #[default]
+    Executed,
+    /// Besides, all the blocks with the outcomes are final
+    Final,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionSettings {
+    pub wait_until: WaitUntil,
+    pub resign: bool,
+    pub dry_run: bool,
+}
+
+thread_local! {
+    static SUBMISSION_SETTINGS: std::cell::RefCell<SubmissionSettings> =
+        std::cell::RefCell::new(SubmissionSettings::default());
+}
+
+pub fn set_submission_settings(submission_settings: SubmissionSettings) {
+    SUBMISSION_SETTINGS.with(|settings| *settings.borrow_mut() = submission_settings);
+}
+
+pub fn submission_settings() -> SubmissionSettings {
+    SUBMISSION_SETTINGS.with(|settings| settings.borrow().clone())
+}
+
+const FIRST_POLL_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+const MAX_POLL_DELAY: std::time::Duration = std::time::Duration::from_secs(16);
+/// About three minutes with the delays above
+const MAX_POLLS: usize = 15;
+const MAX_RESIGNS: usize = 3;
+
+enum TransactionStatus {
+    Done(near_primitives::views::FinalExecutionOutcomeView),
+    Rejected(near_primitives::errors::InvalidTxError),
+    /// The transaction has not shown up on the chain
+    Missing,
+    /// The transaction is on the chain, but has not reached the `--wait-until` level in time
+    Pending,
+}
+
+/// Sends the transaction and waits for it as set with `--wait-until`.
+/// `signer_secret_key` is needed to sign the transaction again (see `--resign`).
+pub async fn send_transaction(
+    network_connection_config: &crate::common::ConnectionConfig,
+    mut signed_transaction: near_primitives::transaction::SignedTransaction,
+    signer_secret_key: Option<&near_crypto::SecretKey>,
+) -> color_eyre::eyre::Result<near_primitives::views::FinalExecutionOutcomeView> {
+    let settings = submission_settings();
+    let json_rpc_client =
+        near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+    let mut resigns = 0;
+    loop {
+        let status = match check_transaction(&json_rpc_client, &signed_transaction).await {
+            Some(invalid_tx_error) => TransactionStatus::Rejected(invalid_tx_error),
+            None => {
+                let transaction_hash = json_rpc_client
+                    .broadcast_tx_async(near_primitives::serialize::to_base64(
+                        signed_transaction
+                            .try_to_vec()
+                            .expect("Transaction is not expected to fail on serialization"),
+                    ))
+                    .await
+                    .map_err(crate::common::CliError::from)?;
+                crate::common::print_message(&format!(
+                    "Transaction {} sent, waiting until it is {} ...",
+                    transaction_hash,
+                    <&str>::from(settings.wait_until)
+                ));
+                match wait_for_transaction(
+                    &json_rpc_client,
+                    &signed_transaction,
+                    settings.wait_until,
+                )
+                .await?
+                {
+                    // A dropped transaction is usually stale, the node tells why
+                    TransactionStatus::Missing => {
+                        match check_transaction(&json_rpc_client, &signed_transaction).await {
+                            Some(invalid_tx_error) => {
+                                // The nonce may have been used up by this very transaction,
+                                // which must not be sent again then
+                                let is_used_nonce = matches!(
+                                    invalid_tx_error,
+                                    near_primitives::errors::InvalidTxError::InvalidNonce { .. }
+                                );
+                                if is_used_nonce
+                                    && is_on_chain(&json_rpc_client, &signed_transaction).await?
+                                {
+                                    TransactionStatus::Pending
+                                } else {
+                                    TransactionStatus::Rejected(invalid_tx_error)
+                                }
+                            }
+                            None => TransactionStatus::Missing,
+                        }
+                    }
+                    status => status,
+                }
+            }
+        };
+        let invalid_tx_error = match status {
+            TransactionStatus::Done(transaction_info) => return Ok(transaction_info),
+            TransactionStatus::Missing => {
+                return Err(crate::common::CliError::Rpc(format!(
+                    "The transaction {} has not shown up on the chain in time",
+                    signed_transaction.get_hash()
+                ))
+                .into())
+            }
+            TransactionStatus::Pending => {
+                return Err(crate::common::CliError::Rpc(format!(
+                    "The transaction {} is included, but not yet {}; check it later with `view transaction-status`",
+                    signed_transaction.get_hash(),
+                    <&str>::from(settings.wait_until)
+                ))
+                .into())
+            }
+            TransactionStatus::Rejected(invalid_tx_error) => invalid_tx_error,
+        };
+        let is_stale = matches!(
+            invalid_tx_error,
+            near_primitives::errors::InvalidTxError::Expired
+                | near_primitives::errors::InvalidTxError::InvalidNonce { .. }
+        );
+        match signer_secret_key {
+            Some(signer_secret_key) if is_stale && settings.resign && resigns < MAX_RESIGNS => {
+                resigns += 1;
+                crate::common::print_message(&format!(
+                    "The transaction was rejected ({:?}), signing it again with a fresh nonce and block hash ...",
+                    invalid_tx_error
+                ));
+                signed_transaction = resign_transaction(
+                    &json_rpc_client,
+                    signed_transaction.transaction,
+                    signer_secret_key,
+                )
+                .await?;
+            }
+            _ => {
+                if is_stale && !settings.resign {
+                    crate::common::print_message(
+                        "Run the command again, or add --resign to sign the transaction again with a fresh nonce and block hash.",
+                    );
+                }
+                return Err(crate::common::CliError::InvalidTransaction(invalid_tx_error).into());
+            }
+        }
+    }
+}
+
+/// Asks the node to validate the transaction without sending it
+async fn check_transaction(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> Option<near_primitives::errors::InvalidTxError> {
+    let check_result = json_rpc_client
+        .EXPERIMENTAL_check_tx(near_primitives::serialize::to_base64(
+            signed_transaction
+                .try_to_vec()
+                .expect("Transaction is not expected to fail on serialization"),
+        ))
+        .await;
+    match check_result.map_err(crate::common::CliError::from) {
+        Err(crate::common::CliError::InvalidTransaction(invalid_tx_error)) => {
+            Some(invalid_tx_error)
+        }
+        // Any other failure shows up again when the transaction is sent
+        _ => None,
+    }
+}
+
+/// Polls the status of the transaction with exponential backoff
+async fn wait_for_transaction(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+    wait_until: WaitUntil,
+) -> color_eyre::eyre::Result<TransactionStatus> {
+    let transaction_hash = signed_transaction.get_hash().to_string();
+    let signer_id = signed_transaction.transaction.signer_id.clone();
+    let mut delay = FIRST_POLL_DELAY;
+    let mut is_included = false;
+    for _ in 0..MAX_POLLS {
+        actix_web::rt::time::sleep(delay).await;
+        delay = std::cmp::min(delay * 2, MAX_POLL_DELAY);
+        let transaction_info = match json_rpc_client
+            .tx(transaction_hash.clone(), signer_id.clone())
+            .await
+        {
+            Ok(transaction_info) => transaction_info,
+            // Unknown transactions and timeouts are polled again
+            Err(err) if is_unknown_transaction(&err) || is_timeout(&err) => continue,
+            Err(err) => match crate::common::CliError::from(err) {
+                crate::common::CliError::InvalidTransaction(invalid_tx_error) => {
+                    return Ok(TransactionStatus::Rejected(invalid_tx_error))
+                }
+                cli_error => return Err(cli_error.into()),
+            },
+        };
+        is_included = true;
+        let is_executed = matches!(
+            transaction_info.status,
+            near_primitives::views::FinalExecutionStatus::SuccessValue(_)
+                | near_primitives::views::FinalExecutionStatus::Failure(_)
+        );
+        let is_done = match wait_until {
+            WaitUntil::Included => true,
+            WaitUntil::Executed => is_executed,
+            WaitUntil::Final => is_executed && is_final(json_rpc_client, &transaction_info).await?,
+        };
+        if is_done {
+            return Ok(TransactionStatus::Done(transaction_info));
+        }
+    }
+    if is_included {
+        Ok(TransactionStatus::Pending)
+    } else {
+        Ok(TransactionStatus::Missing)
+    }
+}
+
+/// Whether the node knows the transaction; only an unknown transaction is known to be absent
+async fn is_on_chain(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> color_eyre::eyre::Result<bool> {
+    match json_rpc_client
+        .tx(
+            signed_transaction.get_hash().to_string(),
+            signed_transaction.transaction.signer_id.clone(),
+        )
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(err) if is_unknown_transaction(&err) => Ok(false),
+        Err(err) => Err(crate::common::CliError::Rpc(format!(
+            "Failed to check whether the transaction {} is on the chain: {:?}",
+            signed_transaction.get_hash(),
+            err
+        ))
+        .into()),
+    }
+}
+
+fn transaction_error(
+    rpc_error: &near_jsonrpc_primitives::errors::RpcError,
+) -> Option<near_jsonrpc_primitives::types::transactions::RpcTransactionError> {
+    match &rpc_error.error_struct {
+        Some(near_jsonrpc_primitives::errors::RpcErrorKind::HandlerError(handler_error)) => {
+            serde_json::from_value(handler_error.clone()).ok()
+        }
+        _ => None,
+    }
+}
+
+fn is_unknown_transaction(rpc_error: &near_jsonrpc_primitives::errors::RpcError) -> bool {
+    matches!(
+        transaction_error(rpc_error),
+        Some(
+            near_jsonrpc_primitives::types::transactions::RpcTransactionError::UnknownTransaction { .. }
+        )
+    )
+}
+
+fn is_timeout(rpc_error: &near_jsonrpc_primitives::errors::RpcError) -> bool {
+    matches!(
+        transaction_error(rpc_error),
+        Some(near_jsonrpc_primitives::types::transactions::RpcTransactionError::TimeoutError)
+    )
+}
+
+/// Whether all the blocks with the outcomes of the transaction and its receipts are final
+async fn is_final(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+) -> color_eyre::eyre::Result<bool> {
+    let final_block = json_rpc_client
+        .block(near_primitives::types::Finality::Final.into())
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!("Failed to fetch the final block: {:?}", err))
+        })?;
+    let mut block_hashes = vec![transaction_info.transaction_outcome.block_hash];
+    for receipt_outcome in &transaction_info.receipts_outcome {
+        if !block_hashes.contains(&receipt_outcome.block_hash) {
+            block_hashes.push(receipt_outcome.block_hash);
+        }
+    }
+    for block_hash in block_hashes {
+        let block = json_rpc_client
+            .block(near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Hash(block_hash),
+            ))
+            .await
+            .map_err(|err| {
+                crate::common::CliError::Rpc(format!(
+                    "Failed to fetch the block {}: {:?}",
+                    block_hash, err
+                ))
+            })?;
+        if block.header.height > final_block.header.height {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Signs the transaction again with the current nonce and a recent block hash
+async fn resign_transaction(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    transaction: near_primitives::transaction::Transaction,
+    signer_secret_key: &near_crypto::SecretKey,
+) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+    let online_signer_access_key_response = json_rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id: transaction.signer_id.clone(),
+                public_key: transaction.public_key.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!(
+                "Failed to fetch public key information for nonce: {:?}",
+                err
+            ))
+        })?;
+    let current_nonce = if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+        online_signer_access_key,
+    ) = online_signer_access_key_response.kind
+    {
+        online_signer_access_key.nonce
+    } else {
+        return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+    };
+    let unsigned_transaction = near_primitives::transaction::Transaction {
+        block_hash: online_signer_access_key_response.block_hash,
+        nonce: current_nonce + 1,
+        ..transaction
+    };
+    let signature = signer_secret_key.sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+    let signed_transaction =
+        near_primitives::transaction::SignedTransaction::new(signature, unsigned_transaction);
+    crate::common::print_transaction(signed_transaction.transaction.clone());
+    Ok(signed_transaction)
+}
+
+/// Checks the transaction against the current state of the chain instead of sending it
+/// and reports its estimated cost
+pub async fn dry_run(
+    network_connection_config: &crate::common::ConnectionConfig,
+    transaction: &near_primitives::transaction::Transaction,
+) -> crate::CliResult {
+    let json_rpc_client =
+        near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+    let runtime_config = runtime_config(&network_connection_config.rpc_url()).await?;
+    let gas_price = json_rpc_client
+        .gas_price(None)
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!("Failed to fetch the gas price: {:?}", err))
+        })?
+        .gas_price;
+    let estimated_gas = estimated_gas(transaction, &runtime_config.transaction_costs);
+    let gas_cost = u128::from(estimated_gas) * gas_price;
+    let deposits = deposits(transaction);
+    let total_cost = gas_cost + deposits;
+    // Each check is a description and the reason of the failure, if any
+    let mut checks: Vec<(String, Option<String>)> = vec![];
+
+    let signer_account = match query_final(
+        &json_rpc_client,
+        near_primitives::views::QueryRequest::ViewAccount {
+            account_id: transaction.signer_id.clone(),
+        },
+    )
+    .await?
+    {
+        Some(near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(
+            account_view,
+        )) => Some(account_view),
+        _ => None,
+    };
+    checks.push((
+        format!("The signer account <{}> exists", transaction.signer_id),
+        match signer_account {
+            Some(_) => None,
+            None => Some("the account does not exist".to_string()),
+        },
+    ));
+
+    let access_key = match query_final(
+        &json_rpc_client,
+        near_primitives::views::QueryRequest::ViewAccessKey {
+            account_id: transaction.signer_id.clone(),
+            public_key: transaction.public_key.clone(),
+        },
+    )
+    .await?
+    {
+        Some(near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+            access_key_view,
+        )) => Some(access_key_view),
+        _ => None,
+    };
+    checks.push((
+        format!(
+            "The access key {} is on the signer account",
+            transaction.public_key
+        ),
+        match access_key {
+            Some(_) => None,
+            None => Some("the signer account does not have this key".to_string()),
+        },
+    ));
+    if let Some(access_key) = &access_key {
+        checks.push((
+            "The access key allows the transaction".to_string(),
+            permission_mismatch(&access_key.permission, transaction, gas_cost),
+        ));
+    }
+
+    let is_receiver_created = transaction.actions.iter().any(|action| {
+        matches!(
+            action,
+            near_primitives::transaction::Action::CreateAccount(_)
+        )
+    });
+    let receiver_exists = query_final(
+        &json_rpc_client,
+        near_primitives::views::QueryRequest::ViewAccount {
+            account_id: transaction.receiver_id.clone(),
+        },
+    )
+    .await?
+    .is_some();
+    if is_receiver_created {
+        checks.push((
+            format!(
+                "The new account <{}> does not exist yet",
+                transaction.receiver_id
+            ),
+            if receiver_exists {
+                Some("the account already exists".to_string())
+            } else {
+                None
+            },
+        ));
+    } else if !(is_implicit_account(&transaction.receiver_id)
+        && transaction
+            .actions
+            .iter()
+            .all(|action| matches!(action, near_primitives::transaction::Action::Transfer(_))))
+    {
+        // A transfer to an implicit account creates it
+        checks.push((
+            format!("The receiver account <{}> exists", transaction.receiver_id),
+            if receiver_exists {
+                None
+            } else {
+                Some("the account does not exist".to_string())
+            },
+        ));
+    }
+
+    let balance = signer_account.as_ref().map(|account_view| {
+        let storage_cost =
+            u128::from(account_view.storage_usage) * runtime_config.storage_amount_per_byte;
+        std::cmp::min(
+            account_view.amount,
+            (account_view.amount + account_view.locked).saturating_sub(storage_cost),
+        )
+    });
+    if let Some(balance) = balance {
+        checks.push((
+            "The balance covers the deposits and the gas".to_string(),
+            if balance < total_cost {
+                Some(format!(
+                    "only {} is available",
+                    crate::common::NearBalance::from_yoctonear(balance)
+                ))
+            } else {
+                None
+            },
+        ));
+    }
+
+    let failures = checks
+        .iter()
+        .filter_map(|(_, failure)| failure.clone())
+        .collect::<Vec<_>>();
+    match crate::common::output_format() {
+        crate::common::OutputFormat::Plaintext => {
+            println!("\nDry run, the transaction is not sent:");
+            for (description, failure) in &checks {
+                match failure {
+                    None => println!("  [ok] {}", description),
+                    Some(failure) => println!("  [FAILED] {}: {}", description, failure),
+                }
+            }
+            println!(
+                "Gas price: {} per Gas\n\
+                Estimated gas: {}\n\
+                Gas cost: {}\n\
+                Deposits: {}\n\
+                Estimated total cost: {}",
+                crate::common::NearBalance::from_yoctonear(gas_price),
+                crate::common::NearGas {
+                    inner: estimated_gas
+                },
+                crate::common::NearBalance::from_yoctonear(gas_cost),
+                crate::common::NearBalance::from_yoctonear(deposits),
+                crate::common::NearBalance::from_yoctonear(total_cost)
+            );
+        }
+        crate::common::OutputFormat::Json => crate::common::set_json_output(
+            "dry_run",
+            serde_json::json!({
+                "checks": checks
+                    .iter()
+                    .map(|(description, failure)| serde_json::json!({
+                        "check": description,
+                        "ok": failure.is_none(),
+                        "error": failure,
+                    }))
+                    .collect::<Vec<_>>(),
+                "gas_price": gas_price.to_string(),
+                "estimated_gas": estimated_gas,
+                "gas_cost": gas_cost.to_string(),
+                "deposits": deposits.to_string(),
+                "total_cost": total_cost.to_string(),
+                "balance": balance.map(|balance| balance.to_string()),
+            }),
+        ),
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::common::CliError::DryRunFailed(format!(
+            "The transaction would fail: {}",
+            failures.join("; ")
+        ))
+        .into())
+    }
+}
+
+/// The current runtime config of the chain: the storage cost and the fees of the actions
+async fn runtime_config(
+    rpc_url: &url::Url,
+) -> color_eyre::eyre::Result<near_primitives::runtime::config::RuntimeConfig> {
+    let protocol_config =
+        crate::common::protocol_config(rpc_url, near_primitives::types::Finality::Final.into())
+            .await?;
+    let runtime_config = serde_json::from_value(protocol_config["runtime_config"].clone())
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!(
+                "The protocol config has an unexpected runtime config: {}",
+                err
+            ))
+        })?;
+    Ok(runtime_config)
+}
+
+/// Queries the final state, `None` if the account or the access key does not exist
+async fn query_final(
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    request: near_primitives::views::QueryRequest,
+) -> color_eyre::eyre::Result<Option<near_jsonrpc_primitives::types::query::QueryResponseKind>> {
+    match json_rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request,
+        })
+        .await
+    {
+        Ok(query_response) => Ok(Some(query_response.kind)),
+        Err(err) if crate::common::is_unknown_account_or_access_key(&err) => Ok(None),
+        Err(err) => Err(crate::common::CliError::Rpc(format!(
+            "Failed to fetch query for the dry run: {:?}",
+            err
+        ))
+        .into()),
+    }
+}
+
+fn is_implicit_account(account_id: &str) -> bool {
+    account_id.len() == 64
+        && account_id
+            .chars()
+            .all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+}
+
+/// The tokens attached to the transfers and function calls of the transaction
+fn deposits(transaction: &near_primitives::transaction::Transaction) -> u128 {
+    transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::Transfer(transfer) => transfer.deposit,
+            near_primitives::transaction::Action::FunctionCall(function_call) => {
+                function_call.deposit
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// The gas for the transaction and its receipt with the fees of the chain, plus the gas prepaid
+/// for function calls
+fn estimated_gas(
+    transaction: &near_primitives::transaction::Transaction,
+    fees: &near_primitives::runtime::fees::RuntimeFeesConfig,
+) -> u64 {
+    let sir = transaction.signer_id == transaction.receiver_id;
+    let receipt_fee = &fees.action_receipt_creation_config;
+    let mut gas = receipt_fee.send_fee(sir) + receipt_fee.exec_fee();
+    let action_fees = &fees.action_creation_config;
+    for action in &transaction.actions {
+        let (fee, fee_per_byte, bytes) = match action {
+            near_primitives::transaction::Action::CreateAccount(_) => {
+                (&action_fees.create_account_cost, None, 0)
+            }
+            near_primitives::transaction::Action::DeployContract(deploy_contract) => (
+                &action_fees.deploy_contract_cost,
+                Some(&action_fees.deploy_contract_cost_per_byte),
+                deploy_contract.code.len(),
+            ),
+            near_primitives::transaction::Action::FunctionCall(function_call) => {
+                gas += function_call.gas;
+                (
+                    &action_fees.function_call_cost,
+                    Some(&action_fees.function_call_cost_per_byte),
+                    function_call.method_name.len() + function_call.args.len(),
+                )
+            }
+            near_primitives::transaction::Action::Transfer(_) => {
+                (&action_fees.transfer_cost, None, 0)
+            }
+            near_primitives::transaction::Action::Stake(_) => (&action_fees.stake_cost, None, 0),
+            near_primitives::transaction::Action::AddKey(add_key) => {
+                match &add_key.access_key.permission {
+                    near_primitives::account::AccessKeyPermission::FunctionCall(permission) => (
+                        &action_fees.add_key_cost.function_call_cost,
+                        Some(&action_fees.add_key_cost.function_call_cost_per_byte),
+                        permission
+                            .method_names
+                            .iter()
+                            .map(|method_name| method_name.len() + 1)
+                            .sum(),
+                    ),
+                    near_primitives::account::AccessKeyPermission::FullAccess => {
+                        (&action_fees.add_key_cost.full_access_cost, None, 0)
+                    }
+                }
+            }
+            near_primitives::transaction::Action::DeleteKey(_) => {
+                (&action_fees.delete_key_cost, None, 0)
+            }
+            near_primitives::transaction::Action::DeleteAccount(_) => {
+                (&action_fees.delete_account_cost, None, 0)
+            }
+        };
+        gas += fee.send_fee(sir) + fee.exec_fee();
+        if let Some(fee_per_byte) = fee_per_byte {
+            gas += (fee_per_byte.send_fee(sir) + fee_per_byte.exec_fee()) * bytes as u64;
+        }
+    }
+    gas
+}
+
+/// The prepaid gas of the function calls of the transaction at the gas price
+pub fn prepaid_gas_cost(
+    transaction: &near_primitives::transaction::Transaction,
+    gas_price: near_primitives::types::Balance,
+) -> near_primitives::types::Balance {
+    transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::FunctionCall(function_call) => {
+                u128::from(function_call.gas) * gas_price
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Why an access key with the permission cannot sign the transaction, `None` if it can.
+/// The allowance of a function call key has to cover `gas_cost`.
+pub fn permission_mismatch(
+    permission: &near_primitives::views::AccessKeyPermissionView,
+    transaction: &near_primitives::transaction::Transaction,
+    gas_cost: near_primitives::types::Balance,
+) -> Option<String> {
+    let (allowance, receiver_id, method_names) = match permission {
+        near_primitives::views::AccessKeyPermissionView::FullAccess => return None,
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => (allowance, receiver_id, method_names),
+    };
+    let function_call = match transaction.actions.as_slice() {
+        [near_primitives::transaction::Action::FunctionCall(function_call)] => function_call,
+        _ => {
+            return Some(
+                "a function call key can only sign a transaction with a single function call"
+                    .to_string(),
+            )
+        }
+    };
+    if &transaction.receiver_id != receiver_id {
+        return Some(format!("the key only allows calls to <{}>", receiver_id));
+    }
+    if function_call.deposit > 0 {
+        return Some("a function call key cannot attach a deposit".to_string());
+    }
+    if !method_names.is_empty() && !method_names.contains(&function_call.method_name) {
+        return Some(format!(
+            "the key only allows calls to the methods {}",
+            method_names.join(", ")
+        ));
+    }
+    match allowance {
+        Some(allowance) if *allowance < gas_cost => Some(format!(
+            "the allowance of {} does not cover the gas cost of {}",
+            crate::common::NearBalance::from_yoctonear(*allowance),
+            crate::common::NearBalance::from_yoctonear(gas_cost)
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_call_transaction(
+        receiver_id: &str,
+        method_name: &str,
+        deposit: u128,
+    ) -> near_primitives::transaction::Transaction {
+        near_primitives::transaction::Transaction {
+            signer_id: "volodymyr.testnet".to_string(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: receiver_id.to_string(),
+            block_hash: Default::default(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_string(),
+                    args: vec![],
+                    gas: 100_000_000_000_000,
+                    deposit,
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn function_call_key_signs_only_allowed_calls() {
+        let permission = near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "counter.testnet".to_string(),
+            method_names: vec!["increment".to_string()],
+        };
+        assert_eq!(
+            permission_mismatch(
+                &permission,
+                &function_call_transaction("counter.testnet", "increment", 0),
+                0
+            ),
+            None
+        );
+        assert!(permission_mismatch(
+            &permission,
+            &function_call_transaction("counter.testnet", "reset", 0),
+            0
+        )
+        .is_some());
+        assert!(permission_mismatch(
+            &permission,
+            &function_call_transaction("other.testnet", "increment", 0),
+            0
+        )
+        .is_some());
+        assert!(permission_mismatch(
+            &permission,
+            &function_call_transaction("counter.testnet", "increment", 1),
+            0
+        )
+        .is_some());
+        assert_eq!(
+            permission_mismatch(
+                &near_primitives::views::AccessKeyPermissionView::FullAccess,
+                &function_call_transaction("counter.testnet", "increment", 1),
+                0
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn function_call_key_needs_allowance_for_prepaid_gas() {
+        let transaction = function_call_transaction("counter.testnet", "increment", 0);
+        // 100 TeraGas at 100 million yoctoNEAR per gas is 0.01 NEAR
+        let gas_cost = prepaid_gas_cost(&transaction, 100_000_000);
+        assert_eq!(gas_cost, 10_000_000_000_000_000_000_000);
+        let permission =
+            |allowance| near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id: "counter.testnet".to_string(),
+                method_names: vec![],
+            };
+        assert!(
+            permission_mismatch(&permission(Some(gas_cost - 1)), &transaction, gas_cost).is_some()
+        );
+        assert_eq!(
+            permission_mismatch(&permission(Some(gas_cost)), &transaction, gas_cost),
+            None
+        );
+        assert_eq!(
+            permission_mismatch(&permission(None), &transaction, gas_cost),
+            None
+        );
+    }
+
+    #[test]
+    fn dry_run_cost_includes_prepaid_gas_and_deposit() {
+        let transaction = function_call_transaction("counter.testnet", "increment", 5);
+        let fees = near_primitives::runtime::fees::RuntimeFeesConfig::default();
+        assert!(estimated_gas(&transaction, &fees) > 100_000_000_000_000);
+        assert_eq!(deposits(&transaction), 5);
+    }
+}
@@ -0,0 +1,220 @@
+//! The validators of an epoch from the `validators` RPC: the current and the next validators,
+//! the stake proposals, the validators kicked out in the previous epoch and the seat price.
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EpochValidators {
+    pub epoch_height: u64,
+    pub epoch_start_height: u64,
+    pub current_validators: Vec<CurrentValidator>,
+    pub next_validators: Vec<Validator>,
+    pub current_proposals: Vec<Validator>,
+    pub prev_epoch_kickout: Vec<Kickout>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CurrentValidator {
+    pub account_id: String,
+    pub public_key: String,
+    pub stake: String,
+    pub is_slashed: bool,
+    pub num_produced_blocks: u64,
+    pub num_expected_blocks: u64,
+    #[serde(default)]
+    pub num_produced_chunks: u64,
+    #[serde(default)]
+    pub num_expected_chunks: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Validator {
+    pub account_id: String,
+    pub public_key: String,
+    pub stake: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Kickout {
+    pub account_id: String,
+    /// e.g. `"Unstaked"` or `{"NotEnoughBlocks": {"produced": 10, "expected": 20}}`
+    pub reason: serde_json::Value,
+}
+
+/// The stakes of the RPC are decimal strings of yoctoNEAR
+fn stake(stake: &str) -> crate::common::NearBalance {
+    crate::common::NearBalance::from_yoctonear(stake.parse().unwrap_or_default())
+}
+
+/// `produced/expected (percent)`, the expected number is 0 for a validator without a seat
+/// of this kind
+fn uptime(produced: u64, expected: u64) -> String {
+    if expected == 0 {
+        return "-".to_owned();
+    }
+    format!("{}/{} ({}%)", produced, expected, produced * 100 / expected)
+}
+
+pub fn kickout_reason(reason: &serde_json::Value) -> String {
+    match reason {
+        serde_json::Value::String(reason) => reason.clone(),
+        serde_json::Value::Object(reasons) => reasons
+            .iter()
+            .map(|(reason, details)| match details {
+                serde_json::Value::Object(details) => format!(
+                    "{} ({})",
+                    reason,
+                    details
+                        .iter()
+                        .map(|(name, value)| match value {
+                            serde_json::Value::String(value) => format!("{}: {}", name, value),
+                            value => format!("{}: {}", name, value),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                _ => reason.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        reason => reason.to_string(),
+    }
+}
+
+/// The minimum stake of a seat: the largest price such that the stakes buy `num_seats` seats
+/// in total, a stake buying `stake / price` seats
+pub fn find_seat_price(stakes: &[u128], num_seats: u64) -> Option<u128> {
+    let stake_sum: u128 = stakes.iter().sum();
+    if stake_sum < u128::from(num_seats) {
+        return None;
+    }
+    let (mut left, mut right) = (1u128, stake_sum + 1);
+    'outer: loop {
+        if left == right - 1 {
+            return Some(left);
+        }
+        let mid = (left + right) / 2;
+        let mut seats: u128 = 0;
+        for stake in stakes {
+            seats += stake / mid;
+            if seats >= u128::from(num_seats) {
+                left = mid;
+                continue 'outer;
+            }
+        }
+        right = mid;
+    }
+}
+
+pub async fn epoch_validators(
+    rpc_url: &url::Url,
+    block_id: Option<near_primitives::types::BlockId>,
+) -> color_eyre::eyre::Result<EpochValidators> {
+    let epoch_validator_info = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .validators(block_id)
+        .await
+        .map_err(|err| {
+            crate::common::CliError::Rpc(format!("Failed to fetch the validators: {:?}", err))
+        })?;
+    // Only the fields shown are kept, they are the same across the versions of the view
+    let epoch_validator_info = serde_json::to_value(epoch_validator_info)?;
+    Ok(serde_json::from_value(epoch_validator_info)?)
+}
+
+/// The seat price of the current validators, the number of seats is in the protocol config
+pub async fn seat_price(
+    rpc_url: &url::Url,
+    block_reference: near_primitives::types::BlockReference,
+    epoch_validators: &EpochValidators,
+) -> color_eyre::eyre::Result<Option<u128>> {
+    let protocol_config = crate::common::protocol_config(rpc_url, block_reference).await?;
+    let num_seats = protocol_config["num_block_producer_seats"]
+        .as_u64()
+        .ok_or_else(|| {
+            color_eyre::Report::msg("The protocol config has no num_block_producer_seats")
+        })?;
+    let stakes = epoch_validators
+        .current_validators
+        .iter()
+        .map(|validator| validator.stake.parse().unwrap_or_default())
+        .collect::<Vec<u128>>();
+    Ok(find_seat_price(&stakes, num_seats))
+}
+
+pub fn print_validators(epoch_validators: &EpochValidators) {
+    println!(
+        "\nValidators of the epoch {} (started at block #{})",
+        epoch_validators.epoch_height, epoch_validators.epoch_start_height
+    );
+    println!(
+        "\nCurrent validators ({}):",
+        epoch_validators.current_validators.len()
+    );
+    println!(
+        "  {:<40} {:>28} {:>24} {:>24}",
+        "Account", "Stake", "Blocks", "Chunks"
+    );
+    for validator in &epoch_validators.current_validators {
+        println!(
+            "  {:<40} {:>28} {:>24} {:>24}{}",
+            validator.account_id,
+            stake(&validator.stake).to_string(),
+            uptime(validator.num_produced_blocks, validator.num_expected_blocks),
+            uptime(validator.num_produced_chunks, validator.num_expected_chunks),
+            if validator.is_slashed { " slashed" } else { "" }
+        );
+    }
+    println!(
+        "\nNext validators ({}):",
+        epoch_validators.next_validators.len()
+    );
+    print_stakes(&epoch_validators.next_validators);
+    if !epoch_validators.prev_epoch_kickout.is_empty() {
+        println!("\nKicked out in the previous epoch:");
+        for kickout in &epoch_validators.prev_epoch_kickout {
+            println!(
+                "  {:<40} {}",
+                kickout.account_id,
+                kickout_reason(&kickout.reason)
+            );
+        }
+    }
+}
+
+pub fn print_stakes(validators: &[Validator]) {
+    for validator in validators {
+        println!(
+            "  {:<40} {:>28}",
+            validator.account_id,
+            stake(&validator.stake).to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_price_buys_all_the_seats() {
+        assert_eq!(find_seat_price(&[100, 100, 100], 3), Some(100));
+        assert_eq!(find_seat_price(&[100, 100, 100], 6), Some(50));
+        assert_eq!(find_seat_price(&[1_000, 10], 4), Some(250));
+        assert_eq!(find_seat_price(&[1, 1], 3), None);
+    }
+
+    #[test]
+    fn kickout_reasons_are_readable() {
+        assert_eq!(kickout_reason(&serde_json::json!("Unstaked")), "Unstaked");
+        assert_eq!(
+            kickout_reason(
+                &serde_json::json!({"NotEnoughBlocks": {"expected": 20, "produced": 10}})
+            ),
+            "NotEnoughBlocks (expected: 20, produced: 10)"
+        );
+        assert_eq!(
+            kickout_reason(
+                &serde_json::json!({"NotEnoughStake": {"stake": "1", "threshold": "2"}})
+            ),
+            "NotEnoughStake (stake: 1, threshold: 2)"
+        );
+    }
+}
@@ -0,0 +1,400 @@
+//! A minimal reader of WebAssembly modules: just the import, memory and export sections,
+//! to show what a contract needs and provides before it is deployed.
+
+/// The contract size limit of the default runtime config, the fallback where the limit of the
+/// chain cannot be read
+pub const MAX_CONTRACT_SIZE: usize = 4 * 1024 * 1024;
+
+const SECTION_IMPORT: u8 = 2;
+const SECTION_MEMORY: u8 = 5;
+const SECTION_EXPORT: u8 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExternalKind {
+    Function,
+    Table,
+    Memory,
+    Global,
+}
+
+impl ExternalKind {
+    fn from_byte(byte: u8) -> Result<Self, String> {
+        match byte {
+            0 => Ok(Self::Function),
+            1 => Ok(Self::Table),
+            2 => Ok(Self::Memory),
+            3 => Ok(Self::Global),
+            _ => Err(format!("unknown external kind {}", byte)),
+        }
+    }
+}
+
+impl std::fmt::Display for ExternalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Function => write!(f, "function"),
+            Self::Table => write!(f, "table"),
+            Self::Memory => write!(f, "memory"),
+            Self::Global => write!(f, "global"),
+        }
+    }
+}
+
+/// Memory limits in 64 KiB pages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryLimits {
+    pub initial: u32,
+    pub maximum: Option<u32>,
+}
+
+impl std::fmt::Display for MemoryLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.maximum {
+            Some(maximum) => write!(
+                f,
+                "{} pages initial, {} pages maximum",
+                self.initial, maximum
+            ),
+            None => write!(f, "{} pages initial, no maximum", self.initial),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub module: String,
+    pub name: String,
+    pub kind: ExternalKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmInfo {
+    pub size: usize,
+    pub code_hash: near_primitives::hash::CryptoHash,
+    pub exported_functions: Vec<String>,
+    pub imports: Vec<Import>,
+    /// The memory defined or imported by the module
+    pub memory: Option<MemoryLimits>,
+}
+
+impl WasmInfo {
+    /// Parses the module, the error tells why it is not a valid WebAssembly module
+    pub fn new(code: &[u8]) -> Result<Self, String> {
+        let mut reader = Reader { code, position: 0 };
+        if reader.bytes(4)? != b"\0asm" {
+            return Err("the file does not start with the WebAssembly magic number".to_string());
+        }
+        if reader.bytes(4)? != [1, 0, 0, 0] {
+            return Err("only the version 1 of WebAssembly is supported".to_string());
+        }
+        let mut wasm_info = Self {
+            size: code.len(),
+            code_hash: near_primitives::hash::hash(code),
+            exported_functions: vec![],
+            imports: vec![],
+            memory: None,
+        };
+        while !reader.is_empty() {
+            let section_id = reader.byte()?;
+            let section_size = reader.u32()? as usize;
+            let mut section = Reader {
+                code: reader.bytes(section_size)?,
+                position: 0,
+            };
+            match section_id {
+                SECTION_IMPORT => {
+                    for _ in 0..section.u32()? {
+                        let module = section.name()?;
+                        let name = section.name()?;
+                        let kind = ExternalKind::from_byte(section.byte()?)?;
+                        match kind {
+                            ExternalKind::Function => {
+                                section.u32()?;
+                            }
+                            ExternalKind::Table => {
+                                section.byte()?;
+                                section.limits()?;
+                            }
+                            ExternalKind::Memory => wasm_info.memory = Some(section.limits()?),
+                            ExternalKind::Global => {
+                                section.bytes(2)?;
+                            }
+                        }
+                        wasm_info.imports.push(Import { module, name, kind });
+                    }
+                }
+                SECTION_MEMORY => {
+                    if section.u32()? > 0 {
+                        wasm_info.memory = Some(section.limits()?);
+                    }
+                }
+                SECTION_EXPORT => {
+                    for _ in 0..section.u32()? {
+                        let name = section.name()?;
+                        let kind = ExternalKind::from_byte(section.byte()?)?;
+                        section.u32()?;
+                        if kind == ExternalKind::Function {
+                            wasm_info.exported_functions.push(name);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(wasm_info)
+    }
+
+    pub fn exceeds_size_limit(&self, max_contract_size: usize) -> bool {
+        self.size > max_contract_size
+    }
+
+    /// Prints the module details; `deployed_code_hash` is the hash of the code on the account
+    /// to compare with, the result tells whether the hashes match
+    pub fn print(
+        &self,
+        deployed_code_hash: Option<&near_primitives::hash::CryptoHash>,
+        max_contract_size: usize,
+    ) -> bool {
+        let is_code_hash_matching = deployed_code_hash.map(|hash| hash == &self.code_hash);
+        match crate::common::output_format() {
+            crate::common::OutputFormat::Plaintext => {
+                println!(
+                    "Size: {} bytes ({} the limit of {} bytes)",
+                    self.size,
+                    if self.exceeds_size_limit(max_contract_size) {
+                        "EXCEEDS"
+                    } else {
+                        "within"
+                    },
+                    max_contract_size
+                );
+                println!("Code hash: {}", display_code_hash(&self.code_hash));
+                match (deployed_code_hash, is_code_hash_matching) {
+                    (Some(_), Some(true)) => {
+                        println!("The code hash matches the code hash on the account")
+                    }
+                    (Some(deployed_code_hash), _) => println!(
+                        "The code hash DIFFERS from the code hash on the account: {}",
+                        display_code_hash(deployed_code_hash)
+                    ),
+                    _ => {}
+                }
+                match &self.memory {
+                    Some(memory) => println!("Memory: {}", memory),
+                    None => println!("Memory: none"),
+                }
+                println!("Exported functions ({}):", self.exported_functions.len());
+                for exported_function in &self.exported_functions {
+                    println!("  {}", exported_function);
+                }
+                println!("Imports ({}):", self.imports.len());
+                for import in &self.imports {
+                    println!("  {}::{} ({})", import.module, import.name, import.kind);
+                }
+            }
+            crate::common::OutputFormat::Json => crate::common::set_json_output(
+                "wasm",
+                serde_json::json!({
+                    "size": self.size,
+                    "max_contract_size": max_contract_size,
+                    "exceeds_size_limit": self.exceeds_size_limit(max_contract_size),
+                    "code_hash": self.code_hash.to_string(),
+                    "sha256": hex::encode(self.code_hash.as_ref()),
+                    "deployed_code_hash": deployed_code_hash.map(|hash| hash.to_string()),
+                    "code_hash_matches": is_code_hash_matching,
+                    "memory": self.memory.map(|memory| serde_json::json!({
+                        "initial_pages": memory.initial,
+                        "maximum_pages": memory.maximum,
+                    })),
+                    "exported_functions": self.exported_functions,
+                    "imports": self
+                        .imports
+                        .iter()
+                        .map(|import| serde_json::json!({
+                            "module": import.module,
+                            "name": import.name,
+                            "kind": import.kind.to_string(),
+                        }))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+        }
+        is_code_hash_matching.unwrap_or(true)
+    }
+}
+
+/// The code hash in base58, as the RPC shows it, and as the SHA-256 hex of `sha256sum`
+pub fn display_code_hash(code_hash: &near_primitives::hash::CryptoHash) -> String {
+    format!(
+        "{} (SHA-256 hex: {})",
+        code_hash,
+        hex::encode(code_hash.as_ref())
+    )
+}
+
+/// Inspects the code downloaded from the account, which must hash to the account's code hash
+pub fn check_downloaded_code(
+    code: &[u8],
+    code_hash: &near_primitives::hash::CryptoHash,
+) -> crate::CliResult {
+    match WasmInfo::new(code) {
+        Ok(wasm_info) => {
+            if !wasm_info.print(Some(code_hash), MAX_CONTRACT_SIZE) {
+                return Err(crate::common::CliError::CodeHashMismatch(format!(
+                    "The downloaded code hashes to {}, not to the code hash {} of the account",
+                    display_code_hash(&wasm_info.code_hash),
+                    display_code_hash(code_hash)
+                ))
+                .into());
+            }
+        }
+        Err(err) => crate::common::print_message(&format!(
+            "The downloaded code is not a valid WebAssembly module: {}",
+            err
+        )),
+    }
+    Ok(())
+}
+
+/// The contract size limit in the current protocol config of the chain
+pub async fn max_contract_size(rpc_url: &url::Url) -> color_eyre::eyre::Result<usize> {
+    let protocol_config =
+        crate::common::protocol_config(rpc_url, near_primitives::types::Finality::Final.into())
+            .await?;
+    protocol_config
+        .pointer("/runtime_config/wasm_config/limit_config/max_contract_size")
+        .and_then(serde_json::Value::as_u64)
+        .map(|max_contract_size| max_contract_size as usize)
+        .ok_or_else(|| {
+            crate::common::CliError::Rpc(
+                "The protocol config has no contract size limit".to_string(),
+            )
+            .into()
+        })
+}
+
+/// The code hash of the account at the block, `None` if the account does not exist or has
+/// no contract
+pub async fn account_code_hash(
+    rpc_url: &url::Url,
+    account_id: String,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Option<near_primitives::hash::CryptoHash>> {
+    let query_view_account_response = near_jsonrpc_client::new_client(rpc_url.as_str())
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewAccount { account_id },
+        })
+        .await;
+    match query_view_account_response {
+        Ok(query_view_account_response) => match query_view_account_response.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(account_view)
+                if account_view.code_hash != near_primitives::hash::CryptoHash::default() =>
+            {
+                Ok(Some(account_view.code_hash))
+            }
+            _ => Ok(None),
+        },
+        Err(err) if crate::common::is_unknown_account_or_access_key(&err) => Ok(None),
+        Err(err) => Err(crate::common::CliError::Rpc(format!(
+            "Failed to fetch query for view account: {:?}",
+            err
+        ))
+        .into()),
+    }
+}
+
+struct Reader<'a> {
+    code: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.position >= self.code.len()
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.code.len())
+            .ok_or_else(|| "unexpected end of the module".to_string())?;
+        let bytes = &self.code[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// An unsigned LEB128 number
+    fn u32(&mut self) -> Result<u32, String> {
+        let mut result: u32 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            result |= u32::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err("invalid LEB128 number".to_string())
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let length = self.u32()? as usize;
+        String::from_utf8(self.bytes(length)?.to_vec()).map_err(|_| "invalid name".to_string())
+    }
+
+    fn limits(&mut self) -> Result<MemoryLimits, String> {
+        let flags = self.byte()?;
+        let initial = self.u32()?;
+        let maximum = if flags & 1 == 1 {
+            Some(self.u32()?)
+        } else {
+            None
+        };
+        Ok(MemoryLimits { initial, maximum })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_sections_are_read() {
+        let code = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic number and version
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section: () -> ()
+            0x02, 0x0b, 0x01, 0x03, b'e', b'n', b'v', 0x03, b'l', b'o', b'g', 0x00,
+            0x00, // import env::log
+            0x03, 0x02, 0x01, 0x00, // function section
+            0x05, 0x04, 0x01, 0x01, 0x11, 0x20, // memory: 17 pages, at most 32
+            0x07, 0x07, 0x01, 0x03, b'g', b'e', b't', 0x00, 0x01, // export "get"
+            0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code section
+        ];
+        let wasm_info = WasmInfo::new(&code).unwrap();
+        assert_eq!(wasm_info.exported_functions, vec!["get".to_string()]);
+        assert_eq!(
+            wasm_info.imports,
+            vec![Import {
+                module: "env".to_string(),
+                name: "log".to_string(),
+                kind: ExternalKind::Function,
+            }]
+        );
+        assert_eq!(
+            wasm_info.memory,
+            Some(MemoryLimits {
+                initial: 17,
+                maximum: Some(32),
+            })
+        );
+        assert!(!wasm_info.exceeds_size_limit(MAX_CONTRACT_SIZE));
+        assert!(wasm_info.exceeds_size_limit(code.len() - 1));
+        assert!(WasmInfo::new(&code[..20]).is_err());
+        assert!(WasmInfo::new(b"not a module").is_err());
+    }
+}